@@ -0,0 +1,118 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use vecgrid::Vecgrid;
+
+const ROWS: usize = 1080;
+const COLUMNS: usize = 1920;
+
+fn fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill");
+    let mut bytes = Vecgrid::filled_with(0u8, ROWS, COLUMNS);
+    group.bench_function("u8 fill", |b| b.iter(|| bytes.fill(black_box(7))));
+    group.bench_function("u8 iterator", |b| {
+        b.iter(|| {
+            for element in bytes.elements_row_major_iter_mut() {
+                *element = black_box(7);
+            }
+        })
+    });
+    group.finish();
+}
+
+fn eq(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eq");
+    let a = Vecgrid::filled_with(3i32, ROWS, COLUMNS);
+    let b = a.clone();
+    group.bench_function("i32 eq", |bench| {
+        bench.iter(|| black_box(&a) == black_box(&b))
+    });
+    group.bench_function("i32 iterator", |bench| {
+        bench.iter(|| {
+            black_box(&a)
+                .elements_row_major_iter()
+                .eq(black_box(&b).elements_row_major_iter())
+        })
+    });
+    group.finish();
+}
+
+fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+    let bytes = Vecgrid::from_iter_row_major((0..=255u8).cycle(), ROWS, COLUMNS).unwrap();
+    let floats = Vecgrid::from_iter_row_major((0..).map(|x| x as f32), ROWS, COLUMNS).unwrap();
+    group.bench_function("u8 sum", |b| b.iter(|| black_box(&bytes).sum()));
+    group.bench_function("u8 iterator", |b| {
+        b.iter(|| {
+            black_box(&bytes)
+                .elements_row_major_iter()
+                .map(|&x| x as u64)
+                .sum::<u64>()
+        })
+    });
+    group.bench_function("f32 sum", |b| b.iter(|| black_box(&floats).sum()));
+    group.bench_function("f32 iterator", |b| {
+        b.iter(|| black_box(&floats).elements_row_major_iter().sum::<f32>())
+    });
+    group.finish();
+}
+
+fn add_elementwise(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_elementwise");
+    let mut floats = Vecgrid::filled_with(0.0f32, ROWS, COLUMNS);
+    let addend = Vecgrid::filled_with(0.5f32, ROWS, COLUMNS);
+    group.bench_function("f32 add_elementwise", |b| {
+        b.iter(|| floats.add_elementwise(black_box(&addend)).unwrap())
+    });
+    group.bench_function("f32 indexed", |b| {
+        b.iter(|| {
+            for row in 0..ROWS {
+                for column in 0..COLUMNS {
+                    floats[(row, column)] += black_box(&addend)[(row, column)];
+                }
+            }
+        })
+    });
+    group.finish();
+}
+
+fn copy_lines(c: &mut Criterion) {
+    let mut group = c.benchmark_group("copy_lines");
+    let mut bytes = Vecgrid::filled_with(0u8, ROWS, COLUMNS);
+    let scanline = vec![7u8; COLUMNS];
+    let column = vec![7u8; ROWS];
+    group.bench_function("u8 copy_row_from_slice", |b| {
+        b.iter(|| {
+            for row in 0..ROWS {
+                bytes
+                    .copy_row_from_slice(row, black_box(&scanline))
+                    .unwrap();
+            }
+        })
+    });
+    group.bench_function("u8 row set", |b| {
+        b.iter(|| {
+            for row in 0..ROWS {
+                for (column, &value) in black_box(&scanline).iter().enumerate() {
+                    bytes.set(row, column, value).unwrap();
+                }
+            }
+        })
+    });
+    group.bench_function("u8 copy_column_from_slice", |b| {
+        b.iter(|| {
+            bytes
+                .copy_column_from_slice(COLUMNS / 2, black_box(&column))
+                .unwrap()
+        })
+    });
+    group.bench_function("u8 column set", |b| {
+        b.iter(|| {
+            for (row, &value) in black_box(&column).iter().enumerate() {
+                bytes.set(row, COLUMNS / 2, value).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fill, eq, sum, add_elementwise, copy_lines);
+criterion_main!(benches);
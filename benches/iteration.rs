@@ -0,0 +1,85 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use vecgrid::Vecgrid;
+
+const ROWS: usize = 500;
+const COLUMNS: usize = 500;
+
+fn grid() -> Vecgrid<u64> {
+    Vecgrid::from_iter_row_major(0.., ROWS, COLUMNS).unwrap()
+}
+
+fn iteration(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iteration");
+    let vecgrid = grid();
+    let nested = vecgrid.as_rows();
+    group.bench_function("row major", |b| {
+        b.iter(|| black_box(&vecgrid).elements_row_major_iter().sum::<u64>())
+    });
+    group.bench_function("column major", |b| {
+        b.iter(|| {
+            black_box(&vecgrid)
+                .elements_column_major_iter()
+                .sum::<u64>()
+        })
+    });
+    group.bench_function("indexed column major", |b| {
+        b.iter(|| {
+            let vecgrid = black_box(&vecgrid);
+            vecgrid
+                .indices_column_major()
+                .map(|index| vecgrid[index])
+                .sum::<u64>()
+        })
+    });
+    group.bench_function("Vec<Vec<T>> row major", |b| {
+        b.iter(|| black_box(&nested).iter().flatten().sum::<u64>())
+    });
+    group.bench_function("Vec<Vec<T>> column major", |b| {
+        b.iter(|| {
+            let nested = black_box(&nested);
+            (0..COLUMNS)
+                .flat_map(|column| nested.iter().map(move |row| row[column]))
+                .sum::<u64>()
+        })
+    });
+    group.finish();
+}
+
+fn insert_row(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_row");
+    let vecgrid = grid();
+    let nested = vecgrid.as_rows();
+    let row = vec![0; COLUMNS];
+    group.bench_function("head", |b| {
+        b.iter_batched(
+            || (vecgrid.clone(), row.clone()),
+            |(mut vecgrid, row)| vecgrid.insert_row(row, 0).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("tail", |b| {
+        b.iter_batched(
+            || (vecgrid.clone(), row.clone()),
+            |(mut vecgrid, row)| vecgrid.append_rows(vec![row]).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("Vec<Vec<T>> head", |b| {
+        b.iter_batched(
+            || (nested.clone(), row.clone()),
+            |(mut nested, row)| nested.insert(0, row),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("Vec<Vec<T>> tail", |b| {
+        b.iter_batched(
+            || (nested.clone(), row.clone()),
+            |(mut nested, row)| nested.push(row),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, iteration, insert_row);
+criterion_main!(benches);
@@ -0,0 +1,105 @@
+//! Approximate equality of [`Vecgrid`]s through the [`approx`] crate,
+//! enabled with the `approx` feature.
+//!
+//! Two vecgrids are approximately equal if they have the same dimensions
+//! and every pair of corresponding elements is approximately equal.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`approx`]: https://docs.rs/approx
+
+use crate::Vecgrid;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl<T> Vecgrid<T> {
+    fn same_dimensions<U>(&self, other: &Vecgrid<U>) -> bool {
+        self.num_rows == other.num_rows && self.num_columns == other.num_columns
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// # use approx::assert_abs_diff_eq;
+/// # fn main() -> Result<(), Error> {
+/// let a = Vecgrid::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]])?;
+/// let b = Vecgrid::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0 + 1e-9]])?;
+/// assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+/// # Ok(())
+/// # }
+/// ```
+impl<T> AbsDiffEq for Vecgrid<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.same_dimensions(other)
+            && self
+                .vecgrid
+                .iter()
+                .zip(&other.vecgrid)
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// # use approx::assert_relative_eq;
+/// # fn main() -> Result<(), Error> {
+/// let a = Vecgrid::filled_with(1.0e6_f32, 2, 2);
+/// let b = Vecgrid::filled_with(1.0e6_f32 + 0.05, 2, 2);
+/// assert_relative_eq!(a, b, max_relative = 1e-6);
+/// # Ok(())
+/// # }
+/// ```
+impl<T> RelativeEq for Vecgrid<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.same_dimensions(other)
+            && self
+                .vecgrid
+                .iter()
+                .zip(&other.vecgrid)
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<T> UlpsEq for Vecgrid<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.same_dimensions(other)
+            && self
+                .vecgrid
+                .iter()
+                .zip(&other.vecgrid)
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
@@ -0,0 +1,100 @@
+//! Random generation of [`Vecgrid`]s for property testing with
+//! [`quickcheck`], enabled with the `quickcheck` feature.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`quickcheck`]: https://docs.rs/quickcheck
+
+use crate::Vecgrid;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generates vecgrids whose number of rows and columns are each at most the
+/// square root of the generator size, so the number of elements scales
+/// with the size.
+///
+/// Shrinking first removes whole rows and columns from either edge and then
+/// shrinks individual elements.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::Vecgrid;
+/// use quickcheck::{quickcheck, TestResult};
+///
+/// fn transpose_twice_is_identity(vecgrid: Vecgrid<u8>) -> TestResult {
+///     let columns = vecgrid.as_columns();
+///     let transposed = Vecgrid::from_rows(columns).unwrap();
+///     TestResult::from_bool(
+///         vecgrid.num_rows() == 0
+///             || vecgrid.num_columns() == 0
+///             || transposed.as_columns() == vecgrid.as_rows(),
+///     )
+/// }
+///
+/// quickcheck(transpose_twice_is_identity as fn(Vecgrid<u8>) -> TestResult);
+/// ```
+impl<T: Arbitrary> Arbitrary for Vecgrid<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let max_len = (g.size() as f64).sqrt() as usize;
+        let num_rows = usize::arbitrary(g) % (max_len + 1);
+        let num_columns = usize::arbitrary(g) % (max_len + 1);
+        let vecgrid = (0..num_rows * num_columns)
+            .map(|_| T::arbitrary(g))
+            .collect();
+        Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut smaller = Vec::new();
+        if self.num_rows > 0 {
+            smaller.push(self.without_row(self.num_rows - 1));
+            if self.num_rows > 1 {
+                smaller.push(self.without_row(0));
+            }
+        }
+        if self.num_columns > 0 {
+            smaller.push(self.without_column(self.num_columns - 1));
+            if self.num_columns > 1 {
+                smaller.push(self.without_column(0));
+            }
+        }
+        let original = self.clone();
+        let shrunk_elements = (0..self.vecgrid.len()).flat_map(move |index| {
+            let original = original.clone();
+            original.vecgrid[index].shrink().map(move |element| {
+                let mut vecgrid = original.clone();
+                vecgrid.vecgrid[index] = element;
+                vecgrid
+            })
+        });
+        Box::new(smaller.into_iter().chain(shrunk_elements))
+    }
+}
+
+impl<T: Clone> Vecgrid<T> {
+    fn without_row(&self, row: usize) -> Self {
+        let mut vecgrid = self.clone();
+        let start = row * self.num_columns;
+        vecgrid.vecgrid.drain(start..start + self.num_columns);
+        vecgrid.num_rows -= 1;
+        vecgrid
+    }
+
+    fn without_column(&self, column: usize) -> Self {
+        let vecgrid = self
+            .vecgrid
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| index % self.num_columns != column)
+            .map(|(_, element)| element.clone())
+            .collect();
+        Vecgrid {
+            vecgrid,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns - 1,
+        }
+    }
+}
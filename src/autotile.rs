@@ -0,0 +1,100 @@
+//! Adjacency bitmasks for auto-tiling, where the tile drawn for a cell
+//! depends on which of its neighbors are of the same kind.
+
+use crate::{Error, Neighborhood, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Returns the adjacency bitmask of the cell at `row` and `column`: bit
+    /// `i` is set if the `i`-th neighbor in `neighborhood` is in bounds and
+    /// `matches` returns `true` for it. Returns an error if the indices are
+    /// out of bounds.
+    ///
+    /// Neighbors are numbered clockwise starting from the cell above, so
+    /// with [`Neighborhood::Four`] north, east, south and west are `1`, `2`,
+    /// `4` and `8`, and with [`Neighborhood::Eight`] north, north-east,
+    /// east, south-east, south, south-west, west and north-west are `1`
+    /// through `128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Neighborhood, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['.', '#', '.'],
+    ///     vec!['.', '#', '#'],
+    ///     vec!['.', '.', '#'],
+    /// ])?;
+    /// let is_wall = |&cell: &char| cell == '#';
+    /// assert_eq!(map.neighbor_bitmask(1, 1, Neighborhood::Four, is_wall)?, 0b0011);
+    /// assert_eq!(map.neighbor_bitmask(1, 1, Neighborhood::Eight, is_wall)?, 0b1101);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Neighborhood::Four`]: enum.Neighborhood.html#variant.Four
+    /// [`Neighborhood::Eight`]: enum.Neighborhood.html#variant.Eight
+    pub fn neighbor_bitmask<F>(
+        &self,
+        row: usize,
+        column: usize,
+        neighborhood: Neighborhood,
+        matches: F,
+    ) -> Result<u8, Error>
+    where
+        F: Fn(&T) -> bool,
+    {
+        if row >= self.num_rows || column >= self.num_columns {
+            return Err(self.indices_out_of_bounds(row, column));
+        }
+        Ok(self.bitmask(row, column, neighborhood, |index| {
+            matches(&self.vecgrid[index])
+        }))
+    }
+
+    /// Returns the adjacency bitmask of every cell, as computed by
+    /// [`neighbor_bitmask`], calling `matches` only once per cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Neighborhood, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![vec!['#', '#'], vec!['.', '#']])?;
+    /// let masks = map.map_to_bitmasks(Neighborhood::Four, |&cell| cell == '#');
+    /// assert_eq!(masks.as_rows(), vec![vec![0b0010, 0b1100], vec![0b0011, 0b0001]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`neighbor_bitmask`]: struct.Vecgrid.html#method.neighbor_bitmask
+    pub fn map_to_bitmasks<F>(&self, neighborhood: Neighborhood, matches: F) -> Vecgrid<u8>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let matching = self.vecgrid.iter().map(matches).collect::<Vec<_>>();
+        Vecgrid {
+            vecgrid: (0..self.vecgrid.len())
+                .map(|index| {
+                    let (row, column) = self.position(index);
+                    self.bitmask(row, column, neighborhood, |index| matching[index])
+                })
+                .collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    fn bitmask<F>(&self, row: usize, column: usize, neighborhood: Neighborhood, matches: F) -> u8
+    where
+        F: Fn(usize) -> bool,
+    {
+        neighborhood
+            .neighbors(row, column, self.num_rows, self.num_columns)
+            .enumerate()
+            .fold(0, |mask, (bit, neighbor)| match neighbor {
+                Some((row, column)) if matches(row * self.num_columns + column) => mask | 1 << bit,
+                _ => mask,
+            })
+    }
+}
@@ -0,0 +1,359 @@
+//! Disjoint mutable views and owned chunks of consecutive rows of a
+//! [`Vecgrid`], and scoped multi-threaded processing built on them.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, GridIndex, Vecgrid};
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+/// A mutable view of consecutive, complete rows of a [`Vecgrid`], as
+/// returned by [`row_bands`] and [`LayeredVecgrid::layer_mut`].
+///
+/// Rows and columns are indexed relative to the band, so the first row of
+/// every band is row `0`; [`first_row`] gives the row the band starts at in
+/// the vecgrid.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`row_bands`]: struct.Vecgrid.html#method.row_bands
+/// [`first_row`]: struct.RowBand.html#method.first_row
+/// [`LayeredVecgrid::layer_mut`]: struct.LayeredVecgrid.html#method.layer_mut
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct RowBand<'a, T> {
+    elements: &'a mut [T],
+    first_row: usize,
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl<'a, T> RowBand<'a, T> {
+    /// The index, in the vecgrid, of the first row of the band.
+    pub fn first_row(&self) -> usize {
+        self.first_row
+    }
+
+    /// The number of rows in the band.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the band, the same as in the vecgrid.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// Returns a reference to the element at the given `row`, relative to
+    /// the band, and `column`, or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.get_index(row, column)
+            .map(|index| &self.elements[index])
+    }
+
+    /// Returns a mutable reference to the element at the given `row`,
+    /// relative to the band, and `column`, or [`None`] if the index is out
+    /// of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.get_index(row, column)
+            .map(move |index| &mut self.elements[index])
+    }
+
+    /// Returns the elements of the band in [row major order].
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_row_major_slice(&self) -> &[T] {
+        self.elements
+    }
+
+    /// Returns the elements of the band in [row major order] as a mutable
+    /// slice.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_mut_row_major_slice(&mut self) -> &mut [T] {
+        self.elements
+    }
+
+    /// Consumes the band, returning its elements in [row major order] with
+    /// the lifetime of the borrowed vecgrid.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn into_row_major_slice(self) -> &'a mut [T] {
+        self.elements
+    }
+
+    fn get_index(&self, row: usize, column: usize) -> Option<usize> {
+        if row < self.num_rows && column < self.num_columns {
+            Some(row * self.num_columns + column)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// A band over every row of the vecgrid, which can modify the elements
+    /// but not the dimensions.
+    pub(crate) fn as_band(&mut self) -> RowBand<'_, T> {
+        RowBand {
+            elements: &mut self.vecgrid,
+            first_row: 0,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Splits the vecgrid into disjoint mutable [`RowBand`]s of
+    /// `band_height` consecutive rows each, from top to bottom. The last
+    /// band has fewer rows if `band_height` does not divide the number of
+    /// rows.
+    ///
+    /// Since the bands do not overlap they can be modified at the same time,
+    /// for example from different scoped threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `band_height` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(0, 5, 3);
+    /// std::thread::scope(|scope| {
+    ///     for mut band in vecgrid.row_bands(2) {
+    ///         scope.spawn(move || {
+    ///             let first_row = band.first_row();
+    ///             for element in band.as_mut_row_major_slice() {
+    ///                 *element = first_row;
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(vecgrid.column_iter(0)?.copied().collect::<Vec<_>>(), vec![0, 0, 2, 2, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RowBand`]: struct.RowBand.html
+    pub fn row_bands(&mut self, band_height: usize) -> impl Iterator<Item = RowBand<'_, T>> {
+        assert!(band_height > 0, "band height must be greater than 0");
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        let mut rest = self.vecgrid.as_mut_slice();
+        let mut first_row = 0;
+        std::iter::from_fn(move || {
+            if first_row >= num_rows {
+                return None;
+            }
+            let band_rows = band_height.min(num_rows - first_row);
+            let (elements, tail) = mem::take(&mut rest).split_at_mut(band_rows * num_columns);
+            rest = tail;
+            let band = RowBand {
+                elements,
+                first_row,
+                num_rows: band_rows,
+                num_columns,
+            };
+            first_row += band_rows;
+            Some(band)
+        })
+    }
+
+    /// Calls `f` with the index and the elements of every row, spreading
+    /// the rows over `num_threads` scoped threads. Every thread handles one
+    /// band of consecutive rows, see [`row_bands`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_threads` is `0`, or if `f` panics on any thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(1, 4, 3);
+    /// vecgrid.for_each_row_parallel(2, |row_index, row| {
+    ///     for element in row {
+    ///         *element *= row_index;
+    ///     }
+    /// });
+    /// assert_eq!(vecgrid.column_iter(0)?.copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`row_bands`]: struct.Vecgrid.html#method.row_bands
+    pub fn for_each_row_parallel<F>(&mut self, num_threads: usize, f: F)
+    where
+        T: Send,
+        F: Fn(usize, &mut [T]) + Sync,
+    {
+        assert!(num_threads > 0, "number of threads must be greater than 0");
+        let band_height = self.num_rows.div_ceil(num_threads).max(1);
+        let num_columns = self.num_columns;
+        let f = &f;
+        std::thread::scope(|scope| {
+            for band in self.row_bands(band_height) {
+                scope.spawn(move || {
+                    let (first_row, num_rows) = (band.first_row(), band.num_rows());
+                    let elements = band.into_row_major_slice();
+                    for row in 0..num_rows {
+                        f(
+                            first_row + row,
+                            &mut elements[row * num_columns..][..num_columns],
+                        );
+                    }
+                });
+            }
+        });
+    }
+
+    /// Splits the vecgrid into `num_chunks` owned vecgrids of consecutive
+    /// rows, from top to bottom, for example to hand them to other threads
+    /// or processes. The rows are spread as evenly as possible, with the
+    /// first chunks getting one more row if `num_chunks` does not divide the
+    /// number of rows, and chunks without rows if there are more chunks than
+    /// rows. Every chunk keeps the number of columns.
+    ///
+    /// The elements are moved, not cloned; [`from_row_chunks`] puts the
+    /// chunks back together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_chunks` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// let chunks = vecgrid.into_row_chunks(2);
+    /// assert_eq!(chunks[0].as_rows(), vec![vec![1, 2], vec![3, 4]]);
+    /// assert_eq!(chunks[1].as_rows(), vec![vec![5, 6]]);
+    ///
+    /// let handles = chunks
+    ///     .into_iter()
+    ///     .map(|mut chunk| {
+    ///         std::thread::spawn(move || {
+    ///             chunk.elements_row_major_iter_mut().for_each(|element| *element *= 10);
+    ///             chunk
+    ///         })
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let chunks = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+    /// let vecgrid = Vecgrid::from_row_chunks(chunks)?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![10, 20], vec![30, 40], vec![50, 60]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`from_row_chunks`]: struct.Vecgrid.html#method.from_row_chunks
+    pub fn into_row_chunks(self, num_chunks: usize) -> Vec<Vecgrid<T>> {
+        assert!(num_chunks > 0, "number of chunks must be greater than 0");
+        let (rows_per_chunk, extra_rows) = (self.num_rows / num_chunks, self.num_rows % num_chunks);
+        let num_columns = self.num_columns;
+        let mut elements = self.vecgrid;
+        let mut end_row = self.num_rows;
+        // Splitting off from the back moves every element at most once.
+        let mut chunks = (0..num_chunks)
+            .rev()
+            .map(|chunk| {
+                let num_rows = rows_per_chunk + usize::from(chunk < extra_rows);
+                end_row -= num_rows;
+                Vecgrid {
+                    vecgrid: elements.split_off(end_row * num_columns),
+                    num_rows,
+                    num_columns,
+                }
+            })
+            .collect::<Vec<_>>();
+        chunks.reverse();
+        chunks
+    }
+
+    /// Creates a new [`Vecgrid`] by placing the given chunks on top of each
+    /// other, from top to bottom, moving their elements instead of cloning
+    /// them. This is the inverse of [`into_row_chunks`]. Returns an error if
+    /// the chunks do not all have the same number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let top = Vecgrid::from_rows(vec![vec![1, 2]])?;
+    /// let bottom = Vecgrid::from_rows(vec![vec![3, 4], vec![5, 6]])?;
+    /// let vecgrid = Vecgrid::from_row_chunks(vec![top, bottom])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`into_row_chunks`]: struct.Vecgrid.html#method.into_row_chunks
+    pub fn from_row_chunks(chunks: Vec<Vecgrid<T>>) -> Result<Vecgrid<T>, Error> {
+        let num_columns = chunks.first().map_or(0, Vecgrid::num_columns);
+        if let Some((index, chunk)) = chunks
+            .iter()
+            .enumerate()
+            .find(|(_, chunk)| chunk.num_columns != num_columns)
+        {
+            return Err(Error::DimensionMismatch {
+                expected: num_columns,
+                actual: chunk.num_columns,
+                index: Some(index),
+            });
+        }
+        let num_rows = chunks.iter().map(Vecgrid::num_rows).sum();
+        let mut chunks = chunks.into_iter();
+        let mut vecgrid = chunks.next().map_or_else(Vec::new, |chunk| chunk.vecgrid);
+        vecgrid.reserve_exact(num_rows * num_columns - vecgrid.len());
+        for chunk in chunks {
+            vecgrid.extend(chunk.vecgrid);
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        })
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for RowBand<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)` or
+    /// any other [`GridIndex`], relative to the band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for RowBand<'_, T> {
+    /// Returns a mutable version of the element at the given indices, given
+    /// as `(row, column)` or any other [`GridIndex`], relative to the band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
@@ -0,0 +1,167 @@
+//! Applying a one-dimensional vector across every row or every column of a
+//! [`Vecgrid`], such as subtracting the mean of every column.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::ops::{Add, Mul};
+
+impl<T> Vecgrid<T> {
+    /// Calls `f` with every element and the element of `row` in the same
+    /// column, applying `row` across every row of the vecgrid.
+    ///
+    /// Returns an error, without calling `f`, if the length of `row` does
+    /// not equal the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut samples = Vecgrid::from_rows(vec![vec![1.0, 10.0], vec![3.0, 30.0]])?;
+    /// let means = vec![2.0, 20.0];
+    /// samples.broadcast_row(&means, |sample, &mean| *sample /= mean)?;
+    /// assert_eq!(samples.as_rows(), vec![vec![0.5, 0.5], vec![1.5, 1.5]]);
+    /// assert!(samples.broadcast_row(&[1.0], |_, _| ()).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn broadcast_row<U, F>(&mut self, row: &[U], mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut T, &U),
+    {
+        if row.len() != self.num_columns {
+            return Err(Error::dimension_mismatch(self.num_columns, row.len()));
+        }
+        if self.num_columns > 0 {
+            for elements in self.vecgrid.chunks_exact_mut(self.num_columns) {
+                for (element, operand) in elements.iter_mut().zip(row) {
+                    f(element, operand);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls `f` with every element and the element of `column` in the
+    /// same row, applying `column` across every column of the vecgrid.
+    ///
+    /// Returns an error, without calling `f`, if the length of `column`
+    /// does not equal the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut scores = Vecgrid::from_rows(vec![vec![5, 7], vec![2, 9]])?;
+    /// scores.broadcast_column(&[5, 2], |score, &baseline| *score -= baseline)?;
+    /// assert_eq!(scores.as_rows(), vec![vec![0, 2], vec![0, 7]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn broadcast_column<U, F>(&mut self, column: &[U], mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut T, &U),
+    {
+        if column.len() != self.num_rows {
+            return Err(Error::dimension_mismatch(self.num_rows, column.len()));
+        }
+        if self.num_columns > 0 {
+            for (elements, operand) in self.vecgrid.chunks_exact_mut(self.num_columns).zip(column) {
+                for element in elements {
+                    f(element, operand);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the element of `row` in the same column to every element.
+    /// Returns an error, without changing any element, if the length of
+    /// `row` does not equal the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// vecgrid.add_row_vector(&[10, 20, 30])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![11, 22, 33], vec![14, 25, 36]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_row_vector(&mut self, row: &[T]) -> Result<(), Error>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.broadcast_row(row, |element, &addend| *element = *element + addend)
+    }
+
+    /// Adds the element of `column` in the same row to every element.
+    /// Returns an error, without changing any element, if the length of
+    /// `column` does not equal the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// vecgrid.add_column_vector(&[10, 20])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![11, 12, 13], vec![24, 25, 26]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_column_vector(&mut self, column: &[T]) -> Result<(), Error>
+    where
+        T: Copy + Add<Output = T>,
+    {
+        self.broadcast_column(column, |element, &addend| *element = *element + addend)
+    }
+
+    /// Multiplies every element by the element of `row` in the same column.
+    /// Returns an error, without changing any element, if the length of
+    /// `row` does not equal the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// vecgrid.mul_row_vector(&[1, 0, -1])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 0, -3], vec![4, 0, -6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mul_row_vector(&mut self, row: &[T]) -> Result<(), Error>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        self.broadcast_row(row, |element, &factor| *element = *element * factor)
+    }
+
+    /// Multiplies every element by the element of `column` in the same row.
+    /// Returns an error, without changing any element, if the length of
+    /// `column` does not equal the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// vecgrid.mul_column_vector(&[2, -1])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![2, 4, 6], vec![-4, -5, -6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mul_column_vector(&mut self, column: &[T]) -> Result<(), Error>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        self.broadcast_column(column, |element, &factor| *element = *element * factor)
+    }
+}
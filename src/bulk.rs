@@ -0,0 +1,107 @@
+//! Bulk operations over the whole buffer of [`Vecgrid`]s of primitive
+//! numbers, written so the compiler can vectorize them.
+//!
+//! The loops work on fixed size lanes of the row major buffer without
+//! bounds checks or early exits, which lets LLVM emit SIMD instructions on
+//! every target without any `unsafe` or target specific code. Equality needs
+//! no fast path: the derived `PartialEq` compares the underlying buffers,
+//! which the standard library already lowers to `memcmp` for integers.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+
+const LANES: usize = 16;
+
+macro_rules! impl_bulk_ops {
+    ($($element:ty => $sum:ty, $add:ident);*) => {
+        $(
+            impl Vecgrid<$element> {
+                #[doc = concat!(
+                    "Returns the sum of all elements as a `", stringify!($sum), "`, ",
+                    "accumulating in independent lanes so the loop is vectorized."
+                )]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                #[doc = concat!(
+                    "let vecgrid = Vecgrid::<", stringify!($element), ">::from_iter_row_major(",
+                    "std::iter::repeat(2 as ", stringify!($element), "), 3, 40)?;"
+                )]
+                #[doc = concat!("assert_eq!(vecgrid.sum(), 240 as ", stringify!($sum), ");")]
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn sum(&self) -> $sum {
+                    let mut lanes = [<$sum>::default(); LANES];
+                    let mut chunks = self.vecgrid.chunks_exact(LANES);
+                    for chunk in &mut chunks {
+                        for (lane, &element) in lanes.iter_mut().zip(chunk) {
+                            *lane += element as $sum;
+                        }
+                    }
+                    let remainder = chunks
+                        .remainder()
+                        .iter()
+                        .fold(<$sum>::default(), |sum, &element| sum + element as $sum);
+                    lanes.iter().fold(remainder, |sum, &lane| sum + lane)
+                }
+
+                /// Adds every element of `other` to the element at the same
+                /// position. Integers wrap around on overflow. Returns an
+                /// error, without changing any element, if the dimensions do
+                /// not match.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                #[doc = concat!(
+                    "let mut vecgrid = Vecgrid::<", stringify!($element), ">::filled_with(1 as ",
+                    stringify!($element), ", 2, 2);"
+                )]
+                #[doc = concat!(
+                    "let other = Vecgrid::<", stringify!($element), ">::filled_with(2 as ",
+                    stringify!($element), ", 2, 2);"
+                )]
+                /// vecgrid.add_elementwise(&other)?;
+                #[doc = concat!(
+                    "assert_eq!(vecgrid, Vecgrid::filled_with(3 as ", stringify!($element), ", 2, 2));"
+                )]
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn add_elementwise(&mut self, other: &Vecgrid<$element>) -> Result<(), Error> {
+                    if (self.num_rows, self.num_columns) != (other.num_rows, other.num_columns) {
+                        return Err(Error::shape_mismatch(
+                            (self.num_rows, self.num_columns),
+                            (other.num_rows, other.num_columns),
+                        ));
+                    }
+                    for (element, &addend) in self.vecgrid.iter_mut().zip(&other.vecgrid) {
+                        *element = $add(*element, addend);
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+fn wrapping_add_u8(a: u8, b: u8) -> u8 {
+    a.wrapping_add(b)
+}
+
+fn wrapping_add_i32(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+fn add_f32(a: f32, b: f32) -> f32 {
+    a + b
+}
+
+impl_bulk_ops!(u8 => u64, wrapping_add_u8; i32 => i64, wrapping_add_i32; f32 => f32, add_f32);
@@ -0,0 +1,126 @@
+//! Splitting a [`Vecgrid`] whose rows interleave several channels, such as
+//! the RGBA components of pixels, into one vecgrid per channel and back.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Splits the vecgrid into `num_channels` vecgrids, where every group of
+    /// `num_channels` consecutive columns holds one element of each channel.
+    /// Channel `k` gets the columns `k`, `k + num_channels`, and so on.
+    ///
+    /// Returns an error if the number of columns is not a multiple of
+    /// `num_channels`, with the next multiple as the expected number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_channels` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rgb = Vecgrid::from_rows(vec![vec![255, 0, 0, 0, 128, 0]])?;
+    /// let channels = rgb.deinterleave(3)?;
+    /// assert_eq!(channels[0].as_rows(), vec![vec![255, 0]]);
+    /// assert_eq!(channels[1].as_rows(), vec![vec![0, 128]]);
+    /// assert_eq!(Vecgrid::interleave(&channels)?, rgb);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deinterleave(&self, num_channels: usize) -> Result<Vec<Vecgrid<T>>, Error>
+    where
+        T: Clone,
+    {
+        assert!(
+            num_channels > 0,
+            "number of channels must be greater than 0"
+        );
+        if !self.num_columns.is_multiple_of(num_channels) {
+            return Err(Error::dimension_mismatch(
+                self.num_columns.next_multiple_of(num_channels),
+                self.num_columns,
+            ));
+        }
+        let num_columns = self.num_columns / num_channels;
+        let mut channels = (0..num_channels)
+            .map(|_| Vec::with_capacity(self.num_rows * num_columns))
+            .collect::<Vec<_>>();
+        for group in self.vecgrid.chunks_exact(num_channels) {
+            for (channel, element) in channels.iter_mut().zip(group) {
+                channel.push(element.clone());
+            }
+        }
+        Ok(channels
+            .into_iter()
+            .map(|vecgrid| Vecgrid {
+                vecgrid,
+                num_rows: self.num_rows,
+                num_columns,
+            })
+            .collect())
+    }
+
+    /// Creates a new [`Vecgrid`] by interleaving the columns of the given
+    /// channels, the inverse of [`deinterleave`]: column `c` of channel `k`
+    /// becomes column `c * channels.len() + k`.
+    ///
+    /// Returns an error if the channels do not all have the same number of
+    /// rows and columns, with the index of the first one that differs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let left = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// let right = Vecgrid::from_rows(vec![vec![-1, -2], vec![-3, -4]])?;
+    /// let stereo = Vecgrid::interleave(&[left, right])?;
+    /// assert_eq!(stereo.as_rows(), vec![vec![1, -1, 2, -2], vec![3, -3, 4, -4]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`deinterleave`]: struct.Vecgrid.html#method.deinterleave
+    pub fn interleave(channels: &[Vecgrid<T>]) -> Result<Vecgrid<T>, Error>
+    where
+        T: Clone,
+    {
+        let (num_rows, num_columns) = check_channels(channels)?;
+        let mut vecgrid = Vec::with_capacity(num_rows * num_columns * channels.len());
+        for index in 0..num_rows * num_columns {
+            vecgrid.extend(
+                channels
+                    .iter()
+                    .map(|channel| channel.vecgrid[index].clone()),
+            );
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns: num_columns * channels.len(),
+        })
+    }
+}
+
+/// Returns the dimensions shared by all `channels`, or an error with the
+/// index of the first channel that differs from the first one.
+pub(crate) fn check_channels<T>(channels: &[Vecgrid<T>]) -> Result<(usize, usize), Error> {
+    let (num_rows, num_columns) = channels
+        .first()
+        .map_or((0, 0), |channel| (channel.num_rows, channel.num_columns));
+    if let Some(index) = channels
+        .iter()
+        .position(|channel| (channel.num_rows, channel.num_columns) != (num_rows, num_columns))
+    {
+        return Err(Error::ShapeMismatch {
+            expected: (num_rows, num_columns),
+            actual: (channels[index].num_rows, channels[index].num_columns),
+            index: Some(index),
+        });
+    }
+    Ok((num_rows, num_columns))
+}
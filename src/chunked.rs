@@ -0,0 +1,197 @@
+//! An unbounded grid stored as a sparse map of fixed-size [`Vecgrid`]
+//! chunks.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::collections::HashMap;
+
+/// An unbounded grid addressed with signed `(x, y)` coordinates, where `x`
+/// follows the columns and `y` the rows. The cells are stored in chunks,
+/// [`Vecgrid`]s of a fixed size, that are created on demand the first time
+/// one of their cells is written.
+///
+/// Cells of chunks that were never created read as the fill element given
+/// to [`new`], which new chunks are filled with too.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::ChunkedGrid;
+/// let mut world = ChunkedGrid::new(16, 16, '.');
+/// world.set(-1000, 5000, '#');
+/// assert_eq!(world.get(-1000, 5000), &'#');
+/// assert_eq!(world.get(1_000_000, -7), &'.');
+/// assert_eq!(world.num_chunks(), 1);
+/// assert_eq!(world.chunk_coordinates(-1000, 5000), (-63, 312));
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`new`]: struct.ChunkedGrid.html#method.new
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChunkedGrid<T> {
+    chunks: HashMap<(isize, isize), Vecgrid<T>>,
+    chunk_rows: usize,
+    chunk_columns: usize,
+    fill: T,
+}
+
+impl<T> ChunkedGrid<T> {
+    /// Creates an empty grid of chunks with `chunk_rows` rows and
+    /// `chunk_columns` columns each, where every cell reads as `fill` until
+    /// it is written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either chunk dimension is `0`, or does not fit in an
+    /// `isize`.
+    pub fn new(chunk_rows: usize, chunk_columns: usize, fill: T) -> Self {
+        assert!(
+            chunk_rows > 0 && chunk_columns > 0,
+            "chunk dimensions must be greater than 0"
+        );
+        assert!(
+            isize::try_from(chunk_rows).is_ok() && isize::try_from(chunk_columns).is_ok(),
+            "chunk dimensions must fit in an isize"
+        );
+        ChunkedGrid {
+            chunks: HashMap::new(),
+            chunk_rows,
+            chunk_columns,
+            fill,
+        }
+    }
+
+    /// The number of rows of every chunk.
+    pub fn chunk_rows(&self) -> usize {
+        self.chunk_rows
+    }
+
+    /// The number of columns of every chunk.
+    pub fn chunk_columns(&self) -> usize {
+        self.chunk_columns
+    }
+
+    /// The number of chunks that have been created.
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the coordinates, as `(chunk_x, chunk_y)`, of the chunk that
+    /// contains the cell at `x` and `y`.
+    pub fn chunk_coordinates(&self, x: isize, y: isize) -> (isize, isize) {
+        (
+            x.div_euclid(self.chunk_columns as isize),
+            y.div_euclid(self.chunk_rows as isize),
+        )
+    }
+
+    /// Returns a reference to the element at `x` and `y`, or to the fill
+    /// element if its chunk has not been created.
+    pub fn get(&self, x: isize, y: isize) -> &T {
+        let (chunk, row, column) = self.locate(x, y);
+        self.chunks
+            .get(&chunk)
+            .map_or(&self.fill, |chunk| &chunk[(row, column)])
+    }
+
+    /// Returns a mutable reference to the element at `x` and `y`, creating
+    /// its chunk filled with the fill element if needed.
+    pub fn get_mut(&mut self, x: isize, y: isize) -> &mut T
+    where
+        T: Clone,
+    {
+        let (chunk, row, column) = self.locate(x, y);
+        let (chunk_rows, chunk_columns) = (self.chunk_rows, self.chunk_columns);
+        let fill = &self.fill;
+        let chunk = self
+            .chunks
+            .entry(chunk)
+            .or_insert_with(|| Vecgrid::filled_with(fill.clone(), chunk_rows, chunk_columns));
+        &mut chunk[(row, column)]
+    }
+
+    /// Changes the element at `x` and `y` to `element`, creating its chunk
+    /// filled with the fill element if needed.
+    pub fn set(&mut self, x: isize, y: isize, element: T)
+    where
+        T: Clone,
+    {
+        *self.get_mut(x, y) = element;
+    }
+
+    /// Returns the chunk at the chunk coordinates `chunk_x` and `chunk_y`,
+    /// or [`None`] if it has not been created.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn chunk(&self, chunk_x: isize, chunk_y: isize) -> Option<&Vecgrid<T>> {
+        self.chunks.get(&(chunk_x, chunk_y))
+    }
+
+    /// Returns a mutable reference to the chunk at the chunk coordinates
+    /// `chunk_x` and `chunk_y`, or [`None`] if it has not been created.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn chunk_mut(&mut self, chunk_x: isize, chunk_y: isize) -> Option<&mut Vecgrid<T>> {
+        self.chunks.get_mut(&(chunk_x, chunk_y))
+    }
+
+    /// Inserts `chunk`, for example one loaded from disk, at the chunk
+    /// coordinates `chunk_x` and `chunk_y`, returning the chunk it replaces.
+    /// Returns an error if the dimensions of `chunk` are not the chunk
+    /// dimensions of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{ChunkedGrid, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut world = ChunkedGrid::new(2, 2, 0);
+    /// world.insert_chunk(-1, 0, Vecgrid::filled_with(7, 2, 2))?;
+    /// assert_eq!(world.get(-1, 1), &7);
+    /// assert!(world.insert_chunk(0, 0, Vecgrid::filled_with(7, 3, 3)).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn insert_chunk(
+        &mut self,
+        chunk_x: isize,
+        chunk_y: isize,
+        chunk: Vecgrid<T>,
+    ) -> Result<Option<Vecgrid<T>>, Error> {
+        if (chunk.num_rows(), chunk.num_columns()) != (self.chunk_rows, self.chunk_columns) {
+            return Err(Error::shape_mismatch(
+                (self.chunk_rows, self.chunk_columns),
+                (chunk.num_rows(), chunk.num_columns()),
+            ));
+        }
+        Ok(self.chunks.insert((chunk_x, chunk_y), chunk))
+    }
+
+    /// Removes and returns the chunk at the chunk coordinates `chunk_x` and
+    /// `chunk_y`, for example to unload it, or [`None`] if it has not been
+    /// created. Its cells read as the fill element again.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn remove_chunk(&mut self, chunk_x: isize, chunk_y: isize) -> Option<Vecgrid<T>> {
+        self.chunks.remove(&(chunk_x, chunk_y))
+    }
+
+    /// Returns an [`Iterator`] over the chunk coordinates, as `(chunk_x,
+    /// chunk_y)`, and references to all created chunks, in arbitrary order.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn chunks(&self) -> impl Iterator<Item = ((isize, isize), &Vecgrid<T>)> {
+        self.chunks
+            .iter()
+            .map(|(&coordinates, chunk)| (coordinates, chunk))
+    }
+
+    fn locate(&self, x: isize, y: isize) -> ((isize, isize), usize, usize) {
+        let (chunk_rows, chunk_columns) = (self.chunk_rows as isize, self.chunk_columns as isize);
+        let chunk = (x.div_euclid(chunk_columns), y.div_euclid(chunk_rows));
+        let row = y.rem_euclid(chunk_rows) as usize;
+        let column = x.rem_euclid(chunk_columns) as usize;
+        (chunk, row, column)
+    }
+}
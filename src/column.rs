@@ -0,0 +1,204 @@
+//! Transforming a [`Vecgrid`] one whole row or column at a time, with the
+//! index of the line.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, IndexMut};
+
+/// A mutable borrow of one column of a [`Vecgrid`], as passed to the
+/// function of [`map_columns_in_place`]. The elements are indexed by their
+/// row, from top to bottom.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`map_columns_in_place`]: struct.Vecgrid.html#method.map_columns_in_place
+pub struct ColumnMut<'a, T> {
+    /// The elements from the top of the column to the end of the vecgrid.
+    elements: &'a mut [T],
+    stride: usize,
+    len: usize,
+}
+
+impl<T> ColumnMut<'_, T> {
+    /// The number of elements in the column, the number of rows of the
+    /// vecgrid.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the column has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element in the given `row`, or [`None`]
+    /// if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize) -> Option<&T> {
+        (row < self.len).then(|| &self.elements[row * self.stride])
+    }
+
+    /// Returns a mutable reference to the element in the given `row`, or
+    /// [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize) -> Option<&mut T> {
+        (row < self.len).then(|| &mut self.elements[row * self.stride])
+    }
+
+    /// Swaps the elements in rows `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.len && b < self.len,
+            "Swap indices {}, {} out of bounds",
+            a,
+            b
+        );
+        self.elements.swap(a * self.stride, b * self.stride);
+    }
+
+    /// Returns an [`Iterator`] over references to the elements of the
+    /// column, from top to bottom.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
+        self.elements.iter().step_by(self.stride).take(self.len)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to the elements of
+    /// the column, from top to bottom.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.elements.iter_mut().step_by(self.stride).take(self.len)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ColumnMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Columns are equal if they have the same elements, wherever they are.
+impl<T: PartialEq> PartialEq for ColumnMut<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ColumnMut<'_, T> {}
+
+impl<T: Hash> Hash for ColumnMut<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for element in self.iter() {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T> Index<usize> for ColumnMut<'_, T> {
+    type Output = T;
+
+    /// Returns the element in the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index(&self, row: usize) -> &Self::Output {
+        self.get(row)
+            .unwrap_or_else(|| panic!("Index {} out of bounds", row))
+    }
+}
+
+impl<T> IndexMut<usize> for ColumnMut<'_, T> {
+    /// Returns a mutable version of the element in the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        self.get_mut(row)
+            .unwrap_or_else(|| panic!("Index mut {} out of bounds", row))
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Calls `f` with the index and the elements of every row, from top to
+    /// bottom, so a whole row can be transformed at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// vecgrid.map_rows_in_place(|row, elements| elements.rotate_right(row + 1));
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![3, 1, 2], vec![5, 6, 4]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_rows_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        if self.num_columns == 0 {
+            for row in 0..self.num_rows {
+                f(row, &mut []);
+            }
+            return;
+        }
+        for (row, elements) in self.vecgrid.chunks_exact_mut(self.num_columns).enumerate() {
+            f(row, elements);
+        }
+    }
+
+    /// Calls `f` with the index and a [`ColumnMut`] of every column, from
+    /// left to right, so a whole column can be transformed at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// vecgrid.map_columns_in_place(|column, mut elements| {
+    ///     if column == 1 {
+    ///         elements.swap(0, 1);
+    ///     }
+    ///     for element in elements.iter_mut() {
+    ///         *element *= 10;
+    ///     }
+    /// });
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![10, 50, 30], vec![40, 20, 60]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ColumnMut`]: struct.ColumnMut.html
+    pub fn map_columns_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, ColumnMut<'_, T>),
+    {
+        for column in 0..self.num_columns {
+            // A vecgrid without rows has no elements to start a column at.
+            let start = column.min(self.vecgrid.len());
+            f(
+                column,
+                ColumnMut {
+                    elements: &mut self.vecgrid[start..],
+                    stride: self.num_columns,
+                    len: self.num_rows,
+                },
+            );
+        }
+    }
+}
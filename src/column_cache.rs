@@ -0,0 +1,242 @@
+//! A [`Vecgrid`] wrapper that keeps a column major copy of its elements for
+//! workloads that alternate between row and column access.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, GridIndex, Vecgrid};
+use std::cell::OnceCell;
+use std::ops::{Deref, Index, IndexMut};
+
+/// A wrapper around a [`Vecgrid`] that lazily builds a copy of its elements
+/// in [column major order], so every column is a contiguous slice.
+///
+/// The copy is built on first use and reused until the next mutation
+/// through the wrapper, so repeated column passes over an unchanged grid do
+/// not allocate or copy again, unlike calling [`as_column_major`] each
+/// time. Read access to the row major grid is available through [`Deref`];
+/// all mutation goes through the wrapper so that the copy is discarded
+/// whenever an element changes.
+///
+/// The copy holds clones of the elements, so it is not updated when
+/// elements with interior mutability, such as [`Cell`]s, are changed through
+/// shared references. Call [`discard_column_major_cache`] after such
+/// changes.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{ColumnCachedVecgrid, Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+/// let mut cached = ColumnCachedVecgrid::new(vecgrid);
+/// assert_eq!(cached.columns_flat(), &[1, 4, 2, 5, 3, 6]);
+/// assert_eq!(cached.column_slice(1)?, &[2, 5]);
+///
+/// cached[(0, 1)] = 20;
+/// assert!(!cached.is_column_major_cached());
+/// assert_eq!(cached.column_slice(1)?, &[20, 5]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`as_column_major`]: struct.Vecgrid.html#method.as_column_major
+/// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`discard_column_major_cache`]: struct.ColumnCachedVecgrid.html#method.discard_column_major_cache
+/// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Clone)]
+pub struct ColumnCachedVecgrid<T> {
+    vecgrid: Vecgrid<T>,
+    column_major: OnceCell<Vec<T>>,
+}
+
+impl<T> ColumnCachedVecgrid<T> {
+    /// Wraps `vecgrid`, without building the column major copy yet.
+    pub fn new(vecgrid: Vecgrid<T>) -> Self {
+        ColumnCachedVecgrid {
+            vecgrid,
+            column_major: OnceCell::new(),
+        }
+    }
+
+    /// Unwraps the underlying [`Vecgrid`], discarding the column major copy.
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn into_inner(self) -> Vecgrid<T> {
+        self.vecgrid
+    }
+
+    /// Builds the column major copy if it is not up to date, so later calls
+    /// to [`columns_flat`] and [`column_slice`] only borrow it.
+    ///
+    /// [`columns_flat`]: struct.ColumnCachedVecgrid.html#method.columns_flat
+    /// [`column_slice`]: struct.ColumnCachedVecgrid.html#method.column_slice
+    pub fn ensure_column_major_cache(&self)
+    where
+        T: Clone,
+    {
+        self.columns_flat();
+    }
+
+    /// Returns `true` if the column major copy is up to date and will not be
+    /// rebuilt by the next call to [`columns_flat`].
+    ///
+    /// [`columns_flat`]: struct.ColumnCachedVecgrid.html#method.columns_flat
+    pub fn is_column_major_cached(&self) -> bool {
+        self.column_major.get().is_some()
+    }
+
+    /// Discards the column major copy, so the next call to [`columns_flat`]
+    /// rebuilds it from the current elements.
+    ///
+    /// [`columns_flat`]: struct.ColumnCachedVecgrid.html#method.columns_flat
+    pub fn discard_column_major_cache(&mut self) {
+        self.column_major.take();
+    }
+
+    /// Returns all elements as one slice in [column major order], building
+    /// the column major copy first if it is not up to date.
+    ///
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn columns_flat(&self) -> &[T]
+    where
+        T: Clone,
+    {
+        self.column_major
+            .get_or_init(|| self.vecgrid.as_column_major())
+    }
+
+    /// Returns the elements of the given column as one contiguous slice,
+    /// building the column major copy first if it is not up to date.
+    /// Returns an error if the index is out of bounds.
+    pub fn column_slice(&self, column_index: usize) -> Result<&[T], Error>
+    where
+        T: Clone,
+    {
+        if column_index >= self.vecgrid.num_columns() {
+            return Err(self.vecgrid.indices_out_of_bounds(0, column_index));
+        }
+        let num_rows = self.vecgrid.num_rows();
+        let start = column_index * num_rows;
+        Ok(&self.columns_flat()[start..start + num_rows])
+    }
+
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column`, discarding the column major copy. Returns [`None`] if the
+    /// index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        let element = self.vecgrid.get_mut(row, column)?;
+        self.column_major.take();
+        Some(element)
+    }
+
+    /// Changes the element at given `row` and `column` to `element`,
+    /// discarding the column major copy. Returns [`Ok(())`] if the indices
+    /// were in bounds and returns an [`Err`] otherwise.
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
+        self.vecgrid.set(row, column, element)?;
+        self.column_major.take();
+        Ok(())
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// given row, discarding the column major copy. Returns an error if the
+    /// index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter_mut(
+        &mut self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        let row_iter = self.vecgrid.row_iter_mut(row_index)?;
+        self.column_major.take();
+        Ok(row_iter)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// given column, discarding the column major copy. Returns an error if
+    /// the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_iter_mut(
+        &mut self,
+        column_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        let column_iter = self.vecgrid.column_iter_mut(column_index)?;
+        self.column_major.take();
+        Ok(column_iter)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in
+    /// [row major order], discarding the column major copy.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.column_major.take();
+        self.vecgrid.elements_row_major_iter_mut()
+    }
+}
+
+impl<T> From<Vecgrid<T>> for ColumnCachedVecgrid<T> {
+    fn from(vecgrid: Vecgrid<T>) -> Self {
+        ColumnCachedVecgrid::new(vecgrid)
+    }
+}
+
+/// Two wrappers are equal if their vecgrids are, whether or not either has
+/// built its column major copy.
+impl<T: PartialEq> PartialEq for ColumnCachedVecgrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vecgrid == other.vecgrid
+    }
+}
+
+impl<T: Eq> Eq for ColumnCachedVecgrid<T> {}
+
+impl<T> Deref for ColumnCachedVecgrid<T> {
+    type Target = Vecgrid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vecgrid
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for ColumnCachedVecgrid<T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)` or
+    /// any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        &self.vecgrid[index]
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for ColumnCachedVecgrid<T> {
+    /// Returns a mutable version of the element at the given indices, given
+    /// as `(row, column)` or any other [`GridIndex`], discarding the column
+    /// major copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
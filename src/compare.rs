@@ -0,0 +1,44 @@
+//! Comparing [`Vecgrid`]s element by element with a custom predicate.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Returns `true` if `f` returns `true` for every pair of elements at the
+    /// same position in both vecgrids, stopping at the first pair for which
+    /// it returns `false`. The element types may differ. Returns an error if
+    /// the dimensions do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heights = Vecgrid::from_rows(vec![vec![0.1, 0.2], vec![0.3, 0.4]])?;
+    /// let expected = Vecgrid::from_rows(vec![vec![0.1, 0.2], vec![0.3, 0.4000001]])?;
+    /// assert!(heights.eq_elementwise_by(&expected, |a: &f64, b: &f64| (a - b).abs() < 1e-6)?);
+    ///
+    /// let labels = Vecgrid::from_rows(vec![vec!["1", "2"], vec!["3", "4"]])?;
+    /// let numbers = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// assert!(labels.eq_elementwise_by(&numbers, |label, number| *label == number.to_string())?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eq_elementwise_by<U, F>(&self, other: &Vecgrid<U>, f: F) -> Result<bool, Error>
+    where
+        F: Fn(&T, &U) -> bool,
+    {
+        if (self.num_rows, self.num_columns) != (other.num_rows, other.num_columns) {
+            return Err(Error::shape_mismatch(
+                (self.num_rows, self.num_columns),
+                (other.num_rows, other.num_columns),
+            ));
+        }
+        Ok(self
+            .vecgrid
+            .iter()
+            .zip(&other.vecgrid)
+            .all(|(element, other)| f(element, other)))
+    }
+}
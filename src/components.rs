@@ -0,0 +1,113 @@
+//! Labeling the connected components of a [`Vecgrid`], such as islands,
+//! blobs or groups of equal tiles.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Neighborhood, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Labels the connected components of the vecgrid. Two cells that are
+    /// neighbors in `neighborhood` belong to the same component if `same`
+    /// returns `true` for them, and so do cells connected through a chain
+    /// of such neighbors.
+    ///
+    /// Returns a vecgrid of the same dimensions with the label of the
+    /// component of every cell, and the number of components. Labels are
+    /// numbered from `0` in [row major order] of the first cell of every
+    /// component.
+    ///
+    /// The components are found in two passes with a union-find structure,
+    /// calling `same` at most once per pair of neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Neighborhood, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['~', '#', '~'],
+    ///     vec!['#', '~', '#'],
+    /// ])?;
+    /// let (labels, count) = map.label_components(Neighborhood::Four, |a, b| a == b);
+    /// assert_eq!(count, 6);
+    /// let (labels, count) = map.label_components(Neighborhood::Eight, |a, b| a == b);
+    /// assert_eq!(count, 2);
+    /// assert_eq!(labels.as_rows(), vec![vec![0, 1, 0], vec![1, 0, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn label_components<F>(&self, neighborhood: Neighborhood, same: F) -> (Vecgrid<u32>, usize)
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        // Only the neighbors that come earlier in row major order, which
+        // covers every pair of neighbors once.
+        let earlier = neighborhood
+            .offsets()
+            .iter()
+            .filter(|&&(row_offset, column_offset)| {
+                row_offset < 0 || (row_offset == 0 && column_offset < 0)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        let mut parents = (0..self.vecgrid.len()).collect::<Vec<_>>();
+        for index in 0..self.vecgrid.len() {
+            let (row, column) = self.position(index);
+            for &(row_offset, column_offset) in &earlier {
+                let (Some(row), Some(column)) = (
+                    row.checked_add_signed(row_offset),
+                    column.checked_add_signed(column_offset),
+                ) else {
+                    continue;
+                };
+                if column >= self.num_columns {
+                    continue;
+                }
+                let neighbor = row * self.num_columns + column;
+                if same(&self.vecgrid[index], &self.vecgrid[neighbor]) {
+                    union(&mut parents, index, neighbor);
+                }
+            }
+        }
+
+        // Every root is the first cell of its component, so the roots are
+        // labeled before any other cell of their component.
+        let mut labels = vec![0; self.vecgrid.len()];
+        let mut count = 0;
+        for index in 0..self.vecgrid.len() {
+            let root = find(&mut parents, index);
+            labels[index] = if root == index {
+                count += 1;
+                count - 1
+            } else {
+                labels[root]
+            };
+        }
+        (
+            Vecgrid {
+                vecgrid: labels,
+                num_rows: self.num_rows,
+                num_columns: self.num_columns,
+            },
+            count as usize,
+        )
+    }
+}
+
+/// Returns the root of the set containing `index`, halving the path to it.
+fn find(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+    index
+}
+
+/// Merges the sets containing `a` and `b`, keeping the smaller root so that
+/// every root is the first element of its set.
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    parents[a.max(b)] = a.min(b);
+}
@@ -0,0 +1,107 @@
+//! Compressed snapshots of a [`Vecgrid`], enabled with the `deflate`
+//! feature, for example to keep savegames with several large grids small.
+//!
+//! A snapshot starts with the magic bytes `VGRZ`, a format byte (`1` for
+//! zlib compressed [deflate]) and the uncompressed length as a little
+//! endian `u64`, followed by the compressed [row stream] of the vecgrid.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [deflate]: https://en.wikipedia.org/wiki/Deflate
+//! [row stream]: ../struct.Vecgrid.html#method.serialize_rows_to
+
+use crate::Vecgrid;
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use miniz_oxide::inflate::decompress_to_vec_zlib_with_limit;
+use std::io;
+
+const MAGIC: &[u8; 4] = b"VGRZ";
+const FORMAT_DEFLATE: u8 = 1;
+const HEADER_LEN: usize = MAGIC.len() + 1 + 8;
+const COMPRESSION_LEVEL: u8 = 6;
+
+impl<T> Vecgrid<T> {
+    /// Returns a compressed snapshot of the vecgrid, with `encode_row`
+    /// appending the bytes of each row to the buffer it is given, like
+    /// [`serialize_rows_to`]. The snapshot can be read back with
+    /// [`from_compressed_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// # fn main() -> std::io::Result<()> {
+    /// let map = Vecgrid::filled_with(7u8, 256, 256);
+    /// let bytes = map.to_compressed_bytes(|row, buffer| buffer.extend_from_slice(row));
+    /// assert!(bytes.len() < 1024);
+    /// let read = Vecgrid::from_compressed_bytes(&bytes, |bytes| Ok(bytes.to_vec()))?;
+    /// assert_eq!(read, map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`serialize_rows_to`]: struct.Vecgrid.html#method.serialize_rows_to
+    /// [`from_compressed_bytes`]: struct.Vecgrid.html#method.from_compressed_bytes
+    pub fn to_compressed_bytes<F>(&self, encode_row: F) -> Vec<u8>
+    where
+        F: FnMut(&[T], &mut Vec<u8>),
+    {
+        let mut uncompressed = Vec::new();
+        self.serialize_rows_to(&mut uncompressed, encode_row)
+            .expect("writing to a Vec never fails");
+        let compressed = compress_to_vec_zlib(&uncompressed, COMPRESSION_LEVEL);
+        let mut bytes = Vec::with_capacity(HEADER_LEN + compressed.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_DEFLATE);
+        bytes.extend_from_slice(&(uncompressed.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&compressed);
+        bytes
+    }
+
+    /// Reads a snapshot written by [`to_compressed_bytes`], with
+    /// `decode_row` turning the bytes of each row back into its elements,
+    /// like [`deserialize_rows_from`].
+    ///
+    /// Returns an error of kind [`InvalidData`] if the bytes are not a
+    /// snapshot or are corrupted, and any error of `decode_row`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// # fn main() -> std::io::Result<()> {
+    /// let cells = Vecgrid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+    /// let bytes = cells.to_compressed_bytes(|row, buffer| {
+    ///     buffer.extend(row.iter().map(|&cell| cell as u8));
+    /// });
+    /// let decode = |bytes: &[u8]| Ok(bytes.iter().map(|&byte| byte != 0).collect());
+    /// assert_eq!(Vecgrid::from_compressed_bytes(&bytes, decode)?, cells);
+    /// let error = Vecgrid::from_compressed_bytes(&bytes[..bytes.len() - 1], decode);
+    /// assert_eq!(error.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_compressed_bytes`]: struct.Vecgrid.html#method.to_compressed_bytes
+    /// [`deserialize_rows_from`]: struct.Vecgrid.html#method.deserialize_rows_from
+    /// [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn from_compressed_bytes<F>(bytes: &[u8], decode_row: F) -> io::Result<Self>
+    where
+        F: FnMut(&[u8]) -> io::Result<Vec<T>>,
+    {
+        let invalid_data = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+        if bytes.len() < HEADER_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(invalid_data("missing vecgrid snapshot header"));
+        }
+        if bytes[MAGIC.len()] != FORMAT_DEFLATE {
+            return Err(invalid_data("unknown vecgrid snapshot format"));
+        }
+        let len = u64::from_le_bytes(bytes[MAGIC.len() + 1..HEADER_LEN].try_into().unwrap());
+        let len = usize::try_from(len).map_err(|_| invalid_data("snapshot too large"))?;
+        let uncompressed = decompress_to_vec_zlib_with_limit(&bytes[HEADER_LEN..], len)
+            .map_err(|_| invalid_data("corrupted vecgrid snapshot"))?;
+        if uncompressed.len() != len {
+            return Err(invalid_data("corrupted vecgrid snapshot"));
+        }
+        Vecgrid::deserialize_rows_from(uncompressed.as_slice(), decode_row)
+    }
+}
@@ -0,0 +1,144 @@
+//! Tracing the outlines of regions of a [`Vecgrid`], for example to turn
+//! them into polygons or collision shapes.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Neighborhood, Vecgrid};
+use std::collections::VecDeque;
+
+impl<T> Vecgrid<T> {
+    /// Traces the outer boundary of the region of cells for which `inside`
+    /// returns `true` that contains `start`, using [Moore neighbor tracing]
+    /// that stops as soon as the trace would repeat itself. Cells belong to
+    /// the same region if they are adjacent orthogonally or diagonally.
+    ///
+    /// Returns the boundary cells, as `(row, column)`, in clockwise order
+    /// starting from the first cell of the region in [row major order]. A
+    /// cell is listed again every time the outline passes it, for example
+    /// at the tip of a line one cell wide. Returns no cells if `start` is
+    /// not inside, and an error if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['.', '.', '.', '.'],
+    ///     vec!['.', '#', '#', '#'],
+    ///     vec!['.', '#', '#', '#'],
+    ///     vec!['.', '#', '#', '#'],
+    /// ])?;
+    /// let outline = map.trace_boundary((2, 2), |&cell| cell == '#')?;
+    /// assert_eq!(
+    ///     outline,
+    ///     vec![(1, 1), (1, 2), (1, 3), (2, 3), (3, 3), (3, 2), (3, 1), (2, 1)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [Moore neighbor tracing]: https://en.wikipedia.org/wiki/Moore_neighborhood#Algorithm
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn trace_boundary<F>(
+        &self,
+        start: (usize, usize),
+        inside: F,
+    ) -> Result<Vec<(usize, usize)>, Error>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let (row, column) = start;
+        if row >= self.num_rows || column >= self.num_columns {
+            return Err(self.indices_out_of_bounds(row, column));
+        }
+        if !inside(&self.vecgrid[row * self.num_columns + column]) {
+            return Ok(Vec::new());
+        }
+        let offsets = Neighborhood::Eight.offsets();
+        let is_inside = |(row, column): (isize, isize)| {
+            usize::try_from(row).is_ok_and(|row| row < self.num_rows)
+                && usize::try_from(column).is_ok_and(|column| column < self.num_columns)
+                && inside(&self.vecgrid[row as usize * self.num_columns + column as usize])
+        };
+        let step = |(row, column): (isize, isize), direction: usize| {
+            let (row_offset, column_offset) = offsets[direction % offsets.len()];
+            (row + row_offset, column + column_offset)
+        };
+
+        let first = self.first_cell_of_region(row * self.num_columns + column, &inside);
+        let first = (first.0 as isize, first.1 as isize);
+        // The first cell in row major order has no inside neighbor to the
+        // west, so the trace enters it from there.
+        let (mut current, mut backtrack) = (first, 6);
+        // The states the trace has been in, and for every cell the
+        // directions it has been entered from as bits, to stop as soon as a
+        // state repeats.
+        let mut trace = Vec::new();
+        let mut entered = vec![0u8; self.vecgrid.len()];
+        let cycle_start = loop {
+            let Some(turn) =
+                (1..offsets.len()).find(|&turn| is_inside(step(current, backtrack + turn)))
+            else {
+                // A single cell without inside neighbors.
+                trace.push((first, backtrack));
+                break 0;
+            };
+            let previous = step(current, backtrack + turn - 1);
+            current = step(current, backtrack + turn);
+            backtrack = offsets
+                .iter()
+                .position(|&offset| offset == (previous.0 - current.0, previous.1 - current.1))
+                .expect("the previous neighbor is adjacent to the next cell");
+            let index = current.0 as usize * self.num_columns + current.1 as usize;
+            if entered[index] & 1 << backtrack != 0 {
+                // The made up first state may never come back, so the
+                // outline is the cycle from the earlier visit of this state.
+                break trace
+                    .iter()
+                    .position(|&state| state == (current, backtrack))
+                    .unwrap_or(0);
+            }
+            entered[index] |= 1 << backtrack;
+            trace.push((current, backtrack));
+        };
+        let mut boundary = trace
+            .split_off(cycle_start)
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect::<Vec<_>>();
+        if let Some(first_index) = boundary.iter().position(|&cell| cell == first) {
+            boundary.rotate_left(first_index);
+        }
+        Ok(boundary
+            .into_iter()
+            .map(|(row, column)| (row as usize, column as usize))
+            .collect())
+    }
+
+    /// Returns the first cell, in row major order, of the region of inside
+    /// cells that contains the cell at `index`.
+    fn first_cell_of_region<F>(&self, index: usize, inside: F) -> (usize, usize)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut visited = vec![false; self.vecgrid.len()];
+        let mut queue = VecDeque::from([index]);
+        visited[index] = true;
+        let mut first = index;
+        while let Some(index) = queue.pop_front() {
+            first = first.min(index);
+            let (row, column) = self.position(index);
+            let neighbors =
+                Neighborhood::Eight.neighbors(row, column, self.num_rows, self.num_columns);
+            for (row, column) in neighbors.flatten() {
+                let neighbor = row * self.num_columns + column;
+                if !visited[neighbor] && inside(&self.vecgrid[neighbor]) {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        self.position(first)
+    }
+}
@@ -0,0 +1,63 @@
+//! Counting the elements of a [`Vecgrid`] in a single pass over its buffer.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+impl<T> Vecgrid<T> {
+    /// Returns the number of elements for which `predicate` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Vecgrid::from_rows(vec![vec!['*', '.'], vec!['.', '*']])?;
+    /// assert_eq!(board.count_where(|&cell| cell == '*'), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_where<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.vecgrid
+            .iter()
+            .fold(0, |count, element| count + usize::from(predicate(element)))
+    }
+
+    /// Returns a [`HashMap`] from every distinct element to the number of
+    /// times it occurs in the vecgrid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let image = Vecgrid::from_rows(vec![vec!["red", "blue"], vec!["red", "red"]])?;
+    /// let counts = image.value_counts();
+    /// assert_eq!(counts["red"], 3);
+    /// assert_eq!(counts["blue"], 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    pub fn value_counts(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut counts = HashMap::new();
+        for element in &self.vecgrid {
+            match counts.get_mut(element) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(element.clone(), 1);
+                }
+            }
+        }
+        counts
+    }
+}
@@ -0,0 +1,154 @@
+//! A copy-on-write [`Vecgrid`] that can be cloned in constant time.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, GridIndex, Vecgrid};
+use std::ops::{Deref, Index, IndexMut};
+use std::sync::Arc;
+
+/// A [`Vecgrid`] behind a reference counted pointer. Cloning a
+/// [`CowVecgrid`] only bumps a reference count; the elements are copied the
+/// first time a shared vecgrid is mutated.
+///
+/// This suits search algorithms (minimax, MCTS, ...) that clone a board for
+/// every explored node but only change a few of them.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, CowVecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let board = CowVecgrid::new(Vecgrid::filled_with(0, 3, 3));
+/// let mut next = board.clone();
+/// assert!(next.ptr_eq(&board));
+///
+/// next.set(1, 1, 1)?;
+/// assert!(!next.ptr_eq(&board));
+/// assert_eq!(board[(1, 1)], 0);
+/// assert_eq!(next[(1, 1)], 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`CowVecgrid`]: struct.CowVecgrid.html
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct CowVecgrid<T> {
+    vecgrid: Arc<Vecgrid<T>>,
+}
+
+impl<T> CowVecgrid<T> {
+    /// Wraps `vecgrid` without copying its elements.
+    pub fn new(vecgrid: Vecgrid<T>) -> Self {
+        CowVecgrid {
+            vecgrid: Arc::new(vecgrid),
+        }
+    }
+
+    /// Returns `true` if both point to the same, shared, elements.
+    pub fn ptr_eq(&self, other: &CowVecgrid<T>) -> bool {
+        Arc::ptr_eq(&self.vecgrid, &other.vecgrid)
+    }
+
+    /// Returns `true` if other clones currently share the elements, i.e. the
+    /// next mutation will copy them.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.vecgrid) > 1
+    }
+}
+
+impl<T: Clone> CowVecgrid<T> {
+    /// Returns a mutable reference to the underlying [`Vecgrid`], copying
+    /// the elements first if they are shared with another clone.
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn to_mut(&mut self) -> &mut Vecgrid<T> {
+        Arc::make_mut(&mut self.vecgrid)
+    }
+
+    /// Unwraps the underlying [`Vecgrid`], copying the elements only if they
+    /// are shared with another clone.
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn into_vecgrid(self) -> Vecgrid<T> {
+        Arc::try_unwrap(self.vecgrid).unwrap_or_else(|shared| (*shared).clone())
+    }
+
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column`, copying shared elements first. Returns [`None`] if the index
+    /// is out of bounds, without copying.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.vecgrid.get_index(row, column)?;
+        self.to_mut().get_mut(row, column)
+    }
+
+    /// Changes the element at given `row` and `column` to `element`, copying
+    /// shared elements first. Returns [`Ok(())`] if the indices were in
+    /// bounds and returns an [`Err`], without copying, otherwise.
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
+        let error = self.vecgrid.indices_out_of_bounds(row, column);
+        let location = self.get_mut(row, column).ok_or(error)?;
+        *location = element;
+        Ok(())
+    }
+}
+
+impl<T> Clone for CowVecgrid<T> {
+    fn clone(&self) -> Self {
+        CowVecgrid {
+            vecgrid: Arc::clone(&self.vecgrid),
+        }
+    }
+}
+
+impl<T> From<Vecgrid<T>> for CowVecgrid<T> {
+    fn from(vecgrid: Vecgrid<T>) -> Self {
+        CowVecgrid::new(vecgrid)
+    }
+}
+
+impl<T> Deref for CowVecgrid<T> {
+    type Target = Vecgrid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vecgrid
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for CowVecgrid<T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)` or
+    /// any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        &self.vecgrid[index]
+    }
+}
+
+impl<T: Clone, I: Into<GridIndex>> IndexMut<I> for CowVecgrid<T> {
+    /// Returns a mutable version of the element at the given indices, given
+    /// as `(row, column)` or any other [`GridIndex`], copying shared elements
+    /// first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
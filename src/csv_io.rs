@@ -0,0 +1,187 @@
+//! Reading and writing a [`Vecgrid`] as CSV through the [`csv`] crate,
+//! enabled with the `csv` feature.
+//!
+//! Every line is one row and every field one element, without a header
+//! line.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`csv`]: https://docs.rs/csv
+
+use crate::Vecgrid;
+use std::fmt;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+/// An error when reading or writing a [`Vecgrid`] as CSV. New variants may
+/// be added in the future, so matches must include a wildcard arm.
+///
+/// Line numbers are 1-based, while column indices are 0-based.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CsvError {
+    /// The CSV data could not be read or written, for example because of
+    /// an I/O error or invalid UTF-8.
+    Csv(csv::Error),
+    /// A line had a different number of fields than the first line.
+    RaggedLine {
+        /// The offending line.
+        line: u64,
+        /// The number of fields on the first line.
+        expected: usize,
+        /// The number of fields on the offending line.
+        actual: usize,
+    },
+    /// A field could not be parsed into an element.
+    Parse {
+        /// The line of the field.
+        line: u64,
+        /// The column of the field.
+        column: usize,
+        /// The field that could not be parsed.
+        field: String,
+        /// The error message of the parser.
+        message: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(error) => write!(f, "{}", error),
+            CsvError::RaggedLine {
+                line,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "line {} has {} fields but the first line has {}",
+                line, actual, expected
+            ),
+            CsvError::Parse {
+                line,
+                column,
+                field,
+                message,
+            } => write!(
+                f,
+                "cannot parse {:?} on line {}, column {}: {}",
+                field, line, column, message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Csv(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<csv::Error> for CsvError {
+    fn from(error: csv::Error) -> Self {
+        CsvError::Csv(error)
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Reads a vecgrid from CSV, parsing every field with [`FromStr`] after
+    /// trimming surrounding whitespace. Empty lines are skipped.
+    ///
+    /// Returns an error naming the offending line if a line has a different
+    /// number of fields than the first, or if a field cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{CsvError, Vecgrid};
+    /// # fn main() -> Result<(), CsvError> {
+    /// let vecgrid: Vecgrid<f64> = Vecgrid::from_csv_reader("1, 2.5\n3, 4\n".as_bytes())?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1.0, 2.5], vec![3.0, 4.0]]);
+    ///
+    /// let error = Vecgrid::<f64>::from_csv_reader("1,2\n3\n".as_bytes()).unwrap_err();
+    /// assert_eq!(error.to_string(), "line 2 has 1 fields but the first line has 2");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    pub fn from_csv_reader<R>(reader: R) -> Result<Self, CsvError>
+    where
+        R: Read,
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let mut vecgrid = Vec::new();
+        let (mut num_rows, mut num_columns) = (0, 0);
+        let mut record = csv::StringRecord::new();
+        while reader.read_record(&mut record)? {
+            let line = record.position().map_or(0, csv::Position::line);
+            if num_rows == 0 {
+                num_columns = record.len();
+            } else if record.len() != num_columns {
+                return Err(CsvError::RaggedLine {
+                    line,
+                    expected: num_columns,
+                    actual: record.len(),
+                });
+            }
+            for (column, field) in record.iter().enumerate() {
+                let element = field.parse().map_err(|error: T::Err| CsvError::Parse {
+                    line,
+                    column,
+                    field: field.to_string(),
+                    message: error.to_string(),
+                })?;
+                vecgrid.push(element);
+            }
+            num_rows += 1;
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        })
+    }
+
+    /// Writes the vecgrid as CSV, one line per row, formatting every element
+    /// with [`Display`]. Fields are quoted where CSV requires it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{CsvError, Vecgrid};
+    /// # fn main() -> Result<(), CsvError> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec!["a", "b,c"], vec!["d", "e"]]).unwrap();
+    /// let mut csv = Vec::new();
+    /// vecgrid.to_csv_writer(&mut csv)?;
+    /// assert_eq!(csv, b"a,\"b,c\"\nd,e\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    pub fn to_csv_writer<W>(&self, writer: W) -> Result<(), CsvError>
+    where
+        W: Write,
+        T: fmt::Display,
+    {
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(writer);
+        for row in self.vecgrid.chunks(self.num_columns.max(1)) {
+            writer.write_record(row.iter().map(ToString::to_string))?;
+        }
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,347 @@
+//! Drawing lines, rectangles, circles and brushes into a [`Vecgrid`], for
+//! example in map editors, debug overlays and terrain sculpting.
+//!
+//! Rectangles are given as the indices, as `(row, column)`, of two opposite
+//! corners, both inclusive.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Sets every cell on the line from `from` to `to`, both included, to
+    /// `value`. The cells are the ones returned by [`line_iter`]. Returns an
+    /// error if either end is out of bounds, without changing any cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Vecgrid::filled_with('.', 3, 5);
+    /// canvas.draw_line((0, 0), (2, 4), '#')?;
+    /// assert_eq!(
+    ///     canvas.as_rows(),
+    ///     vec![
+    ///         vec!['#', '.', '.', '.', '.'],
+    ///         vec!['.', '#', '#', '.', '.'],
+    ///         vec!['.', '.', '.', '#', '#'],
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`line_iter`]: struct.Vecgrid.html#method.line_iter
+    pub fn draw_line(
+        &mut self,
+        from: (usize, usize),
+        to: (usize, usize),
+        value: T,
+    ) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let line = self.line_iter(from, to)?.collect::<Vec<_>>();
+        for cell in line {
+            self[cell] = value.clone();
+        }
+        Ok(())
+    }
+
+    /// Sets the cells on the outline of the rectangle with the corners
+    /// `top_left` and `bottom_right` to `value`. Returns an error if either
+    /// corner is out of bounds, without changing any cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Vecgrid::filled_with(0, 4, 4);
+    /// canvas.draw_rect((0, 1), (2, 3), 1)?;
+    /// assert_eq!(
+    ///     canvas.as_rows(),
+    ///     vec![
+    ///         vec![0, 1, 1, 1],
+    ///         vec![0, 1, 0, 1],
+    ///         vec![0, 1, 1, 1],
+    ///         vec![0, 0, 0, 0],
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn draw_rect(
+        &mut self,
+        top_left: (usize, usize),
+        bottom_right: (usize, usize),
+        value: T,
+    ) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let (top, left, bottom, right) = self.rect_bounds(top_left, bottom_right)?;
+        for column in left..=right {
+            self[(top, column)] = value.clone();
+            self[(bottom, column)] = value.clone();
+        }
+        for row in top..=bottom {
+            self[(row, left)] = value.clone();
+            self[(row, right)] = value.clone();
+        }
+        Ok(())
+    }
+
+    /// Sets every cell of the rectangle with the corners `top_left` and
+    /// `bottom_right` to `value`. Returns an error if either corner is out of
+    /// bounds, without changing any cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    /// canvas.fill_rect((1, 1), (2, 2), 1)?;
+    /// assert_eq!(
+    ///     canvas.as_rows(),
+    ///     vec![vec![0, 0, 0, 0], vec![0, 1, 1, 0], vec![0, 1, 1, 0]]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill_rect(
+        &mut self,
+        top_left: (usize, usize),
+        bottom_right: (usize, usize),
+        value: T,
+    ) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let (top, left, bottom, right) = self.rect_bounds(top_left, bottom_right)?;
+        for row in top..=bottom {
+            let start = row * self.num_columns;
+            self.vecgrid[start + left..=start + right].fill(value.clone());
+        }
+        Ok(())
+    }
+
+    /// Sets the cells on the outline of the circle around `center` with the
+    /// given `radius` to `value`, using the [midpoint circle algorithm]. The
+    /// parts of the circle outside of the grid are clipped. Returns an error
+    /// if `center` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Vecgrid::filled_with('.', 5, 5);
+    /// canvas.draw_circle((2, 2), 2, '#')?;
+    /// assert_eq!(
+    ///     canvas.as_rows(),
+    ///     vec![
+    ///         vec!['.', '#', '#', '#', '.'],
+    ///         vec!['#', '.', '.', '.', '#'],
+    ///         vec!['#', '.', '.', '.', '#'],
+    ///         vec!['#', '.', '.', '.', '#'],
+    ///         vec!['.', '#', '#', '#', '.'],
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [midpoint circle algorithm]: https://en.wikipedia.org/wiki/Midpoint_circle_algorithm
+    pub fn draw_circle(
+        &mut self,
+        center: (usize, usize),
+        radius: usize,
+        value: T,
+    ) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let (row, column) = self.circle_center(center)?;
+        // Every cell of a larger circle is further from the center than any
+        // cell of the grid.
+        let radius = match isize::try_from(radius) {
+            Ok(radius)
+                if radius.unsigned_abs() <= self.num_rows.saturating_add(self.num_columns) =>
+            {
+                radius
+            }
+            _ => return Ok(()),
+        };
+        for (row_offset, column_offset) in circle_offsets(radius) {
+            if let (Some(row), Some(column)) = (
+                row.checked_add_signed(row_offset),
+                column.checked_add_signed(column_offset),
+            ) {
+                if let Some(cell) = self.get_mut(row, column) {
+                    *cell = value.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets every cell inside the circle drawn by [`draw_circle`] to
+    /// `value`, including its outline. The parts of the circle outside of
+    /// the grid are clipped. Returns an error if `center` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    /// canvas.fill_circle((0, 0), 2, 1)?;
+    /// assert_eq!(
+    ///     canvas.as_rows(),
+    ///     vec![vec![1, 1, 1, 0], vec![1, 1, 1, 0], vec![1, 1, 0, 0]]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`draw_circle`]: struct.Vecgrid.html#method.draw_circle
+    pub fn fill_circle(
+        &mut self,
+        center: (usize, usize),
+        radius: usize,
+        value: T,
+    ) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let (row, column) = self.circle_center(center)?;
+        // A circle this large already covers the whole grid.
+        let radius = radius.min(self.num_rows.saturating_add(self.num_columns));
+        let radius = isize::try_from(radius).unwrap_or(isize::MAX);
+        let mut half_widths: Vec<Option<usize>> = vec![None; self.num_rows];
+        for (row_offset, column_offset) in circle_offsets(radius) {
+            if let Some(half_width) = row
+                .checked_add_signed(row_offset)
+                .and_then(|row| half_widths.get_mut(row))
+            {
+                let width = column_offset.unsigned_abs();
+                *half_width = Some(half_width.map_or(width, |other| other.max(width)));
+            }
+        }
+        for (row, half_width) in half_widths.into_iter().enumerate() {
+            if let Some(half_width) = half_width {
+                let left = column.saturating_sub(half_width);
+                let right = column.saturating_add(half_width).min(self.num_columns - 1);
+                let start = row * self.num_columns;
+                self.vecgrid[start + left..=start + right].fill(value.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies `brush` with its top-left element at `at`, as `(row, column)`,
+    /// calling `blend` with every cell of the grid that the brush covers and
+    /// the brush element above it. The parts of the brush outside of the
+    /// grid are clipped, so `at` may be negative.
+    ///
+    /// `blend` decides how the brush mixes with the grid, for example by
+    /// replacing, adding or taking the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut heights = Vecgrid::filled_with(1, 3, 4);
+    /// let brush = Vecgrid::from_rows(vec![vec![1, 2], vec![2, 4]])?;
+    /// heights.stamp(&brush, (1, 2), |height, raise| *height += raise);
+    /// heights.stamp(&brush, (-1, -1), |height, &raise| *height = (*height).max(raise));
+    /// assert_eq!(
+    ///     heights.as_rows(),
+    ///     vec![vec![4, 1, 1, 1], vec![1, 1, 2, 3], vec![1, 1, 3, 5]]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stamp<U, F>(&mut self, brush: &Vecgrid<U>, at: (isize, isize), blend: F)
+    where
+        F: Fn(&mut T, &U),
+    {
+        let clip = |offset: isize, brush_len: usize, len: usize| {
+            let start = offset.max(0) as usize;
+            let end = offset
+                .saturating_add_unsigned(brush_len)
+                .clamp(0, len as isize) as usize;
+            start..end.max(start)
+        };
+        let columns = clip(at.1, brush.num_columns, self.num_columns);
+        if columns.is_empty() {
+            return;
+        }
+        for row in clip(at.0, brush.num_rows, self.num_rows) {
+            let brush_row = (row as isize - at.0) as usize;
+            let brush_start =
+                brush_row * brush.num_columns + (columns.start as isize - at.1) as usize;
+            let start = row * self.num_columns;
+            let cells = &mut self.vecgrid[start + columns.start..start + columns.end];
+            for (cell, element) in cells.iter_mut().zip(&brush.vecgrid[brush_start..]) {
+                blend(cell, element);
+            }
+        }
+    }
+
+    /// Returns the top and bottom rows and the left and right columns of the
+    /// rectangle with the given opposite corners, as `(top, left, bottom,
+    /// right)`, or an error if either corner is out of bounds.
+    pub(crate) fn rect_bounds(
+        &self,
+        corner: (usize, usize),
+        opposite: (usize, usize),
+    ) -> Result<(usize, usize, usize, usize), Error> {
+        for &(row, column) in &[corner, opposite] {
+            self.get_index(row, column)
+                .ok_or_else(|| self.indices_out_of_bounds(row, column))?;
+        }
+        Ok((
+            corner.0.min(opposite.0),
+            corner.1.min(opposite.1),
+            corner.0.max(opposite.0),
+            corner.1.max(opposite.1),
+        ))
+    }
+
+    fn circle_center(&self, (row, column): (usize, usize)) -> Result<(usize, usize), Error> {
+        self.get_index(row, column)
+            .map(|_| (row, column))
+            .ok_or_else(|| self.indices_out_of_bounds(row, column))
+    }
+}
+
+/// Returns the offsets, as `(row, column)`, of the cells on a circle with
+/// the given `radius` around the origin, some of them more than once.
+fn circle_offsets(radius: isize) -> Vec<(isize, isize)> {
+    let (mut x, mut y) = (radius, 0);
+    let mut error = 1 - x;
+    let mut offsets = Vec::new();
+    while x >= y {
+        for &(row, column) in &[(y, x), (x, y)] {
+            offsets.extend([
+                (row, column),
+                (row, -column),
+                (-row, column),
+                (-row, -column),
+            ]);
+        }
+        y += 1;
+        if error < 0 {
+            error += 2 * y + 1;
+        } else {
+            x -= 1;
+            error += 2 * (y - x) + 1;
+        }
+    }
+    offsets
+}
@@ -0,0 +1,97 @@
+//! Bulk arithmetic transforms for [`Vecgrid`]s of floating point numbers.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+
+macro_rules! impl_float_transforms {
+    ($($float:ty),*) => {
+        $(
+            impl Vecgrid<$float> {
+                /// Linearly rescales all elements so that the smallest element
+                /// becomes `0.0` and the largest becomes `1.0`. If all elements
+                /// are equal, every element becomes `0.0`. `NaN` elements are
+                /// ignored when finding the range and are left untouched.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let mut vecgrid: Vecgrid<f64> =
+                ///     Vecgrid::from_rows(vec![vec![2.0, 4.0], vec![6.0, 10.0]])?;
+                /// vecgrid.normalize();
+                /// assert_eq!(vecgrid.as_rows(), vec![vec![0.0, 0.25], vec![0.5, 1.0]]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn normalize(&mut self) {
+                    let (min, max) = self
+                        .vecgrid
+                        .iter()
+                        .fold((<$float>::INFINITY, <$float>::NEG_INFINITY), |(min, max), &x| {
+                            (min.min(x), max.max(x))
+                        });
+                    if min > max {
+                        return;
+                    }
+                    let range = max - min;
+                    for element in self.vecgrid.iter_mut() {
+                        if range > 0.0 {
+                            *element = (*element - min) / range;
+                        } else if !element.is_nan() {
+                            *element = 0.0;
+                        }
+                    }
+                }
+
+                /// Restricts every element to the interval `[min, max]`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `min > max`, `min` is `NaN`, or `max` is `NaN`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let mut vecgrid: Vecgrid<f64> =
+                ///     Vecgrid::from_rows(vec![vec![-3.0, 0.5], vec![1.5, 0.0]])?;
+                /// vecgrid.clamp_in_place(0.0, 1.0);
+                /// assert_eq!(vecgrid.as_rows(), vec![vec![0.0, 0.5], vec![1.0, 0.0]]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn clamp_in_place(&mut self, min: $float, max: $float) {
+                    assert!(min <= max, "clamp_in_place requires min <= max");
+                    for element in self.vecgrid.iter_mut() {
+                        *element = element.clamp(min, max);
+                    }
+                }
+
+                /// Multiplies every element by `factor`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let mut vecgrid: Vecgrid<f64> =
+                ///     Vecgrid::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]])?;
+                /// vecgrid.scale(0.5);
+                /// assert_eq!(vecgrid.as_rows(), vec![vec![0.5, 1.0], vec![1.5, 2.0]]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn scale(&mut self, factor: $float) {
+                    for element in self.vecgrid.iter_mut() {
+                        *element *= factor;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_transforms!(f32, f64);
@@ -0,0 +1,362 @@
+//! Traits for writing algorithms that work on any two-dimensional grid: an
+//! owned [`Vecgrid`], a borrowed view of one, or a fixed-size array.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{
+    CowVecgrid, Error, OffsetGrid, RowBand, TrackedVecgrid, Vecgrid, VecgridRef, VecgridRefMut,
+    VecgridView, VecgridViewMut,
+};
+
+/// Read access to a two-dimensional grid of elements.
+///
+/// Only the dimensions and [`get`] need to be implemented; iteration is
+/// provided on top of them.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Grid, Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// fn count_walls<G: Grid<Element = char>>(grid: &G) -> usize {
+///     grid.elements_row_major_iter().filter(|&&cell| cell == '#').count()
+/// }
+///
+/// let owned = Vecgrid::from_rows(vec![vec!['#', '.'], vec!['#', '#']])?;
+/// let array = [['.', '#', '.'], ['.', '.', '.']];
+/// assert_eq!(count_walls(&owned), 3);
+/// assert_eq!(count_walls(&array), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`get`]: trait.Grid.html#tymethod.get
+pub trait Grid {
+    /// The type of the elements in the grid.
+    type Element;
+
+    /// The number of rows.
+    fn num_rows(&self) -> usize;
+
+    /// The number of columns.
+    fn num_columns(&self) -> usize;
+
+    /// Returns a reference to the element at the given `row` and `column`
+    /// if the index is in bounds (wrapped in [`Some`]). Returns [`None`] if
+    /// the index is out of bounds.
+    ///
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element>;
+
+    /// The total number of elements, i.e. the product of `num_rows` and
+    /// `num_columns`.
+    fn num_elements(&self) -> usize {
+        self.num_rows() * self.num_columns()
+    }
+
+    /// Returns an [`Iterator`] over all the indices, as `(row, column)`, in
+    /// [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    fn indices_row_major(&self) -> impl Iterator<Item = (usize, usize)> {
+        let num_columns = self.num_columns();
+        (0..self.num_rows()).flat_map(move |row| (0..num_columns).map(move |column| (row, column)))
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in [row major
+    /// order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    fn elements_row_major_iter(&self) -> impl Iterator<Item = &Self::Element> {
+        self.indices_row_major()
+            .filter_map(move |(row, column)| self.get(row, column))
+    }
+
+    /// Returns an [`Iterator`] over the indices, as `(row, column)`, and
+    /// references to all elements in [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    fn enumerate_row_major(&self) -> impl Iterator<Item = ((usize, usize), &Self::Element)> {
+        self.indices_row_major()
+            .filter_map(move |(row, column)| Some(((row, column), self.get(row, column)?)))
+    }
+}
+
+/// Write access to a two-dimensional grid of elements.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Grid, GridMut, Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// fn clear_diagonal<G: GridMut<Element = u8>>(grid: &mut G) -> Result<(), Error> {
+///     for index in 0..grid.num_rows().min(grid.num_columns()) {
+///         grid.set(index, index, 0)?;
+///     }
+///     Ok(())
+/// }
+///
+/// let mut owned = Vecgrid::filled_with(1, 2, 2);
+/// let mut array = [[1, 1], [1, 1]];
+/// clear_diagonal(&mut owned)?;
+/// clear_diagonal(&mut array)?;
+/// assert_eq!(owned.as_rows(), vec![vec![0, 1], vec![1, 0]]);
+/// assert_eq!(array, [[0, 1], [1, 0]]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait GridMut: Grid {
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column` if the index is in bounds (wrapped in [`Some`]). Returns
+    /// [`None`] if the index is out of bounds.
+    ///
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element>;
+
+    /// Changes the element at given `row` and `column` to `element`. Returns
+    /// [`Ok(())`] if the indices were in bounds and returns an [`Err`]
+    /// otherwise.
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    fn set(&mut self, row: usize, column: usize, element: Self::Element) -> Result<(), Error> {
+        let (num_rows, num_columns) = (self.num_rows(), self.num_columns());
+        let location = self.get_mut(row, column).ok_or(Error::IndicesOutOfBounds {
+            row,
+            column,
+            num_rows,
+            num_columns,
+        })?;
+        *location = element;
+        Ok(())
+    }
+}
+
+impl<T> Grid for Vecgrid<T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        Vecgrid::get(self, row, column)
+    }
+
+    fn elements_row_major_iter(&self) -> impl Iterator<Item = &T> {
+        self.vecgrid.iter()
+    }
+}
+
+impl<T> GridMut for Vecgrid<T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        Vecgrid::get_mut(self, row, column)
+    }
+}
+
+impl<T> Grid for RowBand<'_, T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        RowBand::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        RowBand::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        RowBand::get(self, row, column)
+    }
+
+    fn elements_row_major_iter(&self) -> impl Iterator<Item = &T> {
+        self.as_row_major_slice().iter()
+    }
+}
+
+impl<T> GridMut for RowBand<'_, T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        RowBand::get_mut(self, row, column)
+    }
+}
+
+impl<T> Grid for CowVecgrid<T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        Vecgrid::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        Vecgrid::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        Vecgrid::get(self, row, column)
+    }
+}
+
+impl<T: Clone> GridMut for CowVecgrid<T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        CowVecgrid::get_mut(self, row, column)
+    }
+}
+
+impl<T> Grid for TrackedVecgrid<T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        Vecgrid::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        Vecgrid::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        Vecgrid::get(self, row, column)
+    }
+}
+
+impl<T> GridMut for TrackedVecgrid<T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        TrackedVecgrid::get_mut(self, row, column)
+    }
+}
+
+impl<T> Grid for OffsetGrid<T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        Vecgrid::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        Vecgrid::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        Vecgrid::get(self, row, column)
+    }
+}
+
+impl<T> GridMut for OffsetGrid<T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        Vecgrid::get_mut(self, row, column)
+    }
+}
+
+impl<T> Grid for VecgridRef<'_, T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        VecgridRef::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        VecgridRef::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        VecgridRef::get(self, row, column)
+    }
+
+    fn elements_row_major_iter(&self) -> impl Iterator<Item = &T> {
+        self.as_row_major_slice().iter()
+    }
+}
+
+impl<T> Grid for VecgridRefMut<'_, T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        VecgridRefMut::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        VecgridRefMut::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        VecgridRefMut::get(self, row, column)
+    }
+
+    fn elements_row_major_iter(&self) -> impl Iterator<Item = &T> {
+        self.as_row_major_slice().iter()
+    }
+}
+
+impl<T> GridMut for VecgridRefMut<'_, T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        VecgridRefMut::get_mut(self, row, column)
+    }
+}
+
+impl<T> Grid for VecgridView<'_, T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        VecgridView::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        VecgridView::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        VecgridView::get(self, row, column)
+    }
+}
+
+impl<T> Grid for VecgridViewMut<'_, T> {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        VecgridViewMut::num_rows(self)
+    }
+
+    fn num_columns(&self) -> usize {
+        VecgridViewMut::num_columns(self)
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        VecgridViewMut::get(self, row, column)
+    }
+}
+
+impl<T> GridMut for VecgridViewMut<'_, T> {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        VecgridViewMut::get_mut(self, row, column)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Grid for [[T; C]; R] {
+    type Element = T;
+
+    fn num_rows(&self) -> usize {
+        R
+    }
+
+    fn num_columns(&self) -> usize {
+        C
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        <[[T; C]]>::get(self, row)?.get(column)
+    }
+}
+
+impl<T, const R: usize, const C: usize> GridMut for [[T; C]; R] {
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        <[[T; C]]>::get_mut(self, row)?.get_mut(column)
+    }
+}
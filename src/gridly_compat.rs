@@ -0,0 +1,90 @@
+//! Implementations of the [`gridly`] grid traits for [`Vecgrid`], enabled
+//! with the `gridly` feature, so libraries written against those traits can
+//! use a [`Vecgrid`] without copying it.
+//!
+//! A [`Vecgrid`] is rooted at `(0, 0)`, so the row and column of a gridly
+//! `Location` are the same as the indices used by [`Vecgrid`] itself.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`gridly`]: https://docs.rs/gridly
+
+use crate::Vecgrid;
+use gridly::grid::{Grid, GridBounds, GridMut, GridSetter};
+use gridly::location::Location;
+use gridly::vector::Vector;
+
+impl<T> Vecgrid<T> {
+    /// Returns the index in [row major order] of a `location` that gridly
+    /// has already checked to be in bounds.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    fn gridly_index(&self, location: Location) -> usize {
+        location.row.0 as usize * self.num_columns + location.column.0 as usize
+    }
+}
+
+impl<T> GridBounds for Vecgrid<T> {
+    fn dimensions(&self) -> Vector {
+        Vector::new(self.num_rows as isize, self.num_columns as isize)
+    }
+
+    fn root(&self) -> Location {
+        Location::zero()
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// use gridly::prelude::{Grid, Location};
+///
+/// fn sum(grid: &impl Grid<Item = i32>) -> i32 {
+///     grid.rows().iter().flat_map(|row| row.iter().copied()).sum()
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+/// assert_eq!(sum(&vecgrid), 21);
+/// assert_eq!(Grid::get(&vecgrid, Location::new(1, 2)), Ok(&6));
+/// assert!(Grid::get(&vecgrid, Location::new(2, 0)).is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl<T> Grid for Vecgrid<T> {
+    type Item = T;
+
+    unsafe fn get_unchecked(&self, location: Location) -> &T {
+        self.vecgrid.get_unchecked(self.gridly_index(location))
+    }
+}
+
+impl<T> GridMut for Vecgrid<T> {
+    unsafe fn get_unchecked_mut(&mut self, location: Location) -> &mut T {
+        let index = self.gridly_index(location);
+        self.vecgrid.get_unchecked_mut(index)
+    }
+}
+
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// use gridly::prelude::{GridSetter, Location};
+///
+/// # fn main() -> Result<(), Error> {
+/// let mut vecgrid = Vecgrid::filled_with(0, 2, 2);
+/// assert_eq!(GridSetter::replace(&mut vecgrid, Location::new(0, 1), 7), Ok(0));
+/// assert_eq!(vecgrid.as_rows(), vec![vec![0, 7], vec![0, 0]]);
+/// # Ok(())
+/// # }
+/// ```
+impl<T> GridSetter for Vecgrid<T> {
+    unsafe fn replace_unchecked(&mut self, location: Location, value: T) -> T {
+        std::mem::replace(GridMut::get_unchecked_mut(self, location), value)
+    }
+
+    unsafe fn set_unchecked(&mut self, location: Location, value: T) {
+        *GridMut::get_unchecked_mut(self, location) = value;
+    }
+}
@@ -0,0 +1,50 @@
+//! Grouping the rows of a [`Vecgrid`] by a key computed from each row.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+impl<T> Vecgrid<T> {
+    /// Splits the rows of the vecgrid into one new vecgrid per distinct key
+    /// that `key` returns for them. The rows of every group keep their order
+    /// and all groups have the columns of the vecgrid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let readings = Vecgrid::from_rows(vec![
+    ///     vec![1, 20, 3],
+    ///     vec![2, 25, 1],
+    ///     vec![1, 21, 4],
+    /// ])?;
+    /// let by_device = readings.partition_rows_by_key(|row| row[0]);
+    /// assert_eq!(by_device.len(), 2);
+    /// assert_eq!(by_device[&1].as_rows(), vec![vec![1, 20, 3], vec![1, 21, 4]]);
+    /// assert_eq!(by_device[&2].as_rows(), vec![vec![2, 25, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn partition_rows_by_key<K, F>(&self, key: F) -> HashMap<K, Vecgrid<T>>
+    where
+        K: Eq + Hash,
+        F: Fn(&[T]) -> K,
+        T: Clone,
+    {
+        let mut groups: HashMap<K, Vecgrid<T>> = HashMap::new();
+        for row_index in 0..self.num_rows {
+            let row = &self.vecgrid[row_index * self.num_columns..][..self.num_columns];
+            let group = groups.entry(key(row)).or_insert_with(|| Vecgrid {
+                vecgrid: Vec::new(),
+                num_rows: 0,
+                num_columns: self.num_columns,
+            });
+            group.vecgrid.extend_from_slice(row);
+            group.num_rows += 1;
+        }
+        groups
+    }
+}
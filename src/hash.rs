@@ -0,0 +1,223 @@
+//! Fast hashing of whole [`Vecgrid`]s for transposition tables.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::hash::{Hash, Hasher};
+
+/// A table of random keys, one for every combination of cell and cell state,
+/// used to compute [Zobrist hashes] of boards.
+///
+/// Zobrist hashes can be updated incrementally with [`toggle`] when a single
+/// cell changes, instead of rehashing the whole board.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, ZobristTable, Error};
+/// # fn main() -> Result<(), Error> {
+/// // 0 = empty, 1 = black, 2 = white; empty cells do not contribute.
+/// let state = |cell: &u8| (*cell != 0).then(|| *cell as usize - 1);
+/// let table = ZobristTable::new(3, 3, 2, 42);
+///
+/// let mut board = Vecgrid::filled_with(0u8, 3, 3);
+/// let mut hash = board.zobrist_hash(&table, state)?;
+///
+/// board[(1, 1)] = 1;
+/// hash = table.toggle(hash, 1, 1, 0)?;
+/// assert_eq!(hash, board.zobrist_hash(&table, state)?);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Zobrist hashes]: https://en.wikipedia.org/wiki/Zobrist_hashing
+/// [`toggle`]: struct.ZobristTable.html#method.toggle
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ZobristTable {
+    keys: Vec<u64>,
+    num_rows: usize,
+    num_columns: usize,
+    num_states: usize,
+}
+
+impl ZobristTable {
+    /// Creates a table for boards of `num_rows` by `num_columns` cells where
+    /// every cell can be in one of `num_states` states. The keys are
+    /// generated deterministically from `seed`.
+    pub fn new(num_rows: usize, num_columns: usize, num_states: usize, seed: u64) -> Self {
+        let mut state = seed;
+        let keys = (0..num_rows * num_columns * num_states)
+            .map(|_| splitmix64(&mut state))
+            .collect();
+        ZobristTable {
+            keys,
+            num_rows,
+            num_columns,
+            num_states,
+        }
+    }
+
+    /// Returns the key of the cell at `row` and `column` being in `state`,
+    /// or [`None`] if any of them is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn key(&self, row: usize, column: usize, state: usize) -> Option<u64> {
+        if row < self.num_rows && column < self.num_columns && state < self.num_states {
+            Some(self.keys[(row * self.num_columns + column) * self.num_states + state])
+        } else {
+            None
+        }
+    }
+
+    /// Adds or removes (the operation is its own inverse) the cell at `row`
+    /// and `column` being in `state` to or from `hash`. Returns an error if
+    /// the cell or the state is out of bounds.
+    pub fn toggle(&self, hash: u64, row: usize, column: usize, state: usize) -> Result<u64, Error> {
+        if row >= self.num_rows || column >= self.num_columns {
+            return Err(Error::IndicesOutOfBounds {
+                row,
+                column,
+                num_rows: self.num_rows,
+                num_columns: self.num_columns,
+            });
+        }
+        self.check_state(state)?;
+        Ok(hash ^ self.keys[(row * self.num_columns + column) * self.num_states + state])
+    }
+
+    fn check_state(&self, state: usize) -> Result<(), Error> {
+        if state < self.num_states {
+            Ok(())
+        } else {
+            Err(Error::StateOutOfBounds {
+                state,
+                num_states: self.num_states,
+            })
+        }
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Computes the Zobrist hash of the vecgrid using `table`. The `state`
+    /// function maps every element to its state index in the table, or to
+    /// [`None`] for elements that should not contribute (e.g. empty cells).
+    ///
+    /// Returns an error if the dimensions of the vecgrid and the table do not
+    /// match, or if `state` returns an index the table has no keys for.
+    ///
+    /// See [`ZobristTable`] for an example.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`ZobristTable`]: struct.ZobristTable.html
+    pub fn zobrist_hash<F>(&self, table: &ZobristTable, mut state: F) -> Result<u64, Error>
+    where
+        F: FnMut(&T) -> Option<usize>,
+    {
+        if (self.num_rows, self.num_columns) != (table.num_rows, table.num_columns) {
+            return Err(Error::shape_mismatch(
+                (table.num_rows, table.num_columns),
+                (self.num_rows, self.num_columns),
+            ));
+        }
+        let mut hash = 0;
+        for (index, element) in self.vecgrid.iter().enumerate() {
+            if let Some(state) = state(element) {
+                table.check_state(state)?;
+                hash ^= table.keys[index * table.num_states + state];
+            }
+        }
+        Ok(hash)
+    }
+
+    /// Computes a fast, non-cryptographic 64 bit fingerprint of the
+    /// dimensions and elements of the vecgrid. Equal vecgrids always have
+    /// equal fingerprints.
+    ///
+    /// The fingerprint is stable across runs of the same version of this
+    /// crate, but may change between versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let a = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// let b = Vecgrid::from_row_major(vec![1, 2, 3, 4], 2, 2)?;
+    /// let c = Vecgrid::from_row_major(vec![1, 2, 3, 4], 1, 4)?;
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// assert_ne!(a.fingerprint(), c.fingerprint());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fingerprint(&self) -> u64
+    where
+        T: Hash,
+    {
+        let mut hasher = FxHasher::default();
+        self.num_rows.hash(&mut hasher);
+        self.num_columns.hash(&mut hasher);
+        Hash::hash_slice(&self.vecgrid, &mut hasher);
+        hasher.finish()
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// The multiply-rotate hasher used by rustc, which is much faster than the
+/// standard library's SipHash for the small keys produced by grid elements.
+#[derive(Default)]
+struct FxHasher {
+    hash: u64,
+}
+
+const SEED: u64 = 0x51_7C_C1_B7_27_22_0A_95;
+
+impl FxHasher {
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            self.add_to_hash(u64::from_le_bytes(word));
+        }
+        for &byte in chunks.remainder() {
+            self.add_to_hash(byte as u64);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
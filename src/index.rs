@@ -0,0 +1,255 @@
+//! A coordinate type that indexing methods accept in place of a
+//! `(row, column)` tuple.
+
+use crate::{Error, Vecgrid};
+
+/// The location of a cell, as a row and a column.
+///
+/// Indexing a [`Vecgrid`] with square brackets, and the [`get_at`],
+/// [`get_mut_at`] and [`set_at`] methods, accept anything that converts
+/// into a [`GridIndex`]: a `(row, column)` tuple, a `[row, column]` array,
+/// or a coordinate type of your own with a [`From`] implementation.
+///
+/// [`get`], [`get_mut`] and [`set`] still take the row and the column as
+/// two arguments, so that code written against them keeps compiling.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{GridIndex, Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// struct Point {
+///     x: usize,
+///     y: usize,
+/// }
+///
+/// impl From<Point> for GridIndex {
+///     fn from(point: Point) -> Self {
+///         GridIndex::new(point.y, point.x)
+///     }
+/// }
+///
+/// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+/// assert_eq!(vecgrid[Point { x: 2, y: 1 }], 6);
+/// assert_eq!(vecgrid[[0, 1]], 2);
+/// assert_eq!(vecgrid.get_at(Point { x: 3, y: 0 }), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`GridIndex`]: struct.GridIndex.html
+/// [`get_at`]: struct.Vecgrid.html#method.get_at
+/// [`get_mut_at`]: struct.Vecgrid.html#method.get_mut_at
+/// [`set_at`]: struct.Vecgrid.html#method.set_at
+/// [`get`]: struct.Vecgrid.html#method.get
+/// [`get_mut`]: struct.Vecgrid.html#method.get_mut
+/// [`set`]: struct.Vecgrid.html#method.set
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub struct GridIndex {
+    /// The row of the cell.
+    pub row: usize,
+    /// The column of the cell.
+    pub column: usize,
+}
+
+impl GridIndex {
+    /// Creates the index of the cell at the given `row` and `column`.
+    pub fn new(row: usize, column: usize) -> Self {
+        GridIndex { row, column }
+    }
+
+    /// Parses a spreadsheet cell address in [A1 notation], such as `"B3"`,
+    /// where the letters name the column, `A` to `Z` and then `AA`, `AB` and
+    /// so on, and the number is the row counted from `1`. Letters may be
+    /// lowercase. Returns [`None`] if `address` is not a valid address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::GridIndex;
+    /// assert_eq!(GridIndex::from_a1("A1"), Some(GridIndex::new(0, 0)));
+    /// assert_eq!(GridIndex::from_a1("B3"), Some(GridIndex::new(2, 1)));
+    /// assert_eq!(GridIndex::from_a1("ab12"), Some(GridIndex::new(11, 27)));
+    /// assert_eq!(GridIndex::from_a1("A0"), None);
+    /// assert_eq!(GridIndex::from_a1("3B"), None);
+    /// ```
+    ///
+    /// [A1 notation]: https://en.wikipedia.org/wiki/Spreadsheet#Cells
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn from_a1(address: &str) -> Option<Self> {
+        let digits = address.find(|c: char| c.is_ascii_digit())?;
+        let (letters, number) = address.split_at(digits);
+        if letters.is_empty() || number.starts_with('0') {
+            return None;
+        }
+        let column = letters.bytes().try_fold(0usize, |column, letter| {
+            if !letter.is_ascii_alphabetic() {
+                return None;
+            }
+            let value = (letter.to_ascii_uppercase() - b'A') as usize + 1;
+            column.checked_mul(26)?.checked_add(value)
+        })?;
+        if !number.bytes().all(|digit| digit.is_ascii_digit()) {
+            return None;
+        }
+        let row = number.parse::<usize>().ok()?;
+        Some(GridIndex::new(row - 1, column - 1))
+    }
+
+    /// Formats the index as a spreadsheet cell address in A1 notation, the
+    /// inverse of [`from_a1`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::GridIndex;
+    /// assert_eq!(GridIndex::new(2, 1).to_a1(), "B3");
+    /// assert_eq!(GridIndex::new(0, 25).to_a1(), "Z1");
+    /// assert_eq!(GridIndex::new(9, 26).to_a1(), "AA10");
+    /// ```
+    ///
+    /// [`from_a1`]: struct.GridIndex.html#method.from_a1
+    pub fn to_a1(&self) -> String {
+        let mut letters = Vec::new();
+        let mut column = self.column as u128 + 1;
+        while column > 0 {
+            column -= 1;
+            letters.push(b'A' + (column % 26) as u8);
+            column /= 26;
+        }
+        letters.reverse();
+        let letters = String::from_utf8(letters).expect("column letters are ASCII");
+        format!("{}{}", letters, self.row as u128 + 1)
+    }
+}
+
+impl From<(usize, usize)> for GridIndex {
+    fn from((row, column): (usize, usize)) -> Self {
+        GridIndex { row, column }
+    }
+}
+
+impl From<[usize; 2]> for GridIndex {
+    fn from([row, column]: [usize; 2]) -> Self {
+        GridIndex { row, column }
+    }
+}
+
+impl From<GridIndex> for (usize, usize) {
+    fn from(index: GridIndex) -> Self {
+        (index.row, index.column)
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Returns a reference to the element at the given index if it is in
+    /// bounds (wrapped in [`Some`]). Returns [`None`] if the index is out of
+    /// bounds. The same as [`get`], but with any index that converts into a
+    /// [`GridIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// assert_eq!(vecgrid.get_at([0, 0]), Some(&42));
+    /// assert_eq!(vecgrid.get_at((10, 10)), None);
+    /// ```
+    ///
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`get`]: struct.Vecgrid.html#method.get
+    /// [`GridIndex`]: struct.GridIndex.html
+    pub fn get_at(&self, index: impl Into<GridIndex>) -> Option<&T> {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+    }
+
+    /// Returns a mutable reference to the element at the given index if it
+    /// is in bounds (wrapped in [`Some`]). Returns [`None`] if the index is
+    /// out of bounds. The same as [`get_mut`], but with any index that
+    /// converts into a [`GridIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let mut vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// if let Some(element) = vecgrid.get_mut_at([1, 2]) {
+    ///     *element = 100;
+    /// }
+    /// assert_eq!(vecgrid[(1, 2)], 100);
+    /// ```
+    ///
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`get_mut`]: struct.Vecgrid.html#method.get_mut
+    /// [`GridIndex`]: struct.GridIndex.html
+    pub fn get_mut_at(&mut self, index: impl Into<GridIndex>) -> Option<&mut T> {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+    }
+
+    /// Changes the element at the given index to `element`. Returns
+    /// [`Ok(())`] if the index was in bounds and returns an [`Err`]
+    /// otherwise. The same as [`set`], but with any index that converts into
+    /// a [`GridIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// vecgrid.set_at([0, 1], 100)?;
+    /// assert_eq!(vecgrid[(0, 1)], 100);
+    /// assert!(vecgrid.set_at((2, 0), 100).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    /// [`set`]: struct.Vecgrid.html#method.set
+    /// [`GridIndex`]: struct.GridIndex.html
+    pub fn set_at(&mut self, index: impl Into<GridIndex>, element: T) -> Result<(), Error> {
+        let GridIndex { row, column } = index.into();
+        self.set(row, column, element)
+    }
+
+    /// Returns a reference to the element at the given spreadsheet cell
+    /// address in A1 notation, such as `"B3"` for row `2` and column `1`, or
+    /// [`None`] if the address is invalid or out of bounds. See
+    /// [`GridIndex::from_a1`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let sheet = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(sheet.get_a1("C2"), Some(&6));
+    /// assert_eq!(sheet.get_a1("D1"), None);
+    /// assert_eq!(sheet.get_a1("not a cell"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`GridIndex::from_a1`]: struct.GridIndex.html#method.from_a1
+    pub fn get_a1(&self, address: &str) -> Option<&T> {
+        self.get_at(GridIndex::from_a1(address)?)
+    }
+
+    /// Returns a mutable reference to the element at the given spreadsheet
+    /// cell address in A1 notation, or [`None`] if the address is invalid or
+    /// out of bounds. See [`get_a1`].
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`get_a1`]: struct.Vecgrid.html#method.get_a1
+    pub fn get_mut_a1(&mut self, address: &str) -> Option<&mut T> {
+        self.get_mut_at(GridIndex::from_a1(address)?)
+    }
+}
@@ -0,0 +1,128 @@
+//! Building a [`Vecgrid`] from a fallible stream of rows, such as the lines
+//! of a large file parsed one at a time, without losing track of which row
+//! went wrong.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::fmt;
+
+/// An error when building a [`Vecgrid`] from a stream of rows with
+/// [`from_row_stream`]. New variants may be added in the future, so matches
+/// must include a wildcard arm.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`from_row_stream`]: struct.Vecgrid.html#method.from_row_stream
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum IngestError<E> {
+    /// The stream returned an error instead of a row.
+    Source {
+        /// The index of the row the stream failed to produce.
+        row: usize,
+        /// The error returned by the stream.
+        error: E,
+    },
+    /// A row did not fit the vecgrid, with the index of the row in the
+    /// [`Error`].
+    ///
+    /// [`Error`]: enum.Error.html
+    Vecgrid(Error),
+}
+
+impl<E: fmt::Display> fmt::Display for IngestError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IngestError::Source { row, error } => write!(f, "row {}: {}", row, error),
+            IngestError::Vecgrid(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<E> std::error::Error for IngestError<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IngestError::Source { error, .. } => Some(error),
+            IngestError::Vecgrid(error) => Some(error),
+        }
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Creates a new [`Vecgrid`] from a stream of rows that may fail, such
+    /// as lines parsed one at a time, moving the elements of every row into
+    /// the vecgrid as soon as it arrives. All rows must have the length of
+    /// the first.
+    ///
+    /// Stops at the first error, which is an [`IngestError::Source`] with the
+    /// index of the row if the stream returns an error, or an
+    /// [`IngestError::Vecgrid`] holding an [`Error::DimensionMismatch`] with
+    /// the index of the row if a row has the wrong length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{IngestError, Vecgrid, Error};
+    /// let lines = ["1 2 3", "4 5 6"];
+    /// let rows = lines.iter().map(|line| {
+    ///     line.split(' ').map(str::parse::<u8>).collect::<Result<Vec<_>, _>>()
+    /// });
+    /// let vecgrid = Vecgrid::from_row_stream(rows).unwrap();
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// let lines = ["1 2", "3 x", "4"];
+    /// let rows = lines.iter().map(|line| {
+    ///     line.split(' ').map(str::parse::<u8>).collect::<Result<Vec<_>, _>>()
+    /// });
+    /// match Vecgrid::from_row_stream(rows) {
+    ///     Err(IngestError::Source { row, .. }) => assert_eq!(row, 1),
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// let rows = vec![Ok::<_, ()>(vec![1, 2]), Ok(vec![3])];
+    /// assert_eq!(
+    ///     Vecgrid::from_row_stream(rows),
+    ///     Err(IngestError::Vecgrid(Error::DimensionMismatch {
+    ///         expected: 2,
+    ///         actual: 1,
+    ///         index: Some(1),
+    ///     }))
+    /// );
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`IngestError::Source`]: enum.IngestError.html#variant.Source
+    /// [`IngestError::Vecgrid`]: enum.IngestError.html#variant.Vecgrid
+    /// [`Error::DimensionMismatch`]: enum.Error.html#variant.DimensionMismatch
+    pub fn from_row_stream<I, E>(rows: I) -> Result<Self, IngestError<E>>
+    where
+        I: IntoIterator<Item = Result<Vec<T>, E>>,
+    {
+        let mut vecgrid = Vecgrid {
+            vecgrid: Vec::new(),
+            num_rows: 0,
+            num_columns: 0,
+        };
+        for (row_index, row) in rows.into_iter().enumerate() {
+            let row = row.map_err(|error| IngestError::Source {
+                row: row_index,
+                error,
+            })?;
+            if row_index == 0 {
+                vecgrid.num_columns = row.len();
+            } else if row.len() != vecgrid.num_columns {
+                return Err(IngestError::Vecgrid(Error::DimensionMismatch {
+                    expected: vecgrid.num_columns,
+                    actual: row.len(),
+                    index: Some(row_index),
+                }));
+            }
+            vecgrid.vecgrid.extend(row);
+            vecgrid.num_rows += 1;
+        }
+        Ok(vecgrid)
+    }
+}
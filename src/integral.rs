@@ -0,0 +1,198 @@
+//! Summed-area tables, also called integral images, of [`Vecgrid`]s of
+//! primitive numbers, for constant time sums over rectangular regions.
+//!
+//! Every element of an integral image is the sum of all elements of the
+//! source above and to the left of it, including its own row and column.
+//! Sums are accumulated in a wider type: `u64` for unsigned integers, `i64`
+//! for signed integers and `f64` for floats.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::ops::Deref;
+
+/// The integral image of a [`Vecgrid`], returned by [`integral_image`]. The
+/// element at `(row, column)` is the sum of all elements of the source in
+/// rows `0..=row` and columns `0..=column`, and sums over any rectangle of
+/// the source can be read in constant time with [`region_sum`].
+///
+/// Integer sums wrap around instead of overflowing, so [`region_sum`] is
+/// exact whenever the sum of the region itself fits in the sum type, even
+/// if the sums of larger regions do not. Read access to the sums is
+/// available through [`Deref`].
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`integral_image`]: struct.Vecgrid.html#method.integral_image
+/// [`region_sum`]: struct.IntegralImage.html#method.region_sum
+/// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct IntegralImage<S> {
+    sums: Vecgrid<S>,
+}
+
+impl<S> IntegralImage<S> {
+    /// Unwraps the underlying [`Vecgrid`] of sums.
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn into_inner(self) -> Vecgrid<S> {
+        self.sums
+    }
+}
+
+impl<S> Deref for IntegralImage<S> {
+    type Target = Vecgrid<S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sums
+    }
+}
+
+/// The arithmetic used to accumulate sums: wrapping for integers, so that
+/// intermediate results cannot overflow, and plain for floats.
+trait Accumulate: Copy {
+    const ZERO: Self;
+
+    fn add(self, other: Self) -> Self;
+
+    fn sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_accumulate {
+    ($($sum:ty => $zero:expr, $add:path, $sub:path);*) => {
+        $(
+            impl Accumulate for $sum {
+                const ZERO: Self = $zero;
+
+                fn add(self, other: Self) -> Self {
+                    $add(self, other)
+                }
+
+                fn sub(self, other: Self) -> Self {
+                    $sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_accumulate!(
+    u64 => 0, u64::wrapping_add, u64::wrapping_sub;
+    i64 => 0, i64::wrapping_add, i64::wrapping_sub;
+    f64 => 0.0, std::ops::Add::add, std::ops::Sub::sub
+);
+
+macro_rules! impl_integral_image {
+    ($($element:ty => $sum:ty),*) => {
+        $(
+            impl Vecgrid<$element> {
+                #[doc = concat!(
+                    "Returns the [`IntegralImage`] of the vecgrid, with `", stringify!($sum),
+                    "` sums and the same dimensions. The element at `(row, column)` is the sum ",
+                    "of all elements in rows `0..=row` and columns `0..=column`. Sums over ",
+                    "rectangles can then be read with [`region_sum`]."
+                )]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                #[doc = concat!(
+                    "let vecgrid = Vecgrid::<", stringify!($element), ">::filled_with(1 as ",
+                    stringify!($element), ", 2, 3);"
+                )]
+                /// let integral = vecgrid.integral_image();
+                #[doc = concat!("assert_eq!(integral[(0, 2)], 3 as ", stringify!($sum), ");")]
+                #[doc = concat!("assert_eq!(integral[(1, 2)], 6 as ", stringify!($sum), ");")]
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [`IntegralImage`]: struct.IntegralImage.html
+                /// [`region_sum`]: struct.IntegralImage.html#method.region_sum
+                pub fn integral_image(&self) -> IntegralImage<$sum> {
+                    let num_columns = self.num_columns;
+                    let mut sums: Vec<$sum> = Vec::with_capacity(self.vecgrid.len());
+                    for (index, &element) in self.vecgrid.iter().enumerate() {
+                        let column = index % num_columns;
+                        let left = if column > 0 { sums[index - 1] } else { <$sum>::ZERO };
+                        let above = if index >= num_columns {
+                            sums[index - num_columns]
+                        } else {
+                            <$sum>::ZERO
+                        };
+                        let above_left = if column > 0 && index >= num_columns {
+                            sums[index - num_columns - 1]
+                        } else {
+                            <$sum>::ZERO
+                        };
+                        sums.push((element as $sum).add(left).add(above).sub(above_left));
+                    }
+                    IntegralImage {
+                        sums: Vecgrid {
+                            vecgrid: sums,
+                            num_rows: self.num_rows,
+                            num_columns,
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_region_sum {
+    ($($sum:ty),*) => {
+        $(
+            impl IntegralImage<$sum> {
+                /// Returns the sum of all elements of the source vecgrid in
+                /// the rectangle with the corners `top_left` and
+                /// `bottom_right`, both included. Takes the same time for
+                /// any size of rectangle. Returns an error if either corner
+                /// is out of bounds.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heat = Vecgrid::<u8>::from_rows(vec![
+                ///     vec![1, 2, 3],
+                ///     vec![4, 5, 6],
+                ///     vec![7, 8, 9],
+                /// ])?;
+                /// let integral = heat.integral_image();
+                /// assert_eq!(integral.region_sum((1, 1), (2, 2))?, 28);
+                /// assert_eq!(integral.region_sum((0, 0), (2, 2))?, 45);
+                /// assert!(integral.region_sum((0, 0), (3, 0)).is_err());
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn region_sum(
+                    &self,
+                    top_left: (usize, usize),
+                    bottom_right: (usize, usize),
+                ) -> Result<$sum, Error> {
+                    let sums = &self.sums;
+                    let (top, left, bottom, right) = sums.rect_bounds(top_left, bottom_right)?;
+                    let at = |row: usize, column: usize| sums.vecgrid[row * sums.num_columns + column];
+                    let above = if top > 0 { at(top - 1, right) } else { <$sum>::ZERO };
+                    let before = if left > 0 { at(bottom, left - 1) } else { <$sum>::ZERO };
+                    let corner = if top > 0 && left > 0 {
+                        at(top - 1, left - 1)
+                    } else {
+                        <$sum>::ZERO
+                    };
+                    Ok(at(bottom, right).add(corner).sub(above).sub(before))
+                }
+            }
+        )*
+    };
+}
+
+impl_integral_image!(
+    u8 => u64, u16 => u64, u32 => u64, u64 => u64,
+    i8 => i64, i16 => i64, i32 => i64, i64 => i64,
+    f32 => f64, f64 => f64
+);
+impl_region_sum!(u64, i64, f64);
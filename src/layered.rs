@@ -0,0 +1,229 @@
+//! A stack of equally sized [`Vecgrid`]s, such as terrain, items and units,
+//! whose dimensions are kept in sync.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, RowBand, Vecgrid};
+
+/// Several layers of [`Vecgrid`]s that always have the same dimensions.
+///
+/// Structural operations such as [`insert_row`] and [`remove_rows`] apply to
+/// every layer at once, and are checked for every layer before any of them
+/// is changed. Layers are modified through a [`RowBand`] view, which cannot
+/// change their dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{LayeredVecgrid, Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let terrain = Vecgrid::filled_with('.', 2, 3);
+/// let units = Vecgrid::filled_with(' ', 2, 3);
+/// let mut map = LayeredVecgrid::new(vec![terrain, units])?;
+/// if let Some(mut units) = map.layer_mut(1) {
+///     units[(1, 2)] = '@';
+/// }
+/// assert_eq!(map.column_view(1, 2)?.collect::<String>(), ".@");
+///
+/// map.remove_row(0)?;
+/// assert_eq!(map.num_rows(), 1);
+/// assert_eq!(map.layer(1).map(|units| units[(0, 2)]), Some('@'));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`insert_row`]: struct.LayeredVecgrid.html#method.insert_row
+/// [`remove_rows`]: struct.LayeredVecgrid.html#method.remove_rows
+/// [`RowBand`]: struct.RowBand.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LayeredVecgrid<T> {
+    layers: Vec<Vecgrid<T>>,
+}
+
+impl<T> LayeredVecgrid<T> {
+    /// Creates a new [`LayeredVecgrid`] from the given layers, from bottom to
+    /// top. Returns an error if the layers do not all have the same
+    /// dimensions.
+    ///
+    /// [`LayeredVecgrid`]: struct.LayeredVecgrid.html
+    pub fn new(layers: Vec<Vecgrid<T>>) -> Result<Self, Error> {
+        if let Some(first) = layers.first() {
+            let shape = (first.num_rows(), first.num_columns());
+            if let Some(index) = layers
+                .iter()
+                .position(|layer| (layer.num_rows(), layer.num_columns()) != shape)
+            {
+                return Err(layer_mismatch(shape, &layers[index], index));
+            }
+        }
+        Ok(LayeredVecgrid { layers })
+    }
+
+    /// Creates a new [`LayeredVecgrid`] of `num_layers` layers with the
+    /// specified number of rows and columns that contains `element` in every
+    /// location.
+    ///
+    /// [`LayeredVecgrid`]: struct.LayeredVecgrid.html
+    pub fn filled_with(element: T, num_layers: usize, num_rows: usize, num_columns: usize) -> Self
+    where
+        T: Clone,
+    {
+        LayeredVecgrid {
+            layers: vec![Vecgrid::filled_with(element, num_rows, num_columns); num_layers],
+        }
+    }
+
+    /// The number of layers.
+    pub fn num_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// The number of rows of every layer, or `0` if there are no layers.
+    pub fn num_rows(&self) -> usize {
+        self.layers.first().map_or(0, Vecgrid::num_rows)
+    }
+
+    /// The number of columns of every layer, or `0` if there are no layers.
+    pub fn num_columns(&self) -> usize {
+        self.layers.first().map_or(0, Vecgrid::num_columns)
+    }
+
+    /// Returns the layer at the given index, or [`None`] if the index is out
+    /// of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn layer(&self, index: usize) -> Option<&Vecgrid<T>> {
+        self.layers.get(index)
+    }
+
+    /// Returns a mutable view of every row of the layer at the given index,
+    /// or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn layer_mut(&mut self, index: usize) -> Option<RowBand<'_, T>> {
+        self.layers.get_mut(index).map(Vecgrid::as_band)
+    }
+
+    /// Returns an [`Iterator`] over the elements of every layer, from bottom
+    /// to top, at the given `row` and `column`. Returns an error if the
+    /// indices are out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_view(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &T>, Error> {
+        if row >= self.num_rows() || column >= self.num_columns() {
+            return Err(Error::IndicesOutOfBounds {
+                row,
+                column,
+                num_rows: self.num_rows(),
+                num_columns: self.num_columns(),
+            });
+        }
+        Ok(self.layers.iter().map(move |layer| &layer[(row, column)]))
+    }
+
+    /// Adds `layer` on top of the others. Returns an error if its dimensions
+    /// differ from those of the other layers.
+    pub fn push_layer(&mut self, layer: Vecgrid<T>) -> Result<(), Error> {
+        if let Some(first) = self.layers.first() {
+            let shape = (first.num_rows(), first.num_columns());
+            if (layer.num_rows(), layer.num_columns()) != shape {
+                return Err(layer_mismatch(shape, &layer, self.layers.len()));
+            }
+        }
+        self.layers.push(layer);
+        Ok(())
+    }
+
+    /// Removes and returns the layer at the given index. Returns an error if
+    /// the index is out of bounds.
+    pub fn remove_layer(&mut self, index: usize) -> Result<Vecgrid<T>, Error> {
+        if index >= self.layers.len() {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.layers.len(),
+            });
+        }
+        Ok(self.layers.remove(index))
+    }
+
+    /// Inserts one row into every layer at the provided row index, taking
+    /// the row for layer `i` from `rows[i]`. Returns an error, without
+    /// changing any layer, if there is not exactly one row per layer, a row
+    /// has the wrong length or the index is out of bounds.
+    pub fn insert_row(&mut self, rows: Vec<Vec<T>>, at: usize) -> Result<(), Error> {
+        let num_columns = self.num_columns();
+        self.check_per_layer(&rows, num_columns)?;
+        self.apply_per_layer(rows, |layer, row| layer.insert_row(row, at))
+    }
+
+    /// Inserts one column into every layer at the provided column index,
+    /// taking the column for layer `i` from `columns[i]`. Returns an error,
+    /// without changing any layer, if there is not exactly one column per
+    /// layer, a column has the wrong length or the index is out of bounds.
+    pub fn insert_column(&mut self, columns: Vec<Vec<T>>, at: usize) -> Result<(), Error> {
+        let num_rows = self.num_rows();
+        self.check_per_layer(&columns, num_rows)?;
+        self.apply_per_layer(columns, |layer, column| layer.insert_column(column, at))
+    }
+
+    /// Removes the row at the provided row index from every layer. Returns
+    /// an error, without changing any layer, if the index is out of bounds.
+    pub fn remove_row(&mut self, at: usize) -> Result<(), Error> {
+        self.remove_rows(at, 1)
+    }
+
+    /// Removes `n` consecutive rows at the provided row index from every
+    /// layer. Returns an error, without changing any layer, if the rows are
+    /// out of bounds.
+    pub fn remove_rows(&mut self, at: usize, n: usize) -> Result<(), Error> {
+        for layer in &mut self.layers {
+            layer.remove_rows(at, n)?;
+        }
+        Ok(())
+    }
+
+    /// Unwraps the layers, from bottom to top.
+    pub fn into_layers(self) -> Vec<Vecgrid<T>> {
+        self.layers
+    }
+
+    fn check_per_layer(&self, lines: &[Vec<T>], len: usize) -> Result<(), Error> {
+        if lines.len() != self.layers.len() {
+            return Err(Error::dimension_mismatch(self.layers.len(), lines.len()));
+        }
+        if let Some(index) = lines.iter().position(|line| line.len() != len) {
+            return Err(Error::DimensionMismatch {
+                expected: len,
+                actual: lines[index].len(),
+                index: Some(index),
+            });
+        }
+        Ok(())
+    }
+
+    /// Applies `operation` to every layer with its line. The lines must have
+    /// been checked already, so only the first layer can fail, on an index
+    /// out of bounds, before anything has changed.
+    fn apply_per_layer<F>(&mut self, lines: Vec<Vec<T>>, operation: F) -> Result<(), Error>
+    where
+        F: Fn(&mut Vecgrid<T>, Vec<T>) -> Result<(), Error>,
+    {
+        for (layer, line) in self.layers.iter_mut().zip(lines) {
+            operation(layer, line)?;
+        }
+        Ok(())
+    }
+}
+
+fn layer_mismatch<T>(shape: (usize, usize), layer: &Vecgrid<T>, index: usize) -> Error {
+    Error::ShapeMismatch {
+        expected: shape,
+        actual: (layer.num_rows(), layer.num_columns()),
+        index: Some(index),
+    }
+}
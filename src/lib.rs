@@ -24,6 +24,8 @@
 //!     [`filled_by_column_major`]).
 //!   - Providing an iterator that is used to produce values to fill the vecgrid
 //!     (see [`from_iter_row_major`] and [`from_iter_column_major`]).
+//!   - Writing the rows literally, with lengths checked at compile time (see
+//!     [`vecgrid!`]).
 //!
 //! ## Extending a [`Vecgrid`]
 //!
@@ -33,19 +35,29 @@
 //!     or providing a mutable slice of rows to [`insert_rows`].
 //!   - Append the grid, either with matching length rows via [`append_rows`]... or future additions!
 //!   - Remove singular or consecutive rows via [`remove_row`] and [`remove_rows`] respectively.
+//!   - Combine grids side by side or on top of each other via [`concat_horizontal`],
+//!     [`concat_vertical`], [`hstack`] and [`vstack`], or repeat a grid via [`tiled`].
 //!
 //! ## Accessing data from an [`Vecgrid`]
 //!
 //! [`Vecgrid`] supports several forms of indexing:
 //!   - Using the indexing syntax (square brackets) with a tuple of [`(usize,
-//!     usize)`], which panics on out-of-bounds accesses.
+//!     usize)`], which panics on out-of-bounds accesses. Arrays `[row, column]`
+//!     and your own coordinate types also work through [`GridIndex`], as do
+//!     the [`get_at`], [`get_mut_at`] and [`set_at`] methods.
 //!   - Using the [`get`], [`get_mut`], and [`set`] methods, which return an
-//!     [`Option`] or a [`Result`] on out-of-bounds accesses.
+//!     [`Option`] or a [`Result`] on out-of-bounds accesses. They keep their
+//!     separate `row` and `column` arguments so existing calls still compile;
+//!     [`get_at`], [`get_mut_at`] and [`set_at`] are the same methods taking
+//!     a [`GridIndex`].
 //!   - Using the row major or column major version of these methods,
 //!     i.e. [`get_row_major`], [`get_mut_row_major`], [`set_row_major`],
 //!     [`get_column_major`], [`get_mut_column_major`],
 //!     [`set_column_major`]. These perform the same tasks as the non row/column
 //!     major methods, but take one index instead of two.
+//!   - Using the `unsafe` [`get_unchecked`] and [`get_unchecked_mut`] methods,
+//!     which skip the bounds checks in hot loops whose indices are known to be
+//!     in bounds.
 //!
 //! [`Vecgrid`] also supports several forms of iteration. You can iterate
 //! through:
@@ -58,6 +70,10 @@
 //!   - All rows or all columns (see [`rows_iter`] and [`columns_iter`]).
 //!   - All rows or all columns of mutable entries (see [`rows_iter_mut`] and [`columns_iter_mut`]).
 //!
+//! The same reading and iteration API is available without copying on a
+//! borrowed slice, such as a memory-mapped file, through [`VecgridRef`] and
+//! [`VecgridRefMut`].
+//!
 //!
 //! ## Extracting all data from an [`Vecgrid`]
 //!
@@ -66,6 +82,32 @@
 //!   - A [`Vec`] of rows or columns (see [`as_rows`] and [`as_columns`]).
 //!   - A "flat" [`Vec`] of elements in either [row major or column major order]
 //!     (see [`as_row_major`] and [`as_column_major`]).
+//!   - Owned rows, columns or elements without cloning, consuming the
+//!     vecgrid (see [`into_rows`], [`into_columns`], [`into_row_major`] and
+//!     [`into_column_major`]).
+//!   - Runs of equal elements, which is much smaller for mostly uniform
+//!     grids (see [`to_rle`] and [`from_rle`]).
+//!
+//! # Performance
+//!
+//! All elements live in a single [`Vec`] in row major order, with the
+//! element at `(row, column)` at index `row * num_columns + column`. This
+//! layout is part of the public API, so code using [`as_row_major_slice`],
+//! [`as_ptr`] or [`into_raw_parts`] for FFI, SIMD or WebAssembly can rely on
+//! it, with the strides given by [`row_stride`] and [`column_stride`].
+//! Because of it:
+//!   - Iterating in row major order walks contiguous memory and is as fast as
+//!     iterating a slice.
+//!   - Iterating in column major order steps through the buffer with a stride
+//!     of one row. It needs no bounds checks, but touches a new cache line for
+//!     nearly every element of a large vecgrid, so prefer row major order in
+//!     hot loops.
+//!   - Appending rows is amortized `O(row length)`, while inserting or
+//!     removing rows anywhere else moves every element after them. A
+//!     `Vec<Vec<T>>` only moves one pointer per row in that case.
+//!
+//! The benchmarks in the `benches` directory compare these cases with a
+//! `Vec<Vec<T>>` and can be run with `cargo bench`.
 //!
 //! # Examples
 //!
@@ -140,6 +182,7 @@
 //! [`from_row_major`]: struct.Vecgrid.html#method.from_row_major
 //! [`from_column_major`]: struct.Vecgrid.html#method.from_column_major
 //! [`filled_with`]: struct.Vecgrid.html#method.filled_with
+//! [`vecgrid!`]: macro.vecgrid.html
 //! [`filled_by_row_major`]: struct.Vecgrid.html#method.filled_by_row_major
 //! [`filled_by_column_major`]: struct.Vecgrid.html#method.filled_by_column_major
 //! [`from_iter_row_major`]: struct.Vecgrid.html#method.from_iter_row_major
@@ -153,6 +196,8 @@
 //! [`get_column_major`]: struct.Vecgrid.html#method.get_column_major
 //! [`get_mut_column_major`]: struct.Vecgrid.html#method.get_mut_column_major
 //! [`set_column_major`]: struct.Vecgrid.html#method.set_column_major
+//! [`get_unchecked`]: struct.Vecgrid.html#method.get_unchecked
+//! [`get_unchecked_mut`]: struct.Vecgrid.html#method.get_unchecked_mut
 //! [`elements_row_major_iter`]: struct.Vecgrid.html#method.elements_row_major_iter
 //! [`elements_column_major_iter`]: struct.Vecgrid.html#method.elements_column_major_iter
 //! [`elements_row_major_iter_mut`]: struct.Vecgrid.html#method.elements_row_major_iter_mut
@@ -169,11 +214,33 @@
 //! [`as_columns`]: struct.Vecgrid.html#method.as_columns
 //! [`as_row_major`]: struct.Vecgrid.html#method.as_row_major
 //! [`as_column_major`]: struct.Vecgrid.html#method.as_column_major
+//! [`into_rows`]: struct.Vecgrid.html#method.into_rows
+//! [`into_columns`]: struct.Vecgrid.html#method.into_columns
+//! [`into_row_major`]: struct.Vecgrid.html#method.into_row_major
+//! [`into_column_major`]: struct.Vecgrid.html#method.into_column_major
+//! [`GridIndex`]: struct.GridIndex.html
+//! [`VecgridRef`]: struct.VecgridRef.html
+//! [`VecgridRefMut`]: struct.VecgridRefMut.html
+//! [`get_at`]: struct.Vecgrid.html#method.get_at
+//! [`get_mut_at`]: struct.Vecgrid.html#method.get_mut_at
+//! [`set_at`]: struct.Vecgrid.html#method.set_at
+//! [`to_rle`]: struct.Vecgrid.html#method.to_rle
+//! [`from_rle`]: struct.Vecgrid.html#method.from_rle
 //! [`insert_row`]: struct.Vecgrid.html#method.insert_row
 //! [`insert_rows`]: struct.Vecgrid.html#method.insert_rows
 //! [`append_rows`]: struct.Vecgrid.html#method.append_rows
 //! [`remove_row`]: struct.Vecgrid.html#method.remove_row
 //! [`remove_rows`]: struct.Vecgrid.html#method.remove_rows
+//! [`concat_horizontal`]: struct.Vecgrid.html#method.concat_horizontal
+//! [`concat_vertical`]: struct.Vecgrid.html#method.concat_vertical
+//! [`hstack`]: struct.Vecgrid.html#method.hstack
+//! [`vstack`]: struct.Vecgrid.html#method.vstack
+//! [`tiled`]: struct.Vecgrid.html#method.tiled
+//! [`as_row_major_slice`]: struct.Vecgrid.html#method.as_row_major_slice
+//! [`as_ptr`]: struct.Vecgrid.html#method.as_ptr
+//! [`into_raw_parts`]: struct.Vecgrid.html#method.into_raw_parts
+//! [`row_stride`]: struct.Vecgrid.html#method.row_stride
+//! [`column_stride`]: struct.Vecgrid.html#method.column_stride
 //! [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 //! [`Option`]: https://doc.rust-lang.org/std/option/
 //! [`Result`]: https://doc.rust-lang.org/std/result/
@@ -182,11 +249,131 @@
 
 #![deny(missing_docs)]
 
-use std::ops::{Index, IndexMut};
+use std::fmt;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "tracing")]
+use tracing::instrument;
+
+/// Emits a `tracing` event with the new dimensions of a vecgrid after a
+/// structural change, if the `tracing` feature is enabled.
+macro_rules! trace_reshape {
+    ($vecgrid:expr, $message:literal) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            num_rows = $vecgrid.num_rows,
+            num_columns = $vecgrid.num_columns,
+            $message
+        );
+    };
+}
+
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+mod autotile;
+mod band;
+mod broadcast;
+mod bulk;
+mod channels;
+mod chunked;
+mod column;
+mod column_cache;
+mod compare;
+mod components;
+#[cfg(feature = "deflate")]
+mod compress;
+mod contour;
+mod count;
+mod cow;
+#[cfg(feature = "csv")]
+mod csv_io;
+mod draw;
+mod float;
+mod grid;
+#[cfg(feature = "gridly")]
+mod gridly_compat;
+mod group;
+mod hash;
+mod index;
+mod ingest;
+mod integral;
+mod layered;
+mod line;
+mod macros;
+mod mask;
+mod matrix;
+mod morphology;
+mod neighborhood;
+mod netpbm;
+#[cfg(feature = "noise")]
+mod noise;
+mod offset;
+mod order;
+mod pathfinding;
+#[cfg(feature = "pixel")]
+mod pixel;
+#[cfg(feature = "rand")]
+mod random;
+mod rectangle;
+mod remap;
+mod rle;
+mod roi;
+mod sample;
+mod scan;
+mod seam;
+mod search;
+#[cfg(feature = "stats")]
+mod stats;
+mod stencil;
+mod stream;
+mod table;
+#[cfg(feature = "term")]
+mod term;
+mod terrain;
+mod tracked;
+mod transaction;
+mod traversal;
+mod view;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use band::RowBand;
+pub use chunked::ChunkedGrid;
+pub use column::ColumnMut;
+pub use column_cache::ColumnCachedVecgrid;
+pub use cow::CowVecgrid;
+#[cfg(feature = "csv")]
+pub use csv_io::CsvError;
+pub use grid::{Grid, GridMut};
+pub use hash::ZobristTable;
+pub use index::GridIndex;
+pub use ingest::IngestError;
+pub use integral::IntegralImage;
+pub use layered::LayeredVecgrid;
+pub use neighborhood::Neighborhood;
+#[cfg(feature = "noise")]
+pub use noise::NoiseCoordinates;
+pub use offset::OffsetGrid;
+pub use pathfinding::Moves;
+#[cfg(feature = "pixel")]
+pub use pixel::{Rgb, Rgba};
+pub use rle::RunLengthEncoded;
+pub use roi::{VecgridView, VecgridViewMut};
+pub use sample::Resampling;
+pub use stencil::{CenterView, EdgeMode};
+pub use table::Table;
+#[cfg(feature = "term")]
+pub use term::{CellStyle, Color};
+pub use tracked::TrackedVecgrid;
+pub use transaction::Transaction;
+pub use traversal::Parity;
+pub use view::{VecgridRef, VecgridRefMut};
+
 /// A dynamically sized two-dimensional vec.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -198,19 +385,171 @@ pub struct Vecgrid<T> {
 
 /// An error that can arise during the use of an [`Vecgrid`].
 ///
+/// Every variant carries the context needed to tell what went wrong, e.g.
+/// which row of the input had the wrong length. New variants may be added in
+/// the future, so matches must include a wildcard arm.
+///
 /// [`Vecgrid`]: struct.Vecgrid.html
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum Error {
     /// The given indices were out of bounds.
-    IndicesOutOfBounds(usize, usize),
+    IndicesOutOfBounds {
+        /// The requested row.
+        row: usize,
+        /// The requested column.
+        column: usize,
+        /// The number of rows that were available.
+        num_rows: usize,
+        /// The number of columns that were available.
+        num_columns: usize,
+    },
     /// The given index in row or column major order was out of bounds.
-    IndexOutOfBounds(usize),
-    /// The dimensions given did not match the elements provided
-    DimensionMismatch,
+    IndexOutOfBounds {
+        /// The requested index.
+        index: usize,
+        /// The exclusive upper bound the index had to stay below.
+        len: usize,
+    },
+    /// The dimensions given did not match the elements provided.
+    DimensionMismatch {
+        /// The expected length or dimension.
+        expected: usize,
+        /// The length or dimension that was provided.
+        actual: usize,
+        /// The position of the offending row, column or vecgrid if one of
+        /// several inputs was wrong.
+        index: Option<usize>,
+    },
+    /// Two vecgrids that had to have the same number of rows and columns
+    /// did not.
+    ShapeMismatch {
+        /// The expected `(rows, columns)`.
+        expected: (usize, usize),
+        /// The `(rows, columns)` that were provided.
+        actual: (usize, usize),
+        /// The position of the offending vecgrid if one of several inputs
+        /// was wrong.
+        index: Option<usize>,
+    },
     /// There were not enough elements to fill the vecgrid.
-    NotEnoughElements,
+    NotEnoughElements {
+        /// The number of elements needed.
+        expected: usize,
+        /// The number of elements provided.
+        actual: usize,
+    },
+    /// The number of elements of a vecgrid with the given dimensions, or the
+    /// number of elements provided for it, does not fit in a `usize`.
+    ElementCountOverflow {
+        /// The number of rows given.
+        num_rows: usize,
+        /// The number of columns given.
+        num_columns: usize,
+    },
+    /// The given cell state was not one of the states of a table.
+    StateOutOfBounds {
+        /// The requested state.
+        state: usize,
+        /// The number of states that were available.
+        num_states: usize,
+    },
+}
+
+impl Error {
+    pub(crate) fn dimension_mismatch(expected: usize, actual: usize) -> Error {
+        Error::DimensionMismatch {
+            expected,
+            actual,
+            index: None,
+        }
+    }
+
+    pub(crate) fn indices_out_of_bounds(
+        row: usize,
+        column: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Error {
+        Error::IndicesOutOfBounds {
+            row,
+            column,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    pub(crate) fn shape_mismatch(expected: (usize, usize), actual: (usize, usize)) -> Error {
+        Error::ShapeMismatch {
+            expected,
+            actual,
+            index: None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::IndicesOutOfBounds {
+                row,
+                column,
+                num_rows,
+                num_columns,
+            } => write!(
+                f,
+                "indices ({}, {}) out of bounds for {} rows and {} columns",
+                row, column, num_rows, num_columns
+            ),
+            Error::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds for length {}", index, len)
+            }
+            Error::DimensionMismatch {
+                expected,
+                actual,
+                index,
+            } => {
+                write!(f, "expected length {} but got {}", expected, actual)?;
+                match index {
+                    Some(index) => write!(f, " at position {}", index),
+                    None => Ok(()),
+                }
+            }
+            Error::ShapeMismatch {
+                expected,
+                actual,
+                index,
+            } => {
+                write!(
+                    f,
+                    "expected {} rows and {} columns but got {} rows and {} columns",
+                    expected.0, expected.1, actual.0, actual.1
+                )?;
+                match index {
+                    Some(index) => write!(f, " at position {}", index),
+                    None => Ok(()),
+                }
+            }
+            Error::NotEnoughElements { expected, actual } => {
+                write!(f, "expected {} elements but got {}", expected, actual)
+            }
+            Error::ElementCountOverflow {
+                num_rows,
+                num_columns,
+            } => write!(
+                f,
+                "element count overflows for {} rows and {} columns",
+                num_rows, num_columns
+            ),
+            Error::StateOutOfBounds { state, num_states } => {
+                write!(f, "state {} out of bounds for {} states", state, num_states)
+            }
+        }
+    }
 }
 
+impl std::error::Error for Error {}
+
 impl<T> Vecgrid<T> {
     /// Creates a new [`Vecgrid`] from a [`Vec`] of rows, each of which is a
     /// [`Vec`] of elements.
@@ -233,7 +572,7 @@ impl<T> Vecgrid<T> {
     /// [`Vecgrid`]: struct.Vecgrid.html
     /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
     pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Error> {
-        let row_len = rows.get(0).map(Vec::len).unwrap_or(0);
+        let row_len = rows.first().map(Vec::len).unwrap_or(0);
         let mut vecgrid = Vecgrid {
             vecgrid: Vec::new(),
             num_rows: 0,
@@ -263,24 +602,19 @@ impl<T> Vecgrid<T> {
     ///
     /// [`Vecgrid`]: struct.Vecgrid.html
     /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
-    pub fn from_columns(columns: Vec<Vec<T>>) -> Result<Self, Error>
-    where
-        T: Clone, //TODO: Remove type guard
-    {
-        let column_len = columns.get(0).map(Vec::len).unwrap_or(0);
-        if !columns.iter().all(|column| column.len() == column_len) {
-            return Err(Error::DimensionMismatch);
+    pub fn from_columns(columns: Vec<Vec<T>>) -> Result<Self, Error> {
+        let column_len = columns.first().map(Vec::len).unwrap_or(0);
+        if let Some(index) = columns.iter().position(|column| column.len() != column_len) {
+            return Err(Error::DimensionMismatch {
+                expected: column_len,
+                actual: columns[index].len(),
+                index: Some(index),
+            });
         }
         let num_rows = column_len;
         let num_columns = columns.len();
-        let vecgrid = indices_row_major(num_rows, num_columns)
-            .map(|(row, column)| columns[column][row].clone())
-            .collect();
-        Ok(Vecgrid {
-            vecgrid,
-            num_rows,
-            num_columns,
-        })
+        let column_major = columns.into_iter().flatten().collect();
+        Vecgrid::from_column_major(column_major, num_rows, num_columns)
     }
 
     /// Creates a new [`Vecgrid`] from the given flat [`Vec`] in [row major
@@ -312,7 +646,7 @@ impl<T> Vecgrid<T> {
     ) -> Result<Self, Error> {
         let total_len = num_rows * num_columns;
         if total_len != elements.len() {
-            return Err(Error::DimensionMismatch);
+            return Err(Error::dimension_mismatch(total_len, elements.len()));
         }
         Ok(Vecgrid {
             vecgrid: elements,
@@ -344,27 +678,94 @@ impl<T> Vecgrid<T> {
     /// [`Vecgrid`]: struct.Vecgrid.html
     /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
     pub fn from_column_major(
-        elements: Vec<T>,
+        mut elements: Vec<T>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Result<Self, Error> {
+        let total_len = num_rows * num_columns;
+        if total_len != elements.len() {
+            return Err(Error::dimension_mismatch(total_len, elements.len()));
+        }
+        column_major_to_row_major(&mut elements, num_rows, num_columns);
+        Ok(Vecgrid {
+            vecgrid: elements,
+            num_rows,
+            num_columns,
+        })
+    }
+
+    /// Creates a new [`Vecgrid`] by cloning the elements of the given rows,
+    /// borrowed as slices, for example from a static table.
+    ///
+    /// Returns an error if the rows are not all the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// static TABLE: &[&[u8]] = &[&[1, 2, 3], &[4, 5, 6]];
+    /// let vecgrid = Vecgrid::from_row_slices(TABLE)?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn from_row_slices(rows: &[&[T]]) -> Result<Self, Error>
+    where
+        T: Clone,
+    {
+        let row_len = rows.first().map_or(0, |row| row.len());
+        if let Some(index) = rows.iter().position(|row| row.len() != row_len) {
+            return Err(Error::DimensionMismatch {
+                expected: row_len,
+                actual: rows[index].len(),
+                index: Some(index),
+            });
+        }
+        Ok(Vecgrid {
+            vecgrid: rows.concat(),
+            num_rows: rows.len(),
+            num_columns: row_len,
+        })
+    }
+
+    /// Creates a new [`Vecgrid`] by cloning the elements of the given flat
+    /// slice in [row major order], for example a memory-mapped file.
+    ///
+    /// Returns an error if the number of elements in `elements` is not the
+    /// product of `num_rows` and `num_columns`, i.e. the dimensions do not
+    /// match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let bytes = [1u8, 2, 3, 4, 5, 6];
+    /// let vecgrid = Vecgrid::from_flat_slice(&bytes, 2, 3)?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn from_flat_slice(
+        elements: &[T],
         num_rows: usize,
         num_columns: usize,
     ) -> Result<Self, Error>
     where
-        T: Clone, // TODO: remove type guard
+        T: Clone,
     {
         let total_len = num_rows * num_columns;
         if total_len != elements.len() {
-            return Err(Error::DimensionMismatch);
+            return Err(Error::dimension_mismatch(total_len, elements.len()));
         }
-        let indices_row_major =
-            (0..num_rows).flat_map(move |row| (0..num_columns).map(move |column| (row, column)));
-        let vecgrid = indices_row_major
-            .map(|(row, column)| {
-                let index = column * num_rows + row;
-                elements[index].clone()
-            })
-            .collect();
         Ok(Vecgrid {
-            vecgrid,
+            vecgrid: elements.to_vec(),
             num_rows,
             num_columns,
         })
@@ -451,7 +852,6 @@ impl<T> Vecgrid<T> {
     pub fn filled_by_column_major<F>(mut generator: F, num_rows: usize, num_columns: usize) -> Self
     where
         F: FnMut() -> T,
-        T: Clone,
     {
         let total_len = num_rows * num_columns;
         let vecgrid_column_major = (0..total_len).map(|_| generator()).collect::<Vec<_>>();
@@ -463,7 +863,9 @@ impl<T> Vecgrid<T> {
     /// and fills each element with the elements produced from the provided
     /// iterator. If the iterator produces more than enough elements, the
     /// remaining are unused. Returns an error if the iterator does not produce
-    /// enough elements.
+    /// enough elements. An iterator whose size hint already shows it is too
+    /// short, such as a short [`ExactSizeIterator`], is rejected before any
+    /// element is consumed.
     ///
     /// The elements are inserted into the vecgrid in [row major order].
     ///
@@ -480,6 +882,7 @@ impl<T> Vecgrid<T> {
     /// ```
     ///
     /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
     /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
     pub fn from_iter_row_major<I>(
         iterator: I,
@@ -489,11 +892,7 @@ impl<T> Vecgrid<T> {
     where
         I: Iterator<Item = T>,
     {
-        let total_len = num_rows * num_columns;
-        let vecgrid = iterator.take(total_len).collect::<Vec<_>>();
-        if vecgrid.len() != total_len {
-            return Err(Error::NotEnoughElements);
-        }
+        let vecgrid = take_elements(iterator, num_rows * num_columns)?;
         Ok(Vecgrid {
             vecgrid,
             num_rows,
@@ -505,7 +904,9 @@ impl<T> Vecgrid<T> {
     /// and fills each element with the elements produced from the provided
     /// iterator. If the iterator produces more than enough elements, the
     /// remaining are unused. Returns an error if the iterator does not produce
-    /// enough elements.
+    /// enough elements. An iterator whose size hint already shows it is too
+    /// short, such as a short [`ExactSizeIterator`], is rejected before any
+    /// element is consumed.
     ///
     /// The elements are inserted into the vecgrid in [column major order].
     ///
@@ -522,6 +923,7 @@ impl<T> Vecgrid<T> {
     /// ```
     ///
     /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
     /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
     pub fn from_iter_column_major<I>(
         iterator: I,
@@ -530,12 +932,49 @@ impl<T> Vecgrid<T> {
     ) -> Result<Self, Error>
     where
         I: Iterator<Item = T>,
-        T: Clone,
     {
-        let total_len = num_rows * num_columns;
-        let vecgrid_column_major = iterator.take(total_len).collect::<Vec<_>>();
+        let vecgrid_column_major = take_elements(iterator, num_rows * num_columns)?;
         Vecgrid::from_column_major(vecgrid_column_major, num_rows, num_columns)
-            .map_err(|_| Error::NotEnoughElements)
+    }
+
+    /// Overwrites every element of the [`Vecgrid`] in place with the elements
+    /// produced from the provided iterator, keeping the current dimensions
+    /// and reusing the existing allocation. If the iterator produces more
+    /// than enough elements, the remaining are unused. Returns an error if the
+    /// iterator does not produce enough elements, in which case the elements
+    /// that were already produced have been written.
+    ///
+    /// The elements are written into the vecgrid in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(0, 2, 3);
+    /// vecgrid.refill_from_iter_row_major(1..)?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// let result = vecgrid.refill_from_iter_row_major(1..3);
+    /// assert_eq!(result, Err(Error::NotEnoughElements { expected: 6, actual: 2 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn refill_from_iter_row_major<I>(&mut self, iterator: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iterator = iterator.into_iter();
+        let expected = self.vecgrid.len();
+        for (actual, location) in self.vecgrid.iter_mut().enumerate() {
+            *location = iterator
+                .next()
+                .ok_or(Error::NotEnoughElements { expected, actual })?;
+        }
+        Ok(())
     }
 
     /// The number of rows.
@@ -564,6 +1003,42 @@ impl<T> Vecgrid<T> {
         self.num_rows
     }
 
+    /// The distance, in elements, between the start of one row and the start
+    /// of the next in the buffer returned by [`as_row_major_slice`], i.e. the
+    /// number of columns. The element at `(row, column)` is at index
+    /// `row * row_stride() + column * column_stride()`.
+    ///
+    /// Rows are stored contiguously and without padding, and this is part of
+    /// the public API, so FFI and SIMD code can rely on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let (row_stride, column_stride) = (vecgrid.row_stride(), vecgrid.column_stride());
+    /// assert_eq!((row_stride, column_stride), (3, 1));
+    /// assert_eq!(vecgrid.as_row_major_slice()[1 * row_stride + 2 * column_stride], 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`as_row_major_slice`]: struct.Vecgrid.html#method.as_row_major_slice
+    pub fn row_stride(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The distance, in elements, between neighboring elements of a row in
+    /// the buffer returned by [`as_row_major_slice`], which is always `1`
+    /// since rows are contiguous. See [`row_stride`].
+    ///
+    /// [`as_row_major_slice`]: struct.Vecgrid.html#method.as_row_major_slice
+    /// [`row_stride`]: struct.Vecgrid.html#method.row_stride
+    pub fn column_stride(&self) -> usize {
+        1
+    }
+
     /// Returns a reference to the element at the given `row` and `column` if the
     /// index is in bounds (wrapped in [`Some`]). Returns [`None`] if the index
     /// is out of bounds.
@@ -625,11 +1100,40 @@ impl<T> Vecgrid<T> {
     ///
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
     pub fn get_column_major(&self, index: usize) -> Option<&T> {
-        let column = dbg!(dbg!(index) / self.num_rows);
-        let row = dbg!(index % self.num_rows);
+        let column = index.checked_div(self.num_rows)?;
+        let row = index % self.num_rows;
         self.get(row, column)
     }
 
+    /// Returns a reference to the element at the given `row` and `column`
+    /// without checking that the indices are in bounds.
+    ///
+    /// For a safe alternative see [`get`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with `row >= num_rows()` or
+    /// `column >= num_columns()` is *[undefined behavior]*, even if the
+    /// resulting reference is not used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// for (row, column) in vecgrid.indices_row_major() {
+    ///     // SAFETY: the indices come from the vecgrid itself.
+    ///     assert_eq!(unsafe { vecgrid.get_unchecked(row, column) }, &42);
+    /// }
+    /// ```
+    ///
+    /// [`get`]: struct.Vecgrid.html#method.get
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked(&self, row: usize, column: usize) -> &T {
+        debug_assert!(row < self.num_rows && column < self.num_columns);
+        self.vecgrid.get_unchecked(row * self.num_columns + column)
+    }
+
     /// Returns a mutable reference to the element at the given `row` and
     /// `column` if the index is in bounds (wrapped in [`Some`]). Returns
     /// [`None`] if the index is out of bounds.
@@ -710,11 +1214,40 @@ impl<T> Vecgrid<T> {
     ///
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
     pub fn get_mut_column_major(&mut self, index: usize) -> Option<&mut T> {
-        let column = index / self.num_rows;
+        let column = index.checked_div(self.num_rows)?;
         let row = index % self.num_rows;
         self.get_mut(row, column)
     }
 
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column` without checking that the indices are in bounds.
+    ///
+    /// For a safe alternative see [`get_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with `row >= num_rows()` or
+    /// `column >= num_columns()` is *[undefined behavior]*, even if the
+    /// resulting reference is not used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let mut vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// // SAFETY: (1, 2) is within the 2 by 3 vecgrid.
+    /// unsafe { *vecgrid.get_unchecked_mut(1, 2) = 100 };
+    /// assert_eq!(vecgrid[(1, 2)], 100);
+    /// ```
+    ///
+    /// [`get_mut`]: struct.Vecgrid.html#method.get_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, column: usize) -> &mut T {
+        debug_assert!(row < self.num_rows && column < self.num_columns);
+        self.vecgrid
+            .get_unchecked_mut(row * self.num_columns + column)
+    }
+
     /// Changes the element at given `row` and `column` to `element`. Returns
     /// [`Ok(())`] if the indices were in bounds and returns an [`Err`]
     /// otherwise.
@@ -730,7 +1263,10 @@ impl<T> Vecgrid<T> {
     /// assert_eq!(vecgrid.get(0, 0), Some(&100));
     ///
     /// let result = vecgrid.set(10, 20, 200);
-    /// assert_eq!(result, Err(Error::IndicesOutOfBounds(10, 20)));
+    /// assert_eq!(
+    ///     result,
+    ///     Err(Error::IndicesOutOfBounds { row: 10, column: 20, num_rows: 2, num_columns: 3 })
+    /// );
     /// ```
     ///
     /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
@@ -742,7 +1278,7 @@ impl<T> Vecgrid<T> {
             .map(|location| {
                 *location = element;
             })
-            .ok_or(Error::IndicesOutOfBounds(row, column))
+            .ok_or_else(|| self.indices_out_of_bounds(row, column))
     }
 
     /// Changes the element at the given `index` to `element`, in row major
@@ -760,7 +1296,7 @@ impl<T> Vecgrid<T> {
     /// assert_eq!(vecgrid.get(1, 1), Some(&100));
     ///
     /// let result = vecgrid.set_row_major(10, 200);
-    /// assert_eq!(result, Err(Error::IndexOutOfBounds(10)));
+    /// assert_eq!(result, Err(Error::IndexOutOfBounds { index: 10, len: 6 }));
     /// ```
     ///
     /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
@@ -772,7 +1308,7 @@ impl<T> Vecgrid<T> {
             .map(|location| {
                 *location = element;
             })
-            .ok_or(Error::IndexOutOfBounds(index))
+            .ok_or_else(|| self.index_out_of_bounds(index))
     }
 
     /// Changes the element at the given `index` to `element`, in column major
@@ -790,7 +1326,7 @@ impl<T> Vecgrid<T> {
     /// assert_eq!(vecgrid.get(0, 2), Some(&100));
     ///
     /// let result = vecgrid.set_column_major(10, 200);
-    /// assert_eq!(result, Err(Error::IndexOutOfBounds(10)));
+    /// assert_eq!(result, Err(Error::IndexOutOfBounds { index: 10, len: 6 }));
     /// ```
     ///
     /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
@@ -802,7 +1338,70 @@ impl<T> Vecgrid<T> {
             .map(|location| {
                 *location = element;
             })
-            .ok_or(Error::IndexOutOfBounds(index))
+            .ok_or_else(|| self.index_out_of_bounds(index))
+    }
+
+    /// Changes the elements at the given `(row, column)` indices to the
+    /// accompanying elements. All indices are validated before any element is
+    /// changed, so either every update is applied and [`Ok(())`] is returned,
+    /// or the vecgrid is left untouched and an [`Err`] naming the first
+    /// out-of-bounds index is returned. Later updates to the same index
+    /// overwrite earlier ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let mut vecgrid = Vecgrid::filled_with(0, 2, 3);
+    ///
+    /// let result = vecgrid.apply_updates(vec![((0, 0), 1), ((1, 2), 2)]);
+    /// assert_eq!(result, Ok(()));
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 0, 0], vec![0, 0, 2]]);
+    ///
+    /// let result = vecgrid.apply_updates(vec![((0, 1), 3), ((5, 5), 4)]);
+    /// assert!(matches!(result, Err(Error::IndicesOutOfBounds { row: 5, column: 5, .. })));
+    /// assert_eq!(vecgrid.get(0, 1), Some(&0));
+    /// ```
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn apply_updates<I>(&mut self, updates: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = ((usize, usize), T)>,
+    {
+        let updates = updates
+            .into_iter()
+            .map(|((row, column), element)| {
+                self.get_index(row, column)
+                    .map(|index| (index, element))
+                    .ok_or_else(|| self.indices_out_of_bounds(row, column))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (index, element) in updates {
+            self.vecgrid[index] = element;
+        }
+        Ok(())
+    }
+
+    /// Changes every element to a clone of `element`. For primitive element
+    /// types this compiles down to a single `memset` or vectorized loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// vecgrid.fill(0);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![0, 0], vec![0, 0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fill(&mut self, element: T)
+    where
+        T: Clone,
+    {
+        self.vecgrid.fill(element);
     }
 
     /// Returns an [`Iterator`] over references to all elements in [row major
@@ -879,7 +1478,7 @@ impl<T> Vecgrid<T> {
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
     pub fn elements_column_major_iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
-        self.indices_column_major().map(move |i| &self[i])
+        (0..self.num_columns).flat_map(move |column_index| self.strided_column(column_index))
     }
 
     /// Returns an [`Iterator`] over mutable references to all elements in [column major
@@ -934,9 +1533,10 @@ impl<T> Vecgrid<T> {
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     pub fn row_iter(&self, row_index: usize) -> Result<impl DoubleEndedIterator<Item = &T>, Error> {
-        let start = self
-            .get_index(row_index, 0)
-            .ok_or(Error::IndicesOutOfBounds(row_index, 0))?;
+        if row_index >= self.num_rows {
+            return Err(self.indices_out_of_bounds(row_index, 0));
+        }
+        let start = row_index * self.row_len();
         let end = start + self.row_len();
         Ok(self.vecgrid[start..end].iter())
     }
@@ -965,9 +1565,10 @@ impl<T> Vecgrid<T> {
         &mut self,
         row_index: usize,
     ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
-        let start = self
-            .get_index(row_index, 0)
-            .ok_or(Error::IndicesOutOfBounds(row_index, 0))?;
+        if row_index >= self.num_rows {
+            return Err(self.indices_out_of_bounds(row_index, 0));
+        }
+        let start = row_index * self.row_len();
         let end = start + self.row_len();
         Ok(self.vecgrid[start..end].iter_mut())
     }
@@ -996,9 +1597,17 @@ impl<T> Vecgrid<T> {
         column_index: usize,
     ) -> Result<impl DoubleEndedIterator<Item = &T>, Error> {
         if column_index >= self.num_columns {
-            return Err(Error::IndicesOutOfBounds(0, column_index));
+            return Err(self.indices_out_of_bounds(0, column_index));
         }
-        Ok((0..self.column_len()).map(move |row_index| &self[(row_index, column_index)]))
+        Ok(self.strided_column(column_index))
+    }
+
+    fn strided_column(&self, column_index: usize) -> std::iter::StepBy<std::slice::Iter<'_, T>> {
+        self.vecgrid
+            .get(column_index..)
+            .unwrap_or_default()
+            .iter()
+            .step_by(self.num_columns.max(1))
     }
 
     /// Returns an [`Iterator`] over mutable references to all elements in the given
@@ -1025,7 +1634,7 @@ impl<T> Vecgrid<T> {
         column_index: usize,
     ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
         if column_index >= self.num_columns {
-            return Err(Error::IndicesOutOfBounds(0, column_index));
+            return Err(self.indices_out_of_bounds(0, column_index));
         }
         Ok(self
             .vecgrid
@@ -1282,90 +1891,413 @@ impl<T> Vecgrid<T> {
             .collect()
     }
 
-    /// Collects the [`Vecgrid`] into a [`Vec`] of elements in [row major
-    /// order].
+    /// Collects the elements of the given column, from top to bottom, into a
+    /// [`Vec`]. Returns an error if the index is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use vecgrid::{Vecgrid, Error};
     /// # fn main() -> Result<(), Error> {
-    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
-    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
-    /// assert_eq!(vecgrid.as_row_major(), vec![1, 2, 3, 4, 5, 6]);
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.column_to_vec(1)?, vec![2, 5]);
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// [`Vecgrid`]: struct.Vecgrid.html
     /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
-    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
-    pub fn as_row_major(&self) -> Vec<T>
+    pub fn column_to_vec(&self, column_index: usize) -> Result<Vec<T>, Error>
     where
         T: Clone,
     {
-        self.elements_row_major_iter().cloned().collect()
+        self.map_column(column_index, T::clone)
     }
 
-    /// Collects the [`Vecgrid`] into a [`Vec`] of elements in [column major
-    /// order].
+    /// Calls `f` on every element of the given column, from top to bottom,
+    /// and collects the results into a [`Vec`]. Returns an error if the
+    /// index is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use vecgrid::{Vecgrid, Error};
     /// # fn main() -> Result<(), Error> {
-    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
-    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
-    /// assert_eq!(vecgrid.as_column_major(), vec![1, 4, 2, 5, 3, 6]);
+    /// let people = Vecgrid::from_rows(vec![vec!["Ada", "36"], vec!["Alan", "41"]])?;
+    /// let ages = people.map_column(1, |age| age.parse::<u32>().unwrap())?;
+    /// assert_eq!(ages, vec![36, 41]);
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// [`Vecgrid`]: struct.Vecgrid.html
     /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
-    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
-    pub fn as_column_major(&self) -> Vec<T>
+    pub fn map_column<U, F>(&self, column_index: usize, f: F) -> Result<Vec<U>, Error>
     where
-        T: Clone,
+        F: FnMut(&T) -> U,
     {
-        self.elements_column_major_iter().cloned().collect()
+        Ok(self.column_iter(column_index)?.map(f).collect())
     }
 
-    /// Returns the indices of the vecgrid in row major order. Each index is a tuple of [`usize`].
+    /// Creates a new [`Vecgrid`] from clones of the rows in `range`. Returns
+    /// an error if the range is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use vecgrid::{Vecgrid, Error};
     /// # fn main() -> Result<(), Error> {
-    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
-    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
-    /// let indices_row_major = vecgrid.indices_row_major().collect::<Vec<_>>();
-    /// assert_eq!(
-    ///     indices_row_major,
-    ///     vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
-    /// );
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// assert_eq!(vecgrid.rows(1..)?.as_rows(), vec![vec![3, 4], vec![5, 6]]);
+    /// assert!(vecgrid.rows(2..4).is_err());
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
-    pub fn indices_row_major(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
-        indices_row_major(self.num_rows, self.num_columns)
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn rows<R>(&self, range: R) -> Result<Vecgrid<T>, Error>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let (start, end) = resolve_range(range, self.num_rows)?;
+        Ok(Vecgrid {
+            vecgrid: self.vecgrid[start * self.num_columns..end * self.num_columns].to_vec(),
+            num_rows: end - start,
+            num_columns: self.num_columns,
+        })
     }
 
-    /// Returns the indices of the vecgrid in column major order. Each index is a tuple of [`usize`].
+    /// Creates a new [`Vecgrid`] from clones of the columns in `range`.
+    /// Returns an error if the range is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use vecgrid::{Vecgrid, Error};
     /// # fn main() -> Result<(), Error> {
-    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
-    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
-    /// let indices_column_major = vecgrid.indices_column_major().collect::<Vec<_>>();
-    /// assert_eq!(
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.columns(..2)?.as_rows(), vec![vec![1, 2], vec![4, 5]]);
+    /// assert!(vecgrid.columns(1..=3).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn columns<R>(&self, range: R) -> Result<Vecgrid<T>, Error>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let (start, end) = resolve_range(range, self.num_columns)?;
+        let num_columns = end - start;
+        let mut vecgrid = Vec::with_capacity(self.num_rows * num_columns);
+        for row in 0..self.num_rows {
+            let offset = row * self.num_columns;
+            vecgrid.extend_from_slice(&self.vecgrid[offset + start..offset + end]);
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows: self.num_rows,
+            num_columns,
+        })
+    }
+
+    /// Collects the [`Vecgrid`] into a [`Vec`] of elements in [row major
+    /// order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
+    /// assert_eq!(vecgrid.as_row_major(), vec![1, 2, 3, 4, 5, 6]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_row_major(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.elements_row_major_iter().cloned().collect()
+    }
+
+    /// Collects the [`Vecgrid`] into a [`Vec`] of elements in [column major
+    /// order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
+    /// assert_eq!(vecgrid.as_column_major(), vec![1, 4, 2, 5, 3, 6]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_column_major(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.elements_column_major_iter().cloned().collect()
+    }
+
+    /// Converts the [`Vecgrid`] into a [`Vec`] of rows, each of which
+    /// contains a [`Vec`] of elements, moving the elements instead of
+    /// cloning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string(), "d".to_string()]];
+    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
+    /// assert_eq!(vecgrid.into_rows(), rows);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let num_columns = self.num_columns;
+        let mut elements = self.vecgrid.into_iter();
+        (0..self.num_rows)
+            .map(|_| elements.by_ref().take(num_columns).collect())
+            .collect()
+    }
+
+    /// Converts the [`Vecgrid`] into a [`Vec`] of columns, each of which
+    /// contains a [`Vec`] of elements, moving the elements instead of
+    /// cloning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let columns = vec![vec!["a".to_string(), "c".to_string()], vec!["b".to_string(), "d".to_string()]];
+    /// let vecgrid = Vecgrid::from_columns(columns.clone())?;
+    /// assert_eq!(vecgrid.into_columns(), columns);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn into_columns(self) -> Vec<Vec<T>> {
+        let mut columns = (0..self.num_columns)
+            .map(|_| Vec::with_capacity(self.num_rows))
+            .collect::<Vec<_>>();
+        for (index, element) in self.vecgrid.into_iter().enumerate() {
+            columns[index % self.num_columns].push(element);
+        }
+        columns
+    }
+
+    /// Converts the [`Vecgrid`] into a [`Vec`] of elements in [row major
+    /// order] without copying, as the elements are stored in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.into_row_major(), vec![1, 2, 3, 4, 5, 6]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn into_row_major(self) -> Vec<T> {
+        self.vecgrid
+    }
+
+    /// Converts the [`Vecgrid`] into a [`Vec`] of elements in [column major
+    /// order], reordering the elements in place instead of cloning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.into_column_major(), vec![1, 4, 2, 5, 3, 6]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn into_column_major(mut self) -> Vec<T> {
+        // The row major buffer of this vecgrid is the column major buffer of
+        // its transpose, so converting that to row major yields our column
+        // major order.
+        column_major_to_row_major(&mut self.vecgrid, self.num_columns, self.num_rows);
+        self.vecgrid
+    }
+
+    /// Returns all elements as one slice in [row major order], without
+    /// copying. The element at `(row, column)` is at index
+    /// `row * num_columns + column`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.as_row_major_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(vecgrid.as_row_major_slice()[1 * 3 + 2], vecgrid[(1, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_row_major_slice(&self) -> &[T] {
+        &self.vecgrid
+    }
+
+    /// Returns all elements as one mutable slice in [row major order],
+    /// without copying, for example to fill a pixel buffer in place. The
+    /// element at `(row, column)` is at index `row * num_columns + column`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(0, 2, 3);
+    /// vecgrid.as_mut_row_major_slice()[3..].copy_from_slice(&[4, 5, 6]);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![0, 0, 0], vec![4, 5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_mut_row_major_slice(&mut self) -> &mut [T] {
+        &mut self.vecgrid
+    }
+
+    /// Decomposes the [`Vecgrid`] into its elements in [row major order],
+    /// the number of rows and the number of columns, without copying, for
+    /// example to upload the buffer to a GPU or pass it across FFI. The
+    /// parts can be put back together with [`from_raw_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let (elements, num_rows, num_columns) = vecgrid.into_raw_parts();
+    /// assert_eq!((elements.as_slice(), num_rows, num_columns), (&[1, 2, 3, 4, 5, 6][..], 2, 3));
+    /// let vecgrid = Vecgrid::from_raw_parts(elements, num_rows, num_columns)?;
+    /// assert_eq!(vecgrid[(1, 0)], 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`from_raw_parts`]: struct.Vecgrid.html#method.from_raw_parts
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn into_raw_parts(self) -> (Vec<T>, usize, usize) {
+        (self.vecgrid, self.num_rows, self.num_columns)
+    }
+
+    /// Creates a [`Vecgrid`] from the parts returned by [`into_raw_parts`]:
+    /// the elements in [row major order], taken over without copying, and
+    /// the dimensions.
+    ///
+    /// Returns an error if the number of elements is not the product of
+    /// `num_rows` and `num_columns`.
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`into_raw_parts`]: struct.Vecgrid.html#method.into_raw_parts
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn from_raw_parts(
+        elements: Vec<T>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Result<Self, Error> {
+        Vecgrid::from_row_major(elements, num_rows, num_columns)
+    }
+
+    /// Returns a raw pointer to the first element of the buffer, which holds
+    /// all elements contiguously in [row major order].
+    ///
+    /// The pointer is valid for [`num_elements`] reads while the vecgrid is
+    /// neither modified nor dropped, see [`slice::as_ptr`].
+    ///
+    /// [`num_elements`]: struct.Vecgrid.html#method.num_elements
+    /// [`slice::as_ptr`]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_ptr
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_ptr(&self) -> *const T {
+        self.vecgrid.as_ptr()
+    }
+
+    /// Returns a raw mutable pointer to the first element of the buffer,
+    /// which holds all elements contiguously in [row major order].
+    ///
+    /// The pointer is valid for [`num_elements`] reads and writes while the
+    /// vecgrid is not otherwise accessed, resized or dropped, see
+    /// [`slice::as_mut_ptr`].
+    ///
+    /// [`num_elements`]: struct.Vecgrid.html#method.num_elements
+    /// [`slice::as_mut_ptr`]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_mut_ptr
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.vecgrid.as_mut_ptr()
+    }
+
+    /// Returns the indices of the vecgrid in row major order. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
+    /// let indices_row_major = vecgrid.indices_row_major().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     indices_row_major,
+    ///     vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn indices_row_major(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
+        indices_row_major(self.num_rows, self.num_columns)
+    }
+
+    /// Returns the indices of the vecgrid in column major order. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let vecgrid = Vecgrid::from_rows(rows.clone())?;
+    /// let indices_column_major = vecgrid.indices_column_major().collect::<Vec<_>>();
+    /// assert_eq!(
     ///     indices_column_major,
     ///     vec![(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]
     /// );
@@ -1405,7 +2337,11 @@ impl<T> Vecgrid<T> {
     pub fn enumerate_row_major(
         &self,
     ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
-        self.indices_row_major().map(move |i| (i, &self[i]))
+        self.indices_row_major().map(move |(row, column)| {
+            // SAFETY: the indices are generated from the dimensions of the
+            // vecgrid, which cannot change while it is borrowed.
+            ((row, column), unsafe { self.get_unchecked(row, column) })
+        })
     }
 
     /// Iterate through the vecgrid in column major order along with the corresponding indices. Each
@@ -1435,7 +2371,11 @@ impl<T> Vecgrid<T> {
     pub fn enumerate_column_major(
         &self,
     ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
-        self.indices_column_major().map(move |i| (i, &self[i]))
+        self.indices_column_major().map(move |(row, column)| {
+            // SAFETY: the indices are generated from the dimensions of the
+            // vecgrid, which cannot change while it is borrowed.
+            ((row, column), unsafe { self.get_unchecked(row, column) })
+        })
     }
 
     fn get_index(&self, row: usize, column: usize) -> Option<usize> {
@@ -1446,6 +2386,17 @@ impl<T> Vecgrid<T> {
         }
     }
 
+    pub(crate) fn indices_out_of_bounds(&self, row: usize, column: usize) -> Error {
+        Error::indices_out_of_bounds(row, column, self.num_rows, self.num_columns)
+    }
+
+    pub(crate) fn index_out_of_bounds(&self, index: usize) -> Error {
+        Error::IndexOutOfBounds {
+            index,
+            len: self.num_elements(),
+        }
+    }
+
     /// Inserts a new row into the vecgrid at the provided index of the row.
     /// Guards ensure that the supplied row matches the expected dimensions and that
     /// the index is in bound.
@@ -1462,14 +2413,19 @@ impl<T> Vecgrid<T> {
     /// # Ok(())
     /// # }
     ///
+    #[cfg_attr(feature = "tracing", instrument(level = "debug", skip_all, fields(at = at), err(Debug)))]
     pub fn insert_row(&mut self, row: Vec<T>, at: usize) -> Result<(), Error> {
         match (row.len() == self.num_columns, at < self.num_rows) {
-            (false, _) => Err(Error::DimensionMismatch),
-            (_, false) => Err(Error::IndexOutOfBounds(at)),
+            (false, _) => Err(Error::dimension_mismatch(self.num_columns, row.len())),
+            (_, false) => Err(Error::IndexOutOfBounds {
+                index: at,
+                len: self.num_rows,
+            }),
             (true, true) => {
                 let i = at * self.row_len();
                 self.vecgrid.splice(i..i, row);
                 self.num_rows += 1;
+                trace_reshape!(self, "inserted row");
                 Ok(())
             }
         }
@@ -1491,14 +2447,22 @@ impl<T> Vecgrid<T> {
     /// # Ok(())
     /// # }
     ///
-    pub fn insert_rows(&mut self, mut rows: Vec<Vec<T>>, at: usize) -> Result<(), Error> {
+    #[cfg_attr(feature = "tracing", instrument(level = "debug", skip_all, fields(at = at, n = rows.len()), err(Debug)))]
+    pub fn insert_rows(&mut self, rows: Vec<Vec<T>>, at: usize) -> Result<(), Error> {
         match (
-            rows.iter_mut().all(|r| r.len() == self.num_columns),
+            rows.iter().position(|r| r.len() != self.num_columns),
             at < self.num_rows + 1,
         ) {
-            (false, _) => Err(Error::DimensionMismatch),
-            (_, false) => Err(Error::IndexOutOfBounds(at)),
-            (true, true) => {
+            (Some(index), _) => Err(Error::DimensionMismatch {
+                expected: self.num_columns,
+                actual: rows[index].len(),
+                index: Some(index),
+            }),
+            (_, false) => Err(Error::IndexOutOfBounds {
+                index: at,
+                len: self.num_rows + 1,
+            }),
+            (None, true) => {
                 let i = at * self.row_len();
                 let capacity = self.num_columns * rows.len();
                 let num_new_rows = rows.len();
@@ -1506,6 +2470,7 @@ impl<T> Vecgrid<T> {
                 self.vecgrid
                     .splice(i..i, with_size_hint(rows.into_iter().flatten(), capacity));
                 self.num_rows += num_new_rows;
+                trace_reshape!(self, "inserted rows");
                 Ok(())
             }
         }
@@ -1527,10 +2492,14 @@ impl<T> Vecgrid<T> {
     /// # Ok(())
     /// # }
     ///
+    #[cfg_attr(feature = "tracing", instrument(level = "debug", skip_all, fields(at = at), err(Debug)))]
     pub fn insert_column(&mut self, mut column: Vec<T>, at: usize) -> Result<(), Error> {
         match (column.len() == self.num_rows, at < self.num_columns) {
-            (false, _) => Err(Error::DimensionMismatch),
-            (_, false) => Err(Error::IndexOutOfBounds(at)),
+            (false, _) => Err(Error::dimension_mismatch(self.num_rows, column.len())),
+            (_, false) => Err(Error::IndexOutOfBounds {
+                index: at,
+                len: self.num_columns,
+            }),
             (true, true) => {
                 self.vecgrid.reserve(column.len());
                 let new_size = column.len() + self.num_elements();
@@ -1559,6 +2528,7 @@ impl<T> Vecgrid<T> {
                 }
 
                 self.num_columns += 1;
+                trace_reshape!(self, "inserted column");
                 Ok(())
             }
         }
@@ -1615,58 +2585,451 @@ impl<T> Vecgrid<T> {
     /// # Ok(())
     /// # }
     ///
+    #[cfg_attr(feature = "tracing", instrument(level = "debug", skip_all, fields(at = at, n = n), err(Debug)))]
     pub fn remove_rows(&mut self, at: usize, n: usize) -> Result<(), Error> {
-        if at + n > self.num_rows + 1 {
-            return Err(Error::IndicesOutOfBounds(at, at + n));
-        }
+        self.check_row_range(at, n)?;
         let start = self.row_len() * at;
         let end = start + n * self.row_len();
         self.vecgrid.drain(start..end);
         self.num_rows -= n;
+        trace_reshape!(self, "removed rows");
         Ok(())
     }
-}
-
-impl<T> Index<(usize, usize)> for Vecgrid<T> {
-    type Output = T;
 
-    /// Returns the element at the given indices, given as `(row, column)`.
+    /// Removes the row at the provided row index from the vecgrid and
+    /// returns its elements, moving them out instead of dropping them.
+    /// Returns an error if the index is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use vecgrid::{Vecgrid, Error};
-    /// let vecgrid = Vecgrid::filled_with(42, 2, 3);
-    /// assert_eq!(vecgrid[(0, 0)], 42);
-    /// ```
-    ///
-    /// # Panics
-    ///
-    /// Panics if the indices are out of bounds.
-    ///
-    /// ```rust,should_panic
-    /// # use vecgrid::Vecgrid;
-    /// let vecgrid = Vecgrid::filled_with(42, 2, 3);
-    /// let element = vecgrid[(10, 10)];
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// assert_eq!(vecgrid.take_row(1)?, vec![3, 4]);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![5, 6]]);
+    /// # Ok(())
+    /// # }
     /// ```
-    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
-        self.get(row, column)
-            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    pub fn take_row(&mut self, at: usize) -> Result<Vec<T>, Error> {
+        let mut rows = self.take_rows(at, 1)?;
+        Ok(rows.pop().unwrap_or_default())
     }
-}
 
-impl<T> IndexMut<(usize, usize)> for Vecgrid<T> {
-    /// Returns a mutable version of the element at the given indices, given as
-    /// `(row, column)`.
+    /// Removes `n` consecutive rows at the provided row index from the
+    /// vecgrid and returns them, moving the elements out instead of
+    /// dropping them. Returns an error if the rows are out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use vecgrid::{Vecgrid, Error};
-    /// let mut vecgrid = Vecgrid::filled_with(42, 2, 3);
-    /// vecgrid[(0, 0)] = 100;
-    /// assert_eq!(vecgrid[(0, 0)], 100);
-    /// ```
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// assert_eq!(vecgrid.take_rows(0, 2)?, vec![vec![1, 2], vec![3, 4]]);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", instrument(level = "debug", skip_all, fields(at = at, n = n), err(Debug)))]
+    pub fn take_rows(&mut self, at: usize, n: usize) -> Result<Vec<Vec<T>>, Error> {
+        self.check_row_range(at, n)?;
+        let row_len = self.row_len();
+        let start = row_len * at;
+        let mut drained = self.vecgrid.drain(start..start + n * row_len);
+        let rows = (0..n)
+            .map(|_| drained.by_ref().take(row_len).collect())
+            .collect();
+        drop(drained);
+        self.num_rows -= n;
+        trace_reshape!(self, "took rows");
+        Ok(rows)
+    }
+
+    /// Replaces the rows in `range` with `replacement`, which may contain a
+    /// different number of rows, like [`Vec::splice`]. The elements after
+    /// the range are moved only once. Returns an error, without changing the
+    /// vecgrid, if the range is out of bounds or a replacement row does not
+    /// have the same length as the rows of the vecgrid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// vecgrid.splice_rows(1..2, vec![vec![7, 7], vec![8, 8], vec![9, 9]])?;
+    /// assert_eq!(
+    ///     vecgrid.as_rows(),
+    ///     vec![vec![1, 2], vec![7, 7], vec![8, 8], vec![9, 9], vec![5, 6]]
+    /// );
+    /// vecgrid.splice_rows(..4, vec![])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vec::splice`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.splice
+    #[cfg_attr(feature = "tracing", instrument(level = "debug", skip_all, fields(replacement = replacement.len()), err(Debug)))]
+    pub fn splice_rows<R>(&mut self, range: R, replacement: Vec<Vec<T>>) -> Result<(), Error>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(range, self.num_rows)?;
+        if let Some(index) = replacement
+            .iter()
+            .position(|row| row.len() != self.num_columns)
+        {
+            return Err(Error::DimensionMismatch {
+                expected: self.num_columns,
+                actual: replacement[index].len(),
+                index: Some(index),
+            });
+        }
+        let row_len = self.row_len();
+        let num_replaced = replacement.len();
+        // Collecting first gives `splice` an exact size, so it moves the tail
+        // only once.
+        let elements = replacement.into_iter().flatten().collect::<Vec<_>>();
+        self.vecgrid
+            .splice(start * row_len..end * row_len, elements);
+        self.num_rows = self.num_rows - (end - start) + num_replaced;
+        trace_reshape!(self, "spliced rows");
+        Ok(())
+    }
+
+    /// Overwrites the row at the provided row index with `row` and returns
+    /// the previous elements of that row. Returns an error, without changing
+    /// the vecgrid, if `row` does not have the same length as the rows of the
+    /// vecgrid or the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// assert_eq!(vecgrid.replace_row(1, vec![5, 6])?, vec![3, 4]);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn replace_row(&mut self, index: usize, row: Vec<T>) -> Result<Vec<T>, Error> {
+        if row.len() != self.num_columns {
+            return Err(Error::dimension_mismatch(self.num_columns, row.len()));
+        }
+        if index >= self.num_rows {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.num_rows,
+            });
+        }
+        let start = index * self.row_len();
+        Ok(self
+            .vecgrid
+            .splice(start..start + self.num_columns, row)
+            .collect())
+    }
+
+    /// Overwrites the column at the provided column index with `column` and
+    /// returns the previous elements of that column. Returns an error,
+    /// without changing the vecgrid, if `column` does not have the same
+    /// length as the columns of the vecgrid or the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// assert_eq!(vecgrid.replace_column(0, vec![5, 6])?, vec![1, 3]);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![5, 2], vec![6, 4]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn replace_column(&mut self, index: usize, column: Vec<T>) -> Result<Vec<T>, Error> {
+        if column.len() != self.num_rows {
+            return Err(Error::dimension_mismatch(self.num_rows, column.len()));
+        }
+        if index >= self.num_columns {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.num_columns,
+            });
+        }
+        let num_columns = self.num_columns;
+        Ok(column
+            .into_iter()
+            .enumerate()
+            .map(|(row, element)| {
+                std::mem::replace(&mut self.vecgrid[row * num_columns + index], element)
+            })
+            .collect())
+    }
+
+    /// Copies `source` into the row at the provided row index with a single
+    /// [`copy_from_slice`]. Returns an error if `source` does not have the
+    /// same length as the rows of the vecgrid or the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut image = Vecgrid::filled_with(0u8, 2, 3);
+    /// image.copy_row_from_slice(1, &[7, 8, 9])?;
+    /// assert_eq!(image.as_rows(), vec![vec![0, 0, 0], vec![7, 8, 9]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`copy_from_slice`]: https://doc.rust-lang.org/std/primitive.slice.html#method.copy_from_slice
+    pub fn copy_row_from_slice(&mut self, index: usize, source: &[T]) -> Result<(), Error>
+    where
+        T: Copy,
+    {
+        if source.len() != self.num_columns {
+            return Err(Error::dimension_mismatch(self.num_columns, source.len()));
+        }
+        if index >= self.num_rows {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.num_rows,
+            });
+        }
+        let start = index * self.row_len();
+        self.vecgrid[start..start + self.num_columns].copy_from_slice(source);
+        Ok(())
+    }
+
+    /// Copies `source` into the column at the provided column index, stepping
+    /// through the buffer one row at a time. Returns an error if `source`
+    /// does not have the same length as the columns of the vecgrid or the
+    /// index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut image = Vecgrid::filled_with(0u8, 2, 3);
+    /// image.copy_column_from_slice(2, &[7, 8])?;
+    /// assert_eq!(image.as_rows(), vec![vec![0, 0, 7], vec![0, 0, 8]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_column_from_slice(&mut self, index: usize, source: &[T]) -> Result<(), Error>
+    where
+        T: Copy,
+    {
+        if source.len() != self.num_rows {
+            return Err(Error::dimension_mismatch(self.num_rows, source.len()));
+        }
+        if index >= self.num_columns {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.num_columns,
+            });
+        }
+        let column = self.vecgrid[index..].iter_mut().step_by(self.num_columns);
+        for (element, &value) in column.zip(source) {
+            *element = value;
+        }
+        Ok(())
+    }
+
+    fn check_row_range(&self, at: usize, n: usize) -> Result<(), Error> {
+        if at.checked_add(n).is_none_or(|end| end > self.num_rows) {
+            return Err(Error::IndexOutOfBounds {
+                index: at.saturating_add(n),
+                len: self.num_rows + 1,
+            });
+        }
+        Ok(())
+    }
+
+    /// Creates a new [`Vecgrid`] with the columns of `other` placed to the
+    /// right of the columns of `self`.
+    /// Guards ensure that both vecgrids have the same number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let left = Vecgrid::from_rows(vec![vec![1, 2], vec![5, 6]])?;
+    /// let right = Vecgrid::from_rows(vec![vec![3], vec![7]])?;
+    /// let vecgrid = left.concat_horizontal(&right)?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![5, 6, 7]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn concat_horizontal(&self, other: &Vecgrid<T>) -> Result<Vecgrid<T>, Error>
+    where
+        T: Clone,
+    {
+        stack_horizontal([self, other])
+    }
+
+    /// Creates a new [`Vecgrid`] with the rows of `other` placed below the
+    /// rows of `self`.
+    /// Guards ensure that both vecgrids have the same number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let top = Vecgrid::from_rows(vec![vec![1, 2]])?;
+    /// let bottom = Vecgrid::from_rows(vec![vec![3, 4], vec![5, 6]])?;
+    /// let vecgrid = top.concat_vertical(&bottom)?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn concat_vertical(&self, other: &Vecgrid<T>) -> Result<Vecgrid<T>, Error>
+    where
+        T: Clone,
+    {
+        stack_vertical([self, other])
+    }
+
+    /// Creates a new [`Vecgrid`] by placing the given vecgrids side by side,
+    /// from left to right.
+    /// Guards ensure that all vecgrids have the same number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let room = Vecgrid::from_rows(vec![vec![1], vec![2]])?;
+    /// let vecgrid = Vecgrid::hstack(&[room.clone(), room.clone(), room])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 1, 1], vec![2, 2, 2]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn hstack(vecgrids: &[Vecgrid<T>]) -> Result<Vecgrid<T>, Error>
+    where
+        T: Clone,
+    {
+        stack_horizontal(vecgrids)
+    }
+
+    /// Creates a new [`Vecgrid`] by placing the given vecgrids on top of each
+    /// other, from top to bottom.
+    /// Guards ensure that all vecgrids have the same number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let room = Vecgrid::from_rows(vec![vec![1, 2]])?;
+    /// let vecgrid = Vecgrid::vstack(&[room.clone(), room])?;
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![1, 2]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn vstack(vecgrids: &[Vecgrid<T>]) -> Result<Vecgrid<T>, Error>
+    where
+        T: Clone,
+    {
+        stack_vertical(vecgrids)
+    }
+
+    /// Creates a new [`Vecgrid`] by repeating `self` `vertical` times
+    /// downwards and `horizontal` times to the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let tile = Vecgrid::from_rows(vec![vec![1, 2]])?;
+    /// let vecgrid = tile.tiled(2, 2);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 1, 2], vec![1, 2, 1, 2]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn tiled(&self, vertical: usize, horizontal: usize) -> Vecgrid<T>
+    where
+        T: Clone,
+    {
+        let num_rows = self.num_rows * vertical;
+        let num_columns = self.num_columns * horizontal;
+        let mut vecgrid = Vec::with_capacity(num_rows * num_columns);
+        for _ in 0..vertical {
+            for row in self.vecgrid.chunks(self.num_columns.max(1)) {
+                for _ in 0..horizontal {
+                    vecgrid.extend_from_slice(row);
+                }
+            }
+        }
+        Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        }
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for Vecgrid<T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)`,
+    /// `[row, column]` or anything else that converts into a [`GridIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// assert_eq!(vecgrid[(0, 0)], 42);
+    /// assert_eq!(vecgrid[[1, 2]], 42);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// ```rust,should_panic
+    /// # use vecgrid::Vecgrid;
+    /// let vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// let element = vecgrid[(10, 10)];
+    /// ```
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for Vecgrid<T> {
+    /// Returns a mutable version of the element at the given indices, given as
+    /// `(row, column)`, `[row, column]` or anything else that converts into a
+    /// [`GridIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let mut vecgrid = Vecgrid::filled_with(42, 2, 3);
+    /// vecgrid[(0, 0)] = 100;
+    /// assert_eq!(vecgrid[(0, 0)], 100);
+    /// ```
     ///
     /// # Panics
     ///
@@ -1677,12 +3040,189 @@ impl<T> IndexMut<(usize, usize)> for Vecgrid<T> {
     /// let mut vecgrid = Vecgrid::filled_with(42, 2, 3);
     /// vecgrid[(10, 10)] = 7;
     /// ```
-    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
         self.get_mut(row, column)
             .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
     }
 }
 
+/// Compares a [`Vecgrid`] with rows given as a [`Vec`] of [`Vec`]s, without
+/// cloning any element.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+/// assert_eq!(vecgrid, vec![vec![1, 2], vec![3, 4]]);
+/// assert_ne!(vecgrid, vec![vec![1, 2, 3, 4]]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+impl<T, U> PartialEq<Vec<Vec<U>>> for Vecgrid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<Vec<U>>) -> bool {
+        self.eq_rows(other.iter().map(Vec::as_slice), other.len())
+    }
+}
+
+/// Compares a [`Vecgrid`] with rows given as a slice of [`Vec`]s.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+impl<T, U> PartialEq<[Vec<U>]> for Vecgrid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[Vec<U>]) -> bool {
+        self.eq_rows(other.iter().map(Vec::as_slice), other.len())
+    }
+}
+
+/// Compares a [`Vecgrid`] with rows given as a slice of slices.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+/// let rows: &[&[i32]] = &[&[1, 2], &[3, 4]];
+/// assert!(vecgrid == *rows);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+impl<T, U> PartialEq<[&[U]]> for Vecgrid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[&[U]]) -> bool {
+        self.eq_rows(other.iter().copied(), other.len())
+    }
+}
+
+/// Compares a [`Vecgrid`] with rows given as a two-dimensional array.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+/// assert_eq!(vecgrid, [[1, 2], [3, 4]]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+impl<T, U, const R: usize, const C: usize> PartialEq<[[U; C]; R]> for Vecgrid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[[U; C]; R]) -> bool {
+        self.eq_rows(other.iter().map(|row| row.as_slice()), R)
+    }
+}
+
+/// Creates a [`Vecgrid`] by cloning rows given as a slice of slices, see
+/// [`from_row_slices`].
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let rows: &[&[i32]] = &[&[1, 2], &[3, 4]];
+/// let vecgrid = Vecgrid::try_from(rows)?;
+/// assert_eq!(vecgrid, [[1, 2], [3, 4]]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`from_row_slices`]: struct.Vecgrid.html#method.from_row_slices
+impl<T: Clone> TryFrom<&[&[T]]> for Vecgrid<T> {
+    type Error = Error;
+
+    fn try_from(rows: &[&[T]]) -> Result<Self, Self::Error> {
+        Vecgrid::from_row_slices(rows)
+    }
+}
+
+/// Creates a [`Vecgrid`] from rows given as an array of arrays, which all
+/// have the same length by construction. This is what the [`vecgrid!`]
+/// macro expands to.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::Vecgrid;
+/// let vecgrid = Vecgrid::from([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(vecgrid.num_rows(), 2);
+/// assert_eq!(vecgrid[(1, 2)], 6);
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`vecgrid!`]: macro.vecgrid.html
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Vecgrid<T> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        Vecgrid {
+            vecgrid: rows.into_iter().flatten().collect(),
+            num_rows: R,
+            num_columns: C,
+        }
+    }
+}
+
+/// Compares rows given as a [`Vec`] of [`Vec`]s with a [`Vecgrid`].
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+impl<T, U> PartialEq<Vecgrid<U>> for Vec<Vec<T>>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vecgrid<U>) -> bool {
+        if self.len() != other.num_rows {
+            return false;
+        }
+        if other.num_columns == 0 {
+            return self.iter().all(Vec::is_empty);
+        }
+        self.iter()
+            .zip(other.vecgrid.chunks(other.num_columns))
+            .all(|(row, other_row)| row.len() == other.num_columns && row == other_row)
+    }
+}
+
+impl<T> Vecgrid<T> {
+    fn eq_rows<'a, U: 'a, I>(&self, mut rows: I, num_rows: usize) -> bool
+    where
+        T: PartialEq<U>,
+        I: Iterator<Item = &'a [U]>,
+    {
+        if num_rows != self.num_rows {
+            return false;
+        }
+        if self.num_columns == 0 {
+            return rows.all(|row| row.is_empty());
+        }
+        rows.zip(self.vecgrid.chunks(self.num_columns))
+            .all(|(row, own_row)| row.len() == self.num_columns && own_row == row)
+    }
+}
+
 struct SizeHint<I: Iterator> {
     inner: I,
     size_hint: usize,
@@ -1703,6 +3243,84 @@ fn with_size_hint<I: Iterator>(inner: I, size_hint: usize) -> SizeHint<I> {
     SizeHint { inner, size_hint }
 }
 
+/// Collects exactly `total_len` elements from `iterator`. Fails without
+/// consuming or allocating anything if the upper bound of the iterator's
+/// size hint, which is exact for an [`ExactSizeIterator`], is already too
+/// short.
+fn take_elements<T, I>(iterator: I, total_len: usize) -> Result<Vec<T>, Error>
+where
+    I: Iterator<Item = T>,
+{
+    if let (_, Some(upper)) = iterator.size_hint() {
+        if upper < total_len {
+            return Err(Error::NotEnoughElements {
+                expected: total_len,
+                actual: upper,
+            });
+        }
+    }
+    let elements = iterator.take(total_len).collect::<Vec<_>>();
+    if elements.len() != total_len {
+        return Err(Error::NotEnoughElements {
+            expected: total_len,
+            actual: elements.len(),
+        });
+    }
+    Ok(elements)
+}
+
+/// Turns `range` into a start and an end within `0..=len`. Returns an
+/// [`Error::IndexOutOfBounds`] for the end if it is past `len`, or for the
+/// start if it is past the end.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Result<(usize, usize), Error> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        return Err(Error::IndexOutOfBounds {
+            index: end,
+            len: len + 1,
+        });
+    }
+    if start > end {
+        return Err(Error::IndexOutOfBounds {
+            index: start,
+            len: end + 1,
+        });
+    }
+    Ok((start, end))
+}
+
+/// Moves the elements of a buffer in column major order into row major
+/// order in place, by following every cycle of the permutation with swaps.
+fn column_major_to_row_major<T>(elements: &mut [T], num_rows: usize, num_columns: usize) {
+    let source = |index: usize| (index % num_columns) * num_rows + index / num_columns;
+    let mut visited = vec![false; elements.len()];
+    for start in 0..elements.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut current = start;
+        loop {
+            let next = source(current);
+            if next == start {
+                break;
+            }
+            elements.swap(current, next);
+            visited[next] = true;
+            current = next;
+        }
+    }
+}
+
 fn indices_row_major(
     num_rows: usize,
     num_columns: usize,
@@ -1716,3 +3334,68 @@ fn indices_column_major(
 ) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
     (0..num_columns).flat_map(move |column| (0..num_rows).map(move |row| (row, column)))
 }
+
+fn stack_horizontal<'a, T, I>(vecgrids: I) -> Result<Vecgrid<T>, Error>
+where
+    T: Clone + 'a,
+    I: IntoIterator<Item = &'a Vecgrid<T>>,
+    I::IntoIter: Clone,
+{
+    let vecgrids = vecgrids.into_iter();
+    let num_rows = vecgrids.clone().next().map_or(0, Vecgrid::num_rows);
+    if let Some((index, vecgrid)) = vecgrids
+        .clone()
+        .enumerate()
+        .find(|(_, vecgrid)| vecgrid.num_rows != num_rows)
+    {
+        return Err(Error::DimensionMismatch {
+            expected: num_rows,
+            actual: vecgrid.num_rows,
+            index: Some(index),
+        });
+    }
+    let num_columns = vecgrids.clone().map(Vecgrid::num_columns).sum();
+    let mut vecgrid = Vec::with_capacity(num_rows * num_columns);
+    for row in 0..num_rows {
+        for part in vecgrids.clone() {
+            let start = row * part.num_columns;
+            vecgrid.extend_from_slice(&part.vecgrid[start..start + part.num_columns]);
+        }
+    }
+    Ok(Vecgrid {
+        vecgrid,
+        num_rows,
+        num_columns,
+    })
+}
+
+fn stack_vertical<'a, T, I>(vecgrids: I) -> Result<Vecgrid<T>, Error>
+where
+    T: Clone + 'a,
+    I: IntoIterator<Item = &'a Vecgrid<T>>,
+    I::IntoIter: Clone,
+{
+    let vecgrids = vecgrids.into_iter();
+    let num_columns = vecgrids.clone().next().map_or(0, Vecgrid::num_columns);
+    if let Some((index, vecgrid)) = vecgrids
+        .clone()
+        .enumerate()
+        .find(|(_, vecgrid)| vecgrid.num_columns != num_columns)
+    {
+        return Err(Error::DimensionMismatch {
+            expected: num_columns,
+            actual: vecgrid.num_columns,
+            index: Some(index),
+        });
+    }
+    let num_rows = vecgrids.clone().map(Vecgrid::num_rows).sum();
+    let mut vecgrid = Vec::with_capacity(num_rows * num_columns);
+    for part in vecgrids {
+        vecgrid.extend_from_slice(&part.vecgrid);
+    }
+    Ok(Vecgrid {
+        vecgrid,
+        num_rows,
+        num_columns,
+    })
+}
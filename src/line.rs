@@ -0,0 +1,129 @@
+//! Straight lines between the cells of a [`Vecgrid`].
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Returns an [`Iterator`] over the indices, as `(row, column)`, of the
+    /// cells on the straight line from `from` to `to`, both included, using
+    /// [Bresenham's line algorithm]. Returns an error if either end is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::filled_with(0, 3, 5);
+    /// let line = vecgrid.line_iter((0, 0), (2, 4))?.collect::<Vec<_>>();
+    /// assert_eq!(line, vec![(0, 0), (1, 1), (1, 2), (2, 3), (2, 4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [Bresenham's line algorithm]: https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm
+    pub fn line_iter(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) -> Result<impl Iterator<Item = (usize, usize)>, Error> {
+        for &(row, column) in &[from, to] {
+            self.get_index(row, column)
+                .ok_or_else(|| self.indices_out_of_bounds(row, column))?;
+        }
+        Ok(Bresenham::new(from, to))
+    }
+
+    /// Returns `true` if no cell strictly between `from` and `to` on the
+    /// line returned by [`line_iter`] `blocks` the view. The end cells
+    /// themselves are never tested, so a wall can see and be seen. Returns
+    /// an error if either end is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['.', '.', '.'],
+    ///     vec!['.', '#', '.'],
+    ///     vec!['.', '.', '.'],
+    /// ])?;
+    /// let wall = |cell: &char| *cell == '#';
+    /// assert!(map.has_line_of_sight((0, 0), (0, 2), wall)?);
+    /// assert!(!map.has_line_of_sight((0, 0), (2, 2), wall)?);
+    /// assert!(map.has_line_of_sight((0, 0), (1, 1), wall)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`line_iter`]: struct.Vecgrid.html#method.line_iter
+    pub fn has_line_of_sight<F>(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        blocks: F,
+    ) -> Result<bool, Error>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let visible = self
+            .line_iter(from, to)?
+            .filter(|&cell| cell != from && cell != to)
+            .all(|(row, column)| !blocks(&self[(row, column)]));
+        Ok(visible)
+    }
+}
+
+struct Bresenham {
+    current: (isize, isize),
+    end: (isize, isize),
+    delta: (isize, isize),
+    step: (isize, isize),
+    error: isize,
+    done: bool,
+}
+
+impl Bresenham {
+    fn new(from: (usize, usize), to: (usize, usize)) -> Self {
+        let current = (from.0 as isize, from.1 as isize);
+        let end = (to.0 as isize, to.1 as isize);
+        let delta = ((end.0 - current.0).abs(), -(end.1 - current.1).abs());
+        let step = ((end.0 - current.0).signum(), (end.1 - current.1).signum());
+        Bresenham {
+            current,
+            end,
+            delta,
+            step,
+            error: delta.0 + delta.1,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let cell = (self.current.0 as usize, self.current.1 as usize);
+        if self.current == self.end {
+            self.done = true;
+            return Some(cell);
+        }
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.1 {
+            self.error += self.delta.1;
+            self.current.0 += self.step.0;
+        }
+        if doubled <= self.delta.0 {
+            self.error += self.delta.0;
+            self.current.1 += self.step.1;
+        }
+        Some(cell)
+    }
+}
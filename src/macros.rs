@@ -0,0 +1,48 @@
+//! The [`vecgrid!`] macro for writing grids as literal rows.
+//!
+//! [`vecgrid!`]: ../macro.vecgrid.html
+
+/// Creates a [`Vecgrid`] from literal rows, like [`vec!`] does for a
+/// [`Vec`].
+///
+/// - `vecgrid![[a, b], [c, d]]` creates a vecgrid with the given rows. The
+///   rows are written as a nested array, so rows of different lengths are
+///   rejected at compile time.
+/// - `vecgrid![element; num_rows, num_columns]` creates a vecgrid that
+///   contains a clone of `element` in every location, see [`filled_with`].
+/// - `vecgrid![]` creates an empty vecgrid.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::vecgrid;
+/// let vecgrid = vecgrid![[1, 2, 3], [4, 5, 6]];
+/// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+///
+/// let zeros = vecgrid![0; 2, 3];
+/// assert_eq!(zeros.num_elements(), 6);
+/// ```
+///
+/// Rows of different lengths do not compile:
+///
+/// ```compile_fail
+/// # use vecgrid::vecgrid;
+/// let vecgrid = vecgrid![[1, 2, 3], [4, 5]];
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`filled_with`]: struct.Vecgrid.html#method.filled_with
+/// [`vec!`]: https://doc.rust-lang.org/std/macro.vec.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+#[macro_export]
+macro_rules! vecgrid {
+    () => {
+        $crate::Vecgrid::from([[]; 0])
+    };
+    ($element:expr; $num_rows:expr, $num_columns:expr) => {
+        $crate::Vecgrid::filled_with($element, $num_rows, $num_columns)
+    };
+    ($([$($element:expr),* $(,)?]),+ $(,)?) => {
+        $crate::Vecgrid::from([$([$($element),*]),+])
+    };
+}
@@ -0,0 +1,238 @@
+//! Boolean masks over a [`Vecgrid`]: a `Vecgrid<bool>` of the same
+//! dimensions that selects the elements to read or update.
+//!
+//! Masks combine with the `&`, `|` and `^` operators, which return an error
+//! if the dimensions do not match, and invert with `!`:
+//!
+//! ```
+//! # use vecgrid::{Vecgrid, Error};
+//! # fn main() -> Result<(), Error> {
+//! let visible = Vecgrid::from_rows(vec![vec![true, true], vec![false, true]])?;
+//! let walls = Vecgrid::from_rows(vec![vec![false, true], vec![true, false]])?;
+//! let visible_floor = (&visible & &!&walls)?;
+//! assert_eq!(visible_floor.as_rows(), vec![vec![true, false], vec![false, true]]);
+//! assert!((&visible | &walls)?.all());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+impl<T> Vecgrid<T> {
+    /// Creates a mask with the same dimensions as the vecgrid that is `true`
+    /// wherever `predicate` returns `true` for the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let image = Vecgrid::from_rows(vec![vec![10, 200], vec![130, 40]])?;
+    /// let bright = image.mask_from(|&pixel| pixel > 127);
+    /// assert_eq!(bright.as_rows(), vec![vec![false, true], vec![true, false]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mask_from<F>(&self, predicate: F) -> Vecgrid<bool>
+    where
+        F: Fn(&T) -> bool,
+    {
+        Vecgrid {
+            vecgrid: self.vecgrid.iter().map(predicate).collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Creates a mask with the same dimensions as the vecgrid that is `true`
+    /// wherever the element is greater than `threshold`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let image = Vecgrid::from_rows(vec![vec![10, 200], vec![127, 128]])?;
+    /// let bright = image.threshold(127);
+    /// assert_eq!(bright.as_rows(), vec![vec![false, true], vec![false, true]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn threshold(&self, threshold: T) -> Vecgrid<bool>
+    where
+        T: PartialOrd,
+    {
+        self.mask_from(|element| *element > threshold)
+    }
+
+    /// Returns an [`Iterator`] over references to the elements, in [row major
+    /// order], at the locations where `mask` is `true`. Returns an error if
+    /// the dimensions of `mask` do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// let mask = Vecgrid::from_rows(vec![vec![true, false], vec![false, true]])?;
+    /// assert_eq!(vecgrid.select(&mask)?.copied().collect::<Vec<_>>(), vec![1, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn select<'a>(
+        &'a self,
+        mask: &'a Vecgrid<bool>,
+    ) -> Result<impl DoubleEndedIterator<Item = &'a T>, Error> {
+        self.check_mask(mask)?;
+        Ok(self
+            .vecgrid
+            .iter()
+            .zip(&mask.vecgrid)
+            .filter_map(|(element, &selected)| selected.then_some(element)))
+    }
+
+    /// Sets every element at a location where `mask` is `true` to a clone of
+    /// `value`. Returns an error, without changing any element, if the
+    /// dimensions of `mask` do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut image = Vecgrid::from_rows(vec![vec![10, 200], vec![130, 40]])?;
+    /// let bright = image.mask_from(|&pixel| pixel > 127);
+    /// image.set_where(&bright, 255)?;
+    /// assert_eq!(image.as_rows(), vec![vec![10, 255], vec![255, 40]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_where(&mut self, mask: &Vecgrid<bool>, value: T) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        self.check_mask(mask)?;
+        for (element, &selected) in self.vecgrid.iter_mut().zip(&mask.vecgrid) {
+            if selected {
+                *element = value.clone();
+            }
+        }
+        Ok(())
+    }
+
+    fn check_mask(&self, mask: &Vecgrid<bool>) -> Result<(), Error> {
+        if (self.num_rows, self.num_columns) != (mask.num_rows, mask.num_columns) {
+            return Err(Error::shape_mismatch(
+                (self.num_rows, self.num_columns),
+                (mask.num_rows, mask.num_columns),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Vecgrid<bool> {
+    /// Returns `true` if any element is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mask = Vecgrid::from_rows(vec![vec![false, true], vec![false, false]])?;
+    /// assert!(mask.any());
+    /// assert!(!Vecgrid::filled_with(false, 2, 2).any());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn any(&self) -> bool {
+        self.vecgrid.iter().any(|&selected| selected)
+    }
+
+    /// Returns `true` if every element is `true`, including when there are
+    /// no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mask = Vecgrid::from_rows(vec![vec![false, true], vec![true, true]])?;
+    /// assert!(!mask.all());
+    /// assert!(Vecgrid::filled_with(true, 2, 2).all());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn all(&self) -> bool {
+        self.vecgrid.iter().all(|&selected| selected)
+    }
+
+    fn combine(mut self, other: &Vecgrid<bool>, f: fn(bool, bool) -> bool) -> Result<Self, Error> {
+        self.check_mask(other)?;
+        for (element, &other) in self.vecgrid.iter_mut().zip(&other.vecgrid) {
+            *element = f(*element, other);
+        }
+        Ok(self)
+    }
+}
+
+macro_rules! impl_mask_op {
+    ($($trait:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl $trait<&Vecgrid<bool>> for Vecgrid<bool> {
+                type Output = Result<Vecgrid<bool>, Error>;
+
+                #[doc = concat!(
+                    "Combines the masks element by element with `", stringify!($op), "`, ",
+                    "reusing the allocation of the left mask. Returns an error if the ",
+                    "dimensions do not match."
+                )]
+                fn $method(self, other: &Vecgrid<bool>) -> Self::Output {
+                    self.combine(other, |a, b| a $op b)
+                }
+            }
+
+            impl $trait<&Vecgrid<bool>> for &Vecgrid<bool> {
+                type Output = Result<Vecgrid<bool>, Error>;
+
+                #[doc = concat!(
+                    "Combines the masks element by element with `", stringify!($op), "` ",
+                    "into a new mask. Returns an error if the dimensions do not match."
+                )]
+                fn $method(self, other: &Vecgrid<bool>) -> Self::Output {
+                    self.clone().combine(other, |a, b| a $op b)
+                }
+            }
+        )*
+    };
+}
+
+impl_mask_op!(BitAnd, bitand, &; BitOr, bitor, |; BitXor, bitxor, ^);
+
+impl Not for Vecgrid<bool> {
+    type Output = Vecgrid<bool>;
+
+    /// Inverts every element of the mask in place.
+    fn not(mut self) -> Self::Output {
+        for element in &mut self.vecgrid {
+            *element = !*element;
+        }
+        self
+    }
+}
+
+impl Not for &Vecgrid<bool> {
+    type Output = Vecgrid<bool>;
+
+    /// Returns a new mask with every element inverted.
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
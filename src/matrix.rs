@@ -0,0 +1,134 @@
+//! Matrix products for [`Vecgrid`]s of numeric elements.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::ops::{Add, Mul};
+
+impl<T> Vecgrid<T>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    /// Computes the matrix product of `self` and `other`, treating
+    /// [`Default::default`] as zero.
+    ///
+    /// Returns an error if the number of columns of `self` does not equal the
+    /// number of rows of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let a = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let b = Vecgrid::from_rows(vec![vec![7, 8], vec![9, 10], vec![11, 12]])?;
+    /// let product = a.matmul(&b)?;
+    /// assert_eq!(product.as_rows(), vec![vec![58, 64], vec![139, 154]]);
+    ///
+    /// assert!(matches!(a.matmul(&a), Err(Error::DimensionMismatch { expected: 3, actual: 2, .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Default::default`]: https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default
+    pub fn matmul(&self, other: &Vecgrid<T>) -> Result<Vecgrid<T>, Error> {
+        if self.num_columns != other.num_rows {
+            return Err(Error::dimension_mismatch(self.num_columns, other.num_rows));
+        }
+        let (num_rows, num_columns) = (self.num_rows, other.num_columns);
+        let mut vecgrid = vec![T::default(); num_rows * num_columns];
+        if num_columns > 0 && self.num_columns > 0 {
+            // Iterating in i-k-j order keeps every inner loop on contiguous rows.
+            for (output_row, lhs_row) in vecgrid
+                .chunks_mut(num_columns)
+                .zip(self.vecgrid.chunks(self.num_columns))
+            {
+                for (&lhs, rhs_row) in lhs_row.iter().zip(other.vecgrid.chunks(num_columns)) {
+                    for (output, &rhs) in output_row.iter_mut().zip(rhs_row) {
+                        *output = *output + lhs * rhs;
+                    }
+                }
+            }
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        })
+    }
+
+    /// Computes the dot product of row `row` of `self` and column `column` of
+    /// `other`, i.e. the element at `(row, column)` of `self.matmul(other)`.
+    ///
+    /// Returns an error if the dimensions do not match or either index is out
+    /// of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let a = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let b = Vecgrid::from_rows(vec![vec![7, 8], vec![9, 10], vec![11, 12]])?;
+    /// assert_eq!(a.row_column_dot(1, &b, 0)?, 139);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn row_column_dot(
+        &self,
+        row: usize,
+        other: &Vecgrid<T>,
+        column: usize,
+    ) -> Result<T, Error> {
+        if self.num_columns != other.num_rows {
+            return Err(Error::dimension_mismatch(self.num_columns, other.num_rows));
+        }
+        let lhs = self.row_iter(row)?;
+        let rhs = other.column_iter(column)?;
+        Ok(lhs
+            .zip(rhs)
+            .fold(T::default(), |sum, (&lhs, &rhs)| sum + lhs * rhs))
+    }
+
+    /// Computes the dot product of rows `a` and `b` of `self`.
+    ///
+    /// Returns an error if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.row_dot(0, 1)?, 32);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn row_dot(&self, a: usize, b: usize) -> Result<T, Error> {
+        let a = self.row_iter(a)?;
+        let b = self.row_iter(b)?;
+        Ok(a.zip(b)
+            .fold(T::default(), |sum, (&lhs, &rhs)| sum + lhs * rhs))
+    }
+
+    /// Computes the dot product of columns `a` and `b` of `self`.
+    ///
+    /// Returns an error if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.column_dot(0, 2)?, 27);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn column_dot(&self, a: usize, b: usize) -> Result<T, Error> {
+        let a = self.column_iter(a)?;
+        let b = self.column_iter(b)?;
+        Ok(a.zip(b)
+            .fold(T::default(), |sum, (&lhs, &rhs)| sum + lhs * rhs))
+    }
+}
@@ -0,0 +1,86 @@
+//! Morphological operations on boolean masks, such as smoothing generated
+//! caves or cleaning up noisy masks.
+//!
+//! Repeated passes alternate between the mask and a single scratch buffer
+//! of the same size, so running more iterations allocates nothing more.
+
+use crate::{Neighborhood, Vecgrid};
+use std::mem;
+
+impl Vecgrid<bool> {
+    /// Erodes the mask `iterations` times in place: after every pass a cell
+    /// is `true` only if it and all of its neighbors in `neighborhood` were
+    /// `true`. Neighbors outside of the mask are ignored, so the border
+    /// does not erode by itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Neighborhood, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut mask = Vecgrid::from_rows(vec![
+    ///     vec![true, true, true, false],
+    ///     vec![true, true, true, false],
+    ///     vec![true, true, true, true],
+    /// ])?;
+    /// mask.erode(Neighborhood::Four, 1);
+    /// assert_eq!(
+    ///     mask.as_rows(),
+    ///     vec![
+    ///         vec![true, true, false, false],
+    ///         vec![true, true, false, false],
+    ///         vec![true, true, true, false],
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn erode(&mut self, neighborhood: Neighborhood, iterations: usize) {
+        self.morph(neighborhood, iterations, false);
+    }
+
+    /// Dilates the mask `iterations` times in place: after every pass a
+    /// cell is `true` if it or any of its neighbors in `neighborhood` was
+    /// `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Neighborhood, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut mask = Vecgrid::filled_with(false, 3, 3);
+    /// mask[(1, 1)] = true;
+    /// mask.dilate(Neighborhood::Four, 1);
+    /// assert_eq!(mask.count_where(|&cell| cell), 5);
+    /// mask.dilate(Neighborhood::Eight, 1);
+    /// assert!(mask.all());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dilate(&mut self, neighborhood: Neighborhood, iterations: usize) {
+        self.morph(neighborhood, iterations, true);
+    }
+
+    /// Runs `iterations` passes in which every cell becomes `spread` if it
+    /// or any of its neighbors is `spread`: `true` dilates and `false`
+    /// erodes.
+    fn morph(&mut self, neighborhood: Neighborhood, iterations: usize, spread: bool) {
+        if iterations == 0 {
+            return;
+        }
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        let mut scratch = vec![false; self.vecgrid.len()];
+        for _ in 0..iterations {
+            for (index, target) in scratch.iter_mut().enumerate() {
+                let (row, column) = self.position(index);
+                let touched = self.vecgrid[index] == spread
+                    || neighborhood
+                        .neighbors(row, column, num_rows, num_columns)
+                        .flatten()
+                        .any(|(row, column)| self.vecgrid[row * num_columns + column] == spread);
+                *target = if touched { spread } else { !spread };
+            }
+            mem::swap(&mut self.vecgrid, &mut scratch);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! The sets of cells around a cell that neighborhood-based operations, such
+//! as auto-tiling bitmasks and morphology, look at.
+
+/// Which cells around a cell count as its neighbors.
+///
+/// The neighbors are always listed clockwise starting from the cell above,
+/// which is the order of the bits in [`neighbor_bitmask`].
+///
+/// [`neighbor_bitmask`]: struct.Vecgrid.html#method.neighbor_bitmask
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Neighborhood {
+    /// The 4 orthogonally adjacent cells: north, east, south and west.
+    Four,
+    /// The 8 orthogonally and diagonally adjacent cells: north, north-east,
+    /// east, south-east, south, south-west, west and north-west.
+    Eight,
+}
+
+impl Neighborhood {
+    /// Returns the offsets, as `(row, column)`, of the neighbors relative
+    /// to the cell, clockwise starting from the cell above.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Neighborhood;
+    /// assert_eq!(Neighborhood::Four.offsets(), &[(-1, 0), (0, 1), (1, 0), (0, -1)]);
+    /// assert_eq!(Neighborhood::Eight.offsets().len(), 8);
+    /// ```
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighborhood::Four => &[(-1, 0), (0, 1), (1, 0), (0, -1)],
+            Neighborhood::Eight => &[
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+            ],
+        }
+    }
+
+    /// Returns the indices of every neighbor of the cell at `row` and
+    /// `column` in a grid of the given dimensions, in the order of
+    /// [`offsets`], with [`None`] for neighbors outside of the grid.
+    ///
+    /// [`offsets`]: enum.Neighborhood.html#method.offsets
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub(crate) fn neighbors(
+        self,
+        row: usize,
+        column: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> impl Iterator<Item = Option<(usize, usize)>> {
+        self.offsets()
+            .iter()
+            .map(move |&(row_offset, column_offset)| {
+                let row = row.checked_add_signed(row_offset)?;
+                let column = column.checked_add_signed(column_offset)?;
+                (row < num_rows && column < num_columns).then_some((row, column))
+            })
+    }
+}
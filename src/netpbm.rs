@@ -0,0 +1,64 @@
+//! Exporting a [`Vecgrid`] as a [Netpbm] image, to look at a grid without
+//! an image library.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [Netpbm]: https://en.wikipedia.org/wiki/Netpbm
+
+use crate::Vecgrid;
+use std::io::{self, Write};
+
+impl<T> Vecgrid<T> {
+    /// Writes the vecgrid as a binary grayscale PGM image, one pixel per
+    /// element, with the shade of every element given by `gray` (`0` is
+    /// black, `255` is white).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// # fn main() -> std::io::Result<()> {
+    /// let heightmap = Vecgrid::filled_by_row_major(|| 0.5f32, 2, 3);
+    /// let mut image = Vec::new();
+    /// heightmap.write_pgm(&mut image, |&height| (height * 255.0) as u8)?;
+    /// assert_eq!(&image[..11], b"P5\n3 2\n255\n");
+    /// assert_eq!(&image[11..], &[127; 6]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_pgm<W, F>(&self, mut writer: W, gray: F) -> io::Result<()>
+    where
+        W: Write,
+        F: Fn(&T) -> u8,
+    {
+        write!(writer, "P5\n{} {}\n255\n", self.num_columns, self.num_rows)?;
+        let pixels = self.vecgrid.iter().map(gray).collect::<Vec<_>>();
+        writer.write_all(&pixels)
+    }
+
+    /// Writes the vecgrid as a binary color PPM image, one pixel per element,
+    /// with the color of every element given by `rgb` as `[red, green,
+    /// blue]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cells = Vecgrid::from_rows(vec![vec![true, false]])?;
+    /// let mut image = Vec::new();
+    /// cells.write_ppm(&mut image, |&alive| if alive { [255, 0, 0] } else { [0; 3] })?;
+    /// assert_eq!(&image[..11], b"P6\n2 1\n255\n");
+    /// assert_eq!(&image[11..], &[255, 0, 0, 0, 0, 0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_ppm<W, F>(&self, mut writer: W, rgb: F) -> io::Result<()>
+    where
+        W: Write,
+        F: Fn(&T) -> [u8; 3],
+    {
+        write!(writer, "P6\n{} {}\n255\n", self.num_columns, self.num_rows)?;
+        let pixels = self.vecgrid.iter().flat_map(rgb).collect::<Vec<_>>();
+        writer.write_all(&pixels)
+    }
+}
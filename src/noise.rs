@@ -0,0 +1,103 @@
+//! Heightmap generation from noise functions, enabled with the `noise`
+//! feature.
+//!
+//! Any function of two coordinates can be used, such as the `get` method of
+//! a noise function from the [`noise`] crate.
+//!
+//! [`noise`]: https://docs.rs/noise
+
+use crate::Vecgrid;
+
+/// How cell indices are turned into the `(x, y)` coordinates passed to a
+/// noise function. `x` always follows the columns and `y` the rows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoiseCoordinates {
+    /// Use the column and row indices as they are.
+    Cells,
+    /// Map the first and last column and row onto `0.0` and `1.0`
+    /// respectively, independent of the dimensions of the vecgrid.
+    Unit,
+    /// Multiply the column and row indices by the given frequency.
+    Scaled(f64),
+    /// Map the first and last column and row onto the given `x` and `y`
+    /// ranges, given as `((x_start, x_end), (y_start, y_end))`.
+    Bounds((f64, f64), (f64, f64)),
+}
+
+impl Vecgrid<f64> {
+    /// Creates a new [`Vecgrid`] with the specified number of rows and columns
+    /// by sampling `noise` at every cell, using the column and row indices as
+    /// the `x` and `y` coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// let vecgrid = Vecgrid::from_noise(2, 3, |x, y| x + 10.0 * y);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![0.0, 1.0, 2.0], vec![10.0, 11.0, 12.0]]);
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn from_noise<F>(num_rows: usize, num_columns: usize, noise: F) -> Self
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        Vecgrid::from_noise_with(num_rows, num_columns, NoiseCoordinates::Cells, noise)
+    }
+
+    /// Creates a new [`Vecgrid`] with the specified number of rows and columns
+    /// by sampling `noise` at every cell, converting the cell indices to
+    /// coordinates as described by `coordinates`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, NoiseCoordinates};
+    /// let vecgrid = Vecgrid::from_noise_with(3, 2, NoiseCoordinates::Unit, |x, y| x * y);
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![0.0, 0.0], vec![0.0, 0.5], vec![0.0, 1.0]]);
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn from_noise_with<F>(
+        num_rows: usize,
+        num_columns: usize,
+        coordinates: NoiseCoordinates,
+        noise: F,
+    ) -> Self
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let ((x_start, x_step), (y_start, y_step)) = match coordinates {
+            NoiseCoordinates::Cells => ((0.0, 1.0), (0.0, 1.0)),
+            NoiseCoordinates::Unit => (
+                (0.0, span_step(0.0, 1.0, num_columns)),
+                (0.0, span_step(0.0, 1.0, num_rows)),
+            ),
+            NoiseCoordinates::Scaled(frequency) => ((0.0, frequency), (0.0, frequency)),
+            NoiseCoordinates::Bounds((x_start, x_end), (y_start, y_end)) => (
+                (x_start, span_step(x_start, x_end, num_columns)),
+                (y_start, span_step(y_start, y_end, num_rows)),
+            ),
+        };
+        let vecgrid = (0..num_rows)
+            .flat_map(|row| {
+                let y = y_start + row as f64 * y_step;
+                (0..num_columns).map(move |column| (x_start + column as f64 * x_step, y))
+            })
+            .map(|(x, y)| noise(x, y))
+            .collect();
+        Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        }
+    }
+}
+
+fn span_step(start: f64, end: f64, len: usize) -> f64 {
+    if len > 1 {
+        (end - start) / (len - 1) as f64
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,140 @@
+//! A [`Vecgrid`] addressed with signed logical coordinates, such as a world
+//! map centered on the origin.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::ops::{Deref, DerefMut, Range};
+
+/// A wrapper around a [`Vecgrid`] that maps logical `(x, y)` coordinates,
+/// which may be negative, onto its cells. `x` follows the columns and `y`
+/// the rows, and the first cell of the vecgrid is at the logical origin
+/// given on creation.
+///
+/// The underlying vecgrid, with its usual `(row, column)` indices, is
+/// available through [`Deref`] and [`DerefMut`].
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::OffsetGrid;
+/// let mut world = OffsetGrid::filled_with(0, -50..50, -50..50);
+/// if let Some(cell) = world.get_logical_mut(-50, 0) {
+///     *cell = 1;
+/// }
+/// assert_eq!(world.get_logical(-50, 0), Some(&1));
+/// assert_eq!(world[(50, 0)], 1);
+/// assert_eq!(world.get_logical(50, 0), None);
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+/// [`DerefMut`]: https://doc.rust-lang.org/std/ops/trait.DerefMut.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OffsetGrid<T> {
+    vecgrid: Vecgrid<T>,
+    min_x: isize,
+    min_y: isize,
+}
+
+impl<T> OffsetGrid<T> {
+    /// Wraps `vecgrid` so that its first cell, at row `0` and column `0`,
+    /// is at the logical coordinates `(min_x, min_y)`.
+    pub fn new(vecgrid: Vecgrid<T>, min_x: isize, min_y: isize) -> Self {
+        OffsetGrid {
+            vecgrid,
+            min_x,
+            min_y,
+        }
+    }
+
+    /// Creates an [`OffsetGrid`] covering the logical coordinates in
+    /// `x_range` and `y_range` that contains `element` in every location.
+    ///
+    /// [`OffsetGrid`]: struct.OffsetGrid.html
+    pub fn filled_with(element: T, x_range: Range<isize>, y_range: Range<isize>) -> Self
+    where
+        T: Clone,
+    {
+        OffsetGrid::new(
+            Vecgrid::filled_with(element, y_range.len(), x_range.len()),
+            x_range.start,
+            y_range.start,
+        )
+    }
+
+    /// Unwraps the underlying [`Vecgrid`].
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn into_inner(self) -> Vecgrid<T> {
+        self.vecgrid
+    }
+
+    /// The range of logical `x` coordinates covered by the columns.
+    pub fn x_range(&self) -> Range<isize> {
+        self.min_x
+            ..self
+                .min_x
+                .saturating_add_unsigned(self.vecgrid.num_columns())
+    }
+
+    /// The range of logical `y` coordinates covered by the rows.
+    pub fn y_range(&self) -> Range<isize> {
+        self.min_y..self.min_y.saturating_add_unsigned(self.vecgrid.num_rows())
+    }
+
+    /// Converts logical coordinates into the `(row, column)` indices of the
+    /// underlying vecgrid, or [`None`] if they are outside of it.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn to_indices(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let row = usize::try_from(y.checked_sub(self.min_y)?).ok()?;
+        let column = usize::try_from(x.checked_sub(self.min_x)?).ok()?;
+        if row < self.vecgrid.num_rows() && column < self.vecgrid.num_columns() {
+            Some((row, column))
+        } else {
+            None
+        }
+    }
+
+    /// Converts `(row, column)` indices of the underlying vecgrid into
+    /// logical `(x, y)` coordinates.
+    pub fn to_logical(&self, row: usize, column: usize) -> (isize, isize) {
+        (
+            self.min_x.wrapping_add_unsigned(column),
+            self.min_y.wrapping_add_unsigned(row),
+        )
+    }
+
+    /// Returns a reference to the element at the logical coordinates `x`
+    /// and `y`, or [`None`] if they are outside of the grid.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_logical(&self, x: isize, y: isize) -> Option<&T> {
+        let (row, column) = self.to_indices(x, y)?;
+        self.vecgrid.get(row, column)
+    }
+
+    /// Returns a mutable reference to the element at the logical
+    /// coordinates `x` and `y`, or [`None`] if they are outside of the grid.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_logical_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let (row, column) = self.to_indices(x, y)?;
+        self.vecgrid.get_mut(row, column)
+    }
+}
+
+impl<T> Deref for OffsetGrid<T> {
+    type Target = Vecgrid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vecgrid
+    }
+}
+
+impl<T> DerefMut for OffsetGrid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.vecgrid
+    }
+}
@@ -0,0 +1,175 @@
+//! Ordering the cells of a [`Vecgrid`] by their elements, for processing
+//! cells by priority.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+impl<T> Vecgrid<T> {
+    /// Returns the `(row, column)` of every element, ordered from the
+    /// smallest to the largest element. Equal elements stay in [row major
+    /// order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heights = Vecgrid::from_rows(vec![vec![3, 1], vec![2, 1]])?;
+    /// assert_eq!(heights.sorted_positions(), vec![(0, 1), (1, 1), (1, 0), (0, 0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn sorted_positions(&self) -> Vec<(usize, usize)>
+    where
+        T: Ord,
+    {
+        self.sorted_positions_by(T::cmp)
+    }
+
+    /// Returns the `(row, column)` of every element, ordered by `compare`
+    /// like [`slice::sort_by`], for example to order floats or to put the
+    /// largest elements first. Equal elements stay in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heights = Vecgrid::from_rows(vec![vec![0.5f64, 2.0], vec![1.5, 0.0]])?;
+    /// let highest_first = heights.sorted_positions_by(|a, b| b.total_cmp(a));
+    /// assert_eq!(highest_first, vec![(0, 1), (1, 0), (0, 0), (1, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn sorted_positions_by<F>(&self, mut compare: F) -> Vec<(usize, usize)>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.sorted_indices_by(&mut compare)
+            .into_iter()
+            .map(|index| self.position(index))
+            .collect()
+    }
+
+    /// Creates a new [`Vecgrid`] holding the rank of every element, its
+    /// index in [`sorted_positions`]: `0` for the smallest element and one
+    /// less than the number of elements for the largest. Equal elements
+    /// get consecutive ranks in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let scores = Vecgrid::from_rows(vec![vec![30, 10], vec![20, 10]])?;
+    /// assert_eq!(scores.rank_grid().as_rows(), vec![vec![3, 0], vec![2, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`sorted_positions`]: struct.Vecgrid.html#method.sorted_positions
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn rank_grid(&self) -> Vecgrid<usize>
+    where
+        T: Ord,
+    {
+        self.rank_grid_by(T::cmp)
+    }
+
+    /// Creates a new [`Vecgrid`] holding the rank of every element in the
+    /// order of `compare`, its index in [`sorted_positions_by`]. Equal
+    /// elements get consecutive ranks in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heights = Vecgrid::from_rows(vec![vec![0.5, 2.0], vec![1.5, 0.0]])?;
+    /// let ranks = heights.rank_grid_by(f64::total_cmp);
+    /// assert_eq!(ranks.as_rows(), vec![vec![1, 3], vec![2, 0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`sorted_positions_by`]: struct.Vecgrid.html#method.sorted_positions_by
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn rank_grid_by<F>(&self, mut compare: F) -> Vecgrid<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut ranks = vec![0; self.vecgrid.len()];
+        for (rank, index) in self.sorted_indices_by(&mut compare).into_iter().enumerate() {
+            ranks[index] = rank;
+        }
+        Vecgrid {
+            vecgrid: ranks,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Returns the `k` largest elements with their `(row, column)`, from
+    /// the largest to the smallest, or all elements if there are fewer than
+    /// `k`. Of equal elements, the first in [row major order] comes first.
+    ///
+    /// Only `k` elements are kept while scanning the vecgrid, so this is
+    /// faster than [`sorted_positions`] when `k` is small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heatmap = Vecgrid::from_rows(vec![vec![4, 9, 1], vec![7, 3, 9]])?;
+    /// assert_eq!(heatmap.top_k(3), vec![((0, 1), &9), ((1, 2), &9), ((1, 0), &7)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`sorted_positions`]: struct.Vecgrid.html#method.sorted_positions
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn top_k(&self, k: usize) -> Vec<((usize, usize), &T)>
+    where
+        T: Ord,
+    {
+        // A min-heap of the best elements so far, with its worst on top.
+        // Earlier indices rank higher among equal elements.
+        let mut heap = BinaryHeap::with_capacity(k.min(self.vecgrid.len()));
+        for (index, element) in self.vecgrid.iter().enumerate() {
+            let candidate = Reverse((element, Reverse(index)));
+            if heap.len() < k {
+                heap.push(candidate);
+            } else if let Some(mut worst) = heap.peek_mut() {
+                if candidate < *worst {
+                    *worst = candidate;
+                }
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((element, Reverse(index)))| (self.position(index), element))
+            .collect()
+    }
+
+    /// Returns the row major indices of all elements, stably sorted by
+    /// `compare` of their elements.
+    fn sorted_indices_by<F>(&self, compare: &mut F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut indices: Vec<usize> = (0..self.vecgrid.len()).collect();
+        indices.sort_by(|&a, &b| compare(&self.vecgrid[a], &self.vecgrid[b]));
+        indices
+    }
+}
@@ -0,0 +1,338 @@
+//! Path searches over the cells of a [`Vecgrid`].
+//!
+//! Searches work on flat row major indices internally, so no coordinates
+//! are hashed. Movement is between orthogonally adjacent cells.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// The steps a path may take in [`min_cost_path_table`] and
+/// [`min_cost_path`].
+///
+/// [`min_cost_path_table`]: struct.Vecgrid.html#method.min_cost_path_table
+/// [`min_cost_path`]: struct.Vecgrid.html#method.min_cost_path
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Moves {
+    /// One cell to the right or one cell down, so paths never turn back.
+    RightDown,
+    /// One cell up, right, down or left.
+    Orthogonal,
+}
+
+impl<T> Vecgrid<T> {
+    /// Finds a cheapest path from `start` to `goal` using the [A*] search
+    /// algorithm, moving between orthogonally adjacent cells.
+    ///
+    /// `cost` returns the cost of entering a cell, or [`None`] if the cell
+    /// cannot be entered. `heuristic` estimates the remaining cost from a
+    /// cell to `goal`; the result is a cheapest path as long as the estimate
+    /// never exceeds the actual cost (e.g. the Manhattan distance multiplied
+    /// by the smallest cost).
+    ///
+    /// Returns the total cost and the path including both `start` and
+    /// `goal`, [`None`] if `goal` cannot be reached, or an error if `start`
+    /// or `goal` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let terrain = Vecgrid::from_rows(vec![
+    ///     vec!['.', '~', '.'],
+    ///     vec!['.', '#', '.'],
+    ///     vec!['.', '.', '.'],
+    /// ])?;
+    /// let cost = |cell: &char| match cell {
+    ///     '.' => Some(1),
+    ///     '~' => Some(9),
+    ///     _ => None,
+    /// };
+    /// let goal = (0, 2);
+    /// let manhattan = |(row, column): (usize, usize)| {
+    ///     (row.abs_diff(goal.0) + column.abs_diff(goal.1)) as u32
+    /// };
+    /// let (total, path) = terrain.astar((0, 0), goal, cost, manhattan)?.unwrap();
+    /// assert_eq!(total, 6);
+    /// assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [A*]: https://en.wikipedia.org/wiki/A*_search_algorithm
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    #[allow(clippy::type_complexity)]
+    pub fn astar<C, H>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        cost: C,
+        heuristic: H,
+    ) -> Result<Option<(u32, Vec<(usize, usize)>)>, Error>
+    where
+        C: Fn(&T) -> Option<u32>,
+        H: Fn((usize, usize)) -> u32,
+    {
+        let start_index = self
+            .get_index(start.0, start.1)
+            .ok_or_else(|| self.indices_out_of_bounds(start.0, start.1))?;
+        let goal_index = self
+            .get_index(goal.0, goal.1)
+            .ok_or_else(|| self.indices_out_of_bounds(goal.0, goal.1))?;
+
+        let mut best = vec![u32::MAX; self.vecgrid.len()];
+        let mut parents = vec![usize::MAX; self.vecgrid.len()];
+        let mut open = BinaryHeap::new();
+        best[start_index] = 0;
+        open.push(Reverse((heuristic(start), 0u32, start_index)));
+
+        while let Some(Reverse((_, so_far, index))) = open.pop() {
+            if index == goal_index {
+                return Ok(Some((so_far, self.trace_path(&parents, goal_index))));
+            }
+            if so_far > best[index] {
+                continue;
+            }
+            for neighbor in self.orthogonal_neighbors(index) {
+                let step = match cost(&self.vecgrid[neighbor]) {
+                    Some(step) => step,
+                    None => continue,
+                };
+                let through = so_far.saturating_add(step);
+                if through < best[neighbor] {
+                    best[neighbor] = through;
+                    parents[neighbor] = index;
+                    let position = self.position(neighbor);
+                    let estimate = through.saturating_add(heuristic(position));
+                    open.push(Reverse((estimate, through, neighbor)));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Computes, for every cell, the number of orthogonal steps to the
+    /// nearest of the given `sources` using a single breadth-first search.
+    /// Only cells for which `passable` returns `true` are stepped on;
+    /// sources always have distance `0`. Cells that cannot be reached are
+    /// [`None`].
+    ///
+    /// Returns an error if any of the sources is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['.', '.', '.'],
+    ///     vec!['#', '#', '.'],
+    ///     vec!['.', '#', '.'],
+    /// ])?;
+    /// let distances = map.distance_field(&[(0, 0)], |cell| *cell == '.')?;
+    /// assert_eq!(
+    ///     distances.as_rows(),
+    ///     vec![
+    ///         vec![Some(0), Some(1), Some(2)],
+    ///         vec![None, None, Some(3)],
+    ///         vec![None, None, Some(4)],
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn distance_field<F>(
+        &self,
+        sources: &[(usize, usize)],
+        passable: F,
+    ) -> Result<Vecgrid<Option<u32>>, Error>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut distances = vec![None; self.vecgrid.len()];
+        let mut queue = VecDeque::with_capacity(sources.len());
+        for &(row, column) in sources {
+            let index = self
+                .get_index(row, column)
+                .ok_or_else(|| self.indices_out_of_bounds(row, column))?;
+            if distances[index].is_none() {
+                distances[index] = Some(0);
+                queue.push_back(index);
+            }
+        }
+
+        while let Some(index) = queue.pop_front() {
+            let next = distances[index].map(|distance: u32| distance + 1);
+            for neighbor in self.orthogonal_neighbors(index) {
+                if distances[neighbor].is_none() && passable(&self.vecgrid[neighbor]) {
+                    distances[neighbor] = next;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        Ok(Vecgrid {
+            vecgrid: distances,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        })
+    }
+
+    /// Computes, for every cell, the smallest total cost of a path from the
+    /// top left cell to it, where `cost` returns the cost of every cell on
+    /// the path including both ends, using [dynamic programming] for
+    /// [`Moves::RightDown`] and [Dijkstra's algorithm] for
+    /// [`Moves::Orthogonal`]. Totals saturate at [`u64::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Moves, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let costs = Vecgrid::from_rows(vec![vec![1, 3, 1], vec![1, 5, 1], vec![4, 2, 1]])?;
+    /// let table = costs.min_cost_path_table(|&cost| cost, Moves::RightDown);
+    /// assert_eq!(table.as_rows(), vec![vec![1, 4, 5], vec![2, 7, 6], vec![6, 8, 7]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [dynamic programming]: https://en.wikipedia.org/wiki/Dynamic_programming
+    /// [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+    /// [`Moves::RightDown`]: enum.Moves.html#variant.RightDown
+    /// [`Moves::Orthogonal`]: enum.Moves.html#variant.Orthogonal
+    /// [`u64::MAX`]: https://doc.rust-lang.org/std/primitive.u64.html#associatedconstant.MAX
+    pub fn min_cost_path_table<C>(&self, cost: C, moves: Moves) -> Vecgrid<u64>
+    where
+        C: Fn(&T) -> u64,
+    {
+        let (totals, _) = self.min_cost_totals(&cost, moves);
+        Vecgrid {
+            vecgrid: totals,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Finds a cheapest path from the top left cell to `goal`, with the same
+    /// costs and moves as [`min_cost_path_table`].
+    ///
+    /// Returns the total cost and the path including both ends, or an error
+    /// if `goal` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Moves, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let costs = Vecgrid::from_rows(vec![
+    ///     vec![1, 9, 1, 1],
+    ///     vec![1, 9, 1, 9],
+    ///     vec![1, 1, 1, 9],
+    /// ])?;
+    /// let (total, path) = costs.min_cost_path((0, 3), |&cost| cost, Moves::Orthogonal)?;
+    /// assert_eq!(total, 8);
+    /// assert_eq!(
+    ///     path,
+    ///     vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2), (0, 3)]
+    /// );
+    /// let (total, _) = costs.min_cost_path((0, 3), |&cost| cost, Moves::RightDown)?;
+    /// assert_eq!(total, 12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`min_cost_path_table`]: struct.Vecgrid.html#method.min_cost_path_table
+    pub fn min_cost_path<C>(
+        &self,
+        goal: (usize, usize),
+        cost: C,
+        moves: Moves,
+    ) -> Result<(u64, Vec<(usize, usize)>), Error>
+    where
+        C: Fn(&T) -> u64,
+    {
+        let goal_index = self
+            .get_index(goal.0, goal.1)
+            .ok_or_else(|| self.indices_out_of_bounds(goal.0, goal.1))?;
+        let (totals, parents) = self.min_cost_totals(&cost, moves);
+        Ok((totals[goal_index], self.trace_path(&parents, goal_index)))
+    }
+
+    fn min_cost_totals<C>(&self, cost: &C, moves: Moves) -> (Vec<u64>, Vec<usize>)
+    where
+        C: Fn(&T) -> u64,
+    {
+        let len = self.vecgrid.len();
+        let mut totals = vec![u64::MAX; len];
+        let mut parents = vec![usize::MAX; len];
+        if len == 0 {
+            return (totals, parents);
+        }
+        match moves {
+            Moves::RightDown => {
+                for (index, element) in self.vecgrid.iter().enumerate() {
+                    let above = (index >= self.num_columns).then(|| index - self.num_columns);
+                    let left = (index % self.num_columns > 0).then(|| index - 1);
+                    let parent = [above, left]
+                        .into_iter()
+                        .flatten()
+                        .min_by_key(|&parent| totals[parent]);
+                    let so_far = parent.map_or(0, |parent| totals[parent]);
+                    totals[index] = so_far.saturating_add(cost(element));
+                    parents[index] = parent.unwrap_or(usize::MAX);
+                }
+            }
+            Moves::Orthogonal => {
+                let mut open = BinaryHeap::new();
+                totals[0] = cost(&self.vecgrid[0]);
+                open.push(Reverse((totals[0], 0)));
+                while let Some(Reverse((so_far, index))) = open.pop() {
+                    if so_far > totals[index] {
+                        continue;
+                    }
+                    for neighbor in self.orthogonal_neighbors(index) {
+                        let through = so_far.saturating_add(cost(&self.vecgrid[neighbor]));
+                        if through < totals[neighbor] {
+                            totals[neighbor] = through;
+                            parents[neighbor] = index;
+                            open.push(Reverse((through, neighbor)));
+                        }
+                    }
+                }
+            }
+        }
+        (totals, parents)
+    }
+
+    pub(crate) fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.num_columns, index % self.num_columns)
+    }
+
+    pub(crate) fn orthogonal_neighbors(&self, index: usize) -> impl Iterator<Item = usize> {
+        let (row, column) = self.position(index);
+        let num_columns = self.num_columns;
+        [
+            (row > 0).then(|| index - num_columns),
+            (column > 0).then(|| index - 1),
+            (column + 1 < num_columns).then(|| index + 1),
+            (row + 1 < self.num_rows).then(|| index + num_columns),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn trace_path(&self, parents: &[usize], goal_index: usize) -> Vec<(usize, usize)> {
+        let mut path = vec![self.position(goal_index)];
+        let mut index = goal_index;
+        while parents[index] != usize::MAX {
+            index = parents[index];
+            path.push(self.position(index));
+        }
+        path.reverse();
+        path
+    }
+}
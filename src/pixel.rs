@@ -0,0 +1,239 @@
+//! Typed color pixels for [`Vecgrid`]s used as images, enabled with the
+//! `pixel` feature.
+//!
+//! [`Rgb`] and [`Rgba`] are `#[repr(C)]`, so a `Vecgrid<Rgba<u8>>` has the
+//! same memory layout as the interleaved bytes most image formats use.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`Rgb`]: ../struct.Rgb.html
+//! [`Rgba`]: ../struct.Rgba.html
+
+use crate::channels::check_channels;
+use crate::{Error, Vecgrid};
+
+/// A color made of red, green and blue channels.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::Rgb;
+/// let orange = Rgb::new(255u8, 165, 0);
+/// assert_eq!(orange.g, 165);
+/// assert_eq!(orange.channels(), [255, 165, 0]);
+/// assert_eq!(Rgb::from([255, 165, 0]), orange);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub struct Rgb<T> {
+    /// The red channel.
+    pub r: T,
+    /// The green channel.
+    pub g: T,
+    /// The blue channel.
+    pub b: T,
+}
+
+/// A color made of red, green, blue and alpha channels, where alpha is the
+/// opacity.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Rgb, Rgba};
+/// let glass = Rgba::new(200u8, 230, 255, 64);
+/// assert_eq!(glass.a, 64);
+/// assert_eq!(glass.rgb(), Rgb::new(200, 230, 255));
+/// assert_eq!(<[u8; 4]>::from(glass), [200, 230, 255, 64]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub struct Rgba<T> {
+    /// The red channel.
+    pub r: T,
+    /// The green channel.
+    pub g: T,
+    /// The blue channel.
+    pub b: T,
+    /// The alpha channel.
+    pub a: T,
+}
+
+impl<T> Rgb<T> {
+    /// Creates a color from its channels.
+    pub fn new(r: T, g: T, b: T) -> Self {
+        Rgb { r, g, b }
+    }
+
+    /// Returns the channels in the order red, green, blue.
+    pub fn channels(self) -> [T; 3] {
+        [self.r, self.g, self.b]
+    }
+}
+
+impl<T> Rgba<T> {
+    /// Creates a color from its channels.
+    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+        Rgba { r, g, b, a }
+    }
+
+    /// Returns the channels in the order red, green, blue, alpha.
+    pub fn channels(self) -> [T; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Returns the color without its alpha channel.
+    pub fn rgb(self) -> Rgb<T> {
+        Rgb::new(self.r, self.g, self.b)
+    }
+}
+
+impl Rgba<u8> {
+    /// Returns the color of `self` drawn over `below` with the [over]
+    /// operator of alpha compositing, for colors that are not premultiplied
+    /// by their alpha. The result is rounded to the nearest channel values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Rgba;
+    /// let red = Rgba::new(255, 0, 0, 255);
+    /// let half_blue = Rgba::new(0, 0, 255, 128);
+    /// assert_eq!(half_blue.over(red), Rgba::new(127, 0, 128, 255));
+    /// assert_eq!(red.over(half_blue), red);
+    /// ```
+    ///
+    /// [over]: https://en.wikipedia.org/wiki/Alpha_compositing
+    pub fn over(self, below: Rgba<u8>) -> Rgba<u8> {
+        let alpha = u32::from(self.a);
+        let below_alpha = u32::from(below.a) * (255 - alpha);
+        // The alpha of the result, scaled by 255.
+        let total = alpha * 255 + below_alpha;
+        if total == 0 {
+            return Rgba::default();
+        }
+        let channel = |above: u8, below: u8| {
+            let weighted = u32::from(above) * alpha * 255 + u32::from(below) * below_alpha;
+            ((weighted + total / 2) / total) as u8
+        };
+        Rgba {
+            r: channel(self.r, below.r),
+            g: channel(self.g, below.g),
+            b: channel(self.b, below.b),
+            a: ((total + 127) / 255) as u8,
+        }
+    }
+}
+
+impl Vecgrid<Rgba<u8>> {
+    /// Draws `layer` over the image with its top-left pixel at `at`, as
+    /// `(row, column)`, combining every pair of pixels with [`Rgba::over`].
+    /// The parts of the layer outside of the image are clipped, so `at` may
+    /// be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Rgba, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let white = Rgba::new(255, 255, 255, 255);
+    /// let mut image = Vecgrid::filled_with(white, 2, 2);
+    /// let shadow = Vecgrid::filled_with(Rgba::new(0, 0, 0, 128), 2, 2);
+    /// image.composite_over(&shadow, (1, -1));
+    /// let gray = Rgba::new(127, 127, 127, 255);
+    /// assert_eq!(image.as_rows(), vec![vec![white, white], vec![gray, white]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Rgba::over`]: struct.Rgba.html#method.over
+    pub fn composite_over(&mut self, layer: &Vecgrid<Rgba<u8>>, at: (isize, isize)) {
+        self.stamp(layer, at, |pixel, &above| *pixel = above.over(*pixel));
+    }
+}
+
+impl<T> From<[T; 3]> for Rgb<T> {
+    fn from([r, g, b]: [T; 3]) -> Self {
+        Rgb { r, g, b }
+    }
+}
+
+impl<T> From<Rgb<T>> for [T; 3] {
+    fn from(color: Rgb<T>) -> Self {
+        color.channels()
+    }
+}
+
+impl<T> From<[T; 4]> for Rgba<T> {
+    fn from([r, g, b, a]: [T; 4]) -> Self {
+        Rgba { r, g, b, a }
+    }
+}
+
+impl<T> From<Rgba<T>> for [T; 4] {
+    fn from(color: Rgba<T>) -> Self {
+        color.channels()
+    }
+}
+
+macro_rules! impl_channel_split {
+    ($($pixel:ident, $len:literal, $names:literal);*) => {
+        $(
+            impl<T: Copy> Vecgrid<$pixel<T>> {
+                #[doc = concat!(
+                    "Splits the image into one vecgrid per channel, in the order ",
+                    $names, ", with the dimensions of the image."
+                )]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                #[doc = concat!("# use vecgrid::{", stringify!($pixel), ", Vecgrid, Error};")]
+                /// # fn main() -> Result<(), Error> {
+                #[doc = concat!(
+                    "let image = Vecgrid::filled_with(",
+                    stringify!($pixel), "::from([7u8; ", stringify!($len), "]), 2, 3);"
+                )]
+                /// let channels = image.split_channels();
+                /// assert_eq!(channels[0], Vecgrid::filled_with(7, 2, 3));
+                #[doc = concat!(
+                    "assert_eq!(Vecgrid::<", stringify!($pixel), "<u8>>::from_channels(&channels)?, image);"
+                )]
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn split_channels(&self) -> [Vecgrid<T>; $len] {
+                    std::array::from_fn(|channel| Vecgrid {
+                        vecgrid: self
+                            .vecgrid
+                            .iter()
+                            .map(|&pixel| pixel.channels()[channel])
+                            .collect(),
+                        num_rows: self.num_rows,
+                        num_columns: self.num_columns,
+                    })
+                }
+
+                #[doc = concat!(
+                    "Creates an image from one vecgrid per channel, in the order ", $names, ", ",
+                    "the inverse of [`split_channels`]. Returns an error if the channels do not ",
+                    "all have the same dimensions, with the index of the first one that differs."
+                )]
+                ///
+                /// [`split_channels`]: struct.Vecgrid.html#method.split_channels
+                pub fn from_channels(channels: &[Vecgrid<T>; $len]) -> Result<Self, Error> {
+                    let (num_rows, num_columns) = check_channels(channels)?;
+                    let vecgrid = (0..num_rows * num_columns)
+                        .map(|index| $pixel::from(channels.each_ref().map(|channel| channel.vecgrid[index])))
+                        .collect();
+                    Ok(Vecgrid {
+                        vecgrid,
+                        num_rows,
+                        num_columns,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_channel_split!(Rgb, 3, "red, green, blue"; Rgba, 4, "red, green, blue, alpha");
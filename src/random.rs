@@ -0,0 +1,125 @@
+//! Random filling and sampling of [`Vecgrid`]s through the [`rand`] crate,
+//! enabled with the `rand` feature.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`rand`]: https://docs.rs/rand
+
+use crate::Vecgrid;
+use rand::distributions::{Distribution, Standard};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl<T> Vecgrid<T> {
+    /// Creates a new [`Vecgrid`] with the specified number of rows and columns
+    /// where every element is drawn from the [`Standard`] distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// let mut rng = rand::thread_rng();
+    /// let vecgrid: Vecgrid<u8> = Vecgrid::random(3, 2, &mut rng);
+    /// assert_eq!(vecgrid.num_elements(), 6);
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Standard`]: https://docs.rs/rand/0.8/rand/distributions/struct.Standard.html
+    pub fn random<R>(num_rows: usize, num_columns: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+        Standard: Distribution<T>,
+    {
+        Vecgrid::random_from(num_rows, num_columns, &Standard, rng)
+    }
+
+    /// Creates a new [`Vecgrid`] with the specified number of rows and columns
+    /// where every element is drawn from `distribution`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// use rand::distributions::Uniform;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let vecgrid = Vecgrid::random_from(3, 2, &Uniform::new(0, 10), &mut rng);
+    /// assert!(vecgrid.elements_row_major_iter().all(|&x| x < 10));
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn random_from<D, R>(
+        num_rows: usize,
+        num_columns: usize,
+        distribution: &D,
+        rng: &mut R,
+    ) -> Self
+    where
+        D: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        let vecgrid = distribution
+            .sample_iter(rng)
+            .take(num_rows * num_columns)
+            .collect();
+        Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        }
+    }
+
+    /// Randomly shuffles all elements across the whole vecgrid, keeping its
+    /// dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut rng = rand::thread_rng();
+    /// let mut vecgrid = Vecgrid::from_iter_row_major(0.., 3, 3)?;
+    /// vecgrid.shuffle(&mut rng);
+    /// let mut elements = vecgrid.as_row_major();
+    /// elements.sort();
+    /// assert_eq!(elements, (0..9).collect::<Vec<_>>());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        self.vecgrid.shuffle(rng);
+    }
+
+    /// Returns a uniformly chosen element together with its `(row, column)`
+    /// index, or [`None`] if the vecgrid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut rng = rand::thread_rng();
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// let (index, element) = vecgrid.choose(&mut rng).unwrap();
+    /// assert_eq!(&vecgrid[index], element);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn choose<R>(&self, rng: &mut R) -> Option<((usize, usize), &T)>
+    where
+        R: Rng + ?Sized,
+    {
+        if self.vecgrid.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.vecgrid.len());
+        Some((
+            (index / self.num_columns, index % self.num_columns),
+            &self.vecgrid[index],
+        ))
+    }
+}
@@ -0,0 +1,118 @@
+//! Rectangular regions of a [`Vecgrid`] that satisfy a predicate.
+//!
+//! Rectangles are given as the indices, as `(row, column)`, of their
+//! top-left and bottom-right elements, both inclusive.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+
+impl<T> Vecgrid<T> {
+    /// Returns the smallest rectangle, as its top-left and bottom-right
+    /// indices, that contains every element for which `predicate` returns
+    /// `true`, or [`None`] if there is no such element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let sprite = Vecgrid::from_rows(vec![
+    ///     vec![0, 0, 0, 0],
+    ///     vec![0, 0, 7, 0],
+    ///     vec![0, 7, 0, 0],
+    /// ])?;
+    /// assert_eq!(sprite.bounding_box_where(|&pixel| pixel != 0), Some(((1, 1), (2, 2))));
+    /// assert_eq!(sprite.bounding_box_where(|&pixel| pixel > 7), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn bounding_box_where<F>(&self, predicate: F) -> Option<((usize, usize), (usize, usize))>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut matching = self
+            .vecgrid
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| predicate(element))
+            .map(|(index, _)| self.position(index));
+        let first = matching.next()?;
+        Some(matching.fold(
+            (first, first),
+            |((top, left), (bottom, right)), (row, column)| {
+                (
+                    (top, left.min(column)),
+                    (bottom.max(row), right.max(column)),
+                )
+            },
+        ))
+    }
+
+    /// Returns the rectangle with the largest area, as its top-left and
+    /// bottom-right indices, in which `predicate` returns `true` for every
+    /// element, or [`None`] if it returns `true` for no element. Of several
+    /// largest rectangles, the one whose bottom-right element comes first
+    /// in [row major order] is returned.
+    ///
+    /// This runs in `O(rows × columns)` time, using the heights of the runs
+    /// of matching elements above every element and a stack per row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['.', '.', '#', '.'],
+    ///     vec!['.', '.', '.', '.'],
+    ///     vec!['#', '.', '.', '.'],
+    /// ])?;
+    /// let room = map.largest_rectangle_where(|&cell| cell == '.');
+    /// assert_eq!(room, Some(((1, 1), (2, 3))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn largest_rectangle_where<F>(
+        &self,
+        predicate: F,
+    ) -> Option<((usize, usize), (usize, usize))>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut heights = vec![0; self.num_columns];
+        let mut stack: Vec<usize> = Vec::with_capacity(self.num_columns);
+        let mut largest = None;
+        let mut largest_area = 0;
+        for (row, elements) in self.vecgrid.chunks(self.num_columns.max(1)).enumerate() {
+            for (height, element) in heights.iter_mut().zip(elements) {
+                *height = if predicate(element) { *height + 1 } else { 0 };
+            }
+            // Every column is popped once the first lower column after it
+            // is reached, when its rectangle of full height ends.
+            for column in 0..=self.num_columns {
+                let height = heights.get(column).copied().unwrap_or(0);
+                while let Some(&top) = stack.last() {
+                    if heights[top] < height {
+                        break;
+                    }
+                    stack.pop();
+                    let left = stack.last().map_or(0, |&left| left + 1);
+                    let area = heights[top] * (column - left);
+                    if area > largest_area {
+                        largest_area = area;
+                        largest = Some(((row + 1 - heights[top], left), (row, column - 1)));
+                    }
+                }
+                stack.push(column);
+            }
+            stack.clear();
+        }
+        largest
+    }
+}
@@ -0,0 +1,112 @@
+//! Replacing every element of a [`Vecgrid`] through a lookup, such as the
+//! tone curves and contrast adjustments of images.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+
+impl<T> Vecgrid<T> {
+    /// Replaces every element with the result of `table` for it, in a single
+    /// pass over the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut tiles = Vecgrid::from_rows(vec![vec!['#', '.'], vec!['.', '~']])?;
+    /// tiles.remap_values(|tile| match tile {
+    ///     '~' => '.',
+    ///     &other => other,
+    /// });
+    /// assert_eq!(tiles.as_rows(), vec![vec!['#', '.'], vec!['.', '.']]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remap_values<F>(&mut self, mut table: F)
+    where
+        F: FnMut(&T) -> T,
+    {
+        for element in &mut self.vecgrid {
+            *element = table(element);
+        }
+    }
+
+    /// Replaces every element greater than `threshold` with `high` and
+    /// every other element with `low`, like [`threshold`] without creating
+    /// a new vecgrid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut image = Vecgrid::from_rows(vec![vec![10u8, 200], vec![127, 128]])?;
+    /// image.binarize_in_place(127, 0, 255);
+    /// assert_eq!(image.as_rows(), vec![vec![0, 255], vec![0, 255]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`threshold`]: struct.Vecgrid.html#method.threshold
+    pub fn binarize_in_place(&mut self, threshold: T, low: T, high: T)
+    where
+        T: PartialOrd + Clone,
+    {
+        self.remap_values(|element| {
+            if *element > threshold {
+                high.clone()
+            } else {
+                low.clone()
+            }
+        });
+    }
+}
+
+impl Vecgrid<u8> {
+    /// Spreads the values of the vecgrid over the whole range from `0` to
+    /// `255` by [histogram equalization], increasing the contrast of images
+    /// that use only part of the range. The order of values is kept, the
+    /// smallest value becomes `0` and the largest `255`. A vecgrid with a
+    /// single distinct value is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut image = Vecgrid::from_rows(vec![vec![100, 101], vec![102, 103]])?;
+    /// image.equalize_histogram();
+    /// assert_eq!(image.as_rows(), vec![vec![0, 85], vec![170, 255]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [histogram equalization]: https://en.wikipedia.org/wiki/Histogram_equalization
+    pub fn equalize_histogram(&mut self) {
+        let mut counts = [0usize; 256];
+        for &value in &self.vecgrid {
+            counts[usize::from(value)] += 1;
+        }
+        let mut cumulative = [0usize; 256];
+        let mut total = 0;
+        for (value, &count) in counts.iter().enumerate() {
+            total += count;
+            cumulative[value] = total;
+        }
+        let smallest = counts
+            .iter()
+            .position(|&count| count > 0)
+            .map_or(0, |value| cumulative[value]);
+        if total == smallest {
+            return;
+        }
+        let range = (total - smallest) as u64;
+        let mut table = [0u8; 256];
+        for (entry, &below) in table.iter_mut().zip(&cumulative) {
+            let above_smallest = below.saturating_sub(smallest) as u64;
+            *entry = ((above_smallest * 255 + range / 2) / range) as u8;
+        }
+        self.remap_values(|&value| table[usize::from(value)]);
+    }
+}
@@ -0,0 +1,137 @@
+//! A compact [run-length encoded] form of a [`Vecgrid`], for grids with long
+//! runs of equal elements such as terrain maps.
+//!
+//! [run-length encoded]: https://en.wikipedia.org/wiki/Run-length_encoding
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::stream::MAX_INITIAL_RESERVATION;
+use crate::{Error, Vecgrid};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The elements of a [`Vecgrid`] in [row major order], stored as runs of
+/// equal elements, as returned by [`to_rle`].
+///
+/// With the `serde` feature it can be serialized in place of the vecgrid,
+/// which is much smaller when the vecgrid is mostly uniform.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`to_rle`]: struct.Vecgrid.html#method.to_rle
+/// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RunLengthEncoded<T> {
+    runs: Vec<(T, usize)>,
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl<T> RunLengthEncoded<T> {
+    /// The number of rows of the encoded vecgrid.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns of the encoded vecgrid.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The runs, each an element and the number of times it repeats, in
+    /// [row major order].
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn runs(&self) -> &[(T, usize)] {
+        &self.runs
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Encodes the vecgrid as runs of equal consecutive elements in [row
+    /// major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![vec!['~', '~', '~'], vec!['~', '#', '#']])?;
+    /// let rle = map.to_rle();
+    /// assert_eq!(rle.runs(), &[('~', 4), ('#', 2)]);
+    /// assert_eq!(Vecgrid::from_rle(rle)?, map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn to_rle(&self) -> RunLengthEncoded<T>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        for element in &self.vecgrid {
+            match runs.last_mut() {
+                Some((last, count)) if last == element => *count += 1,
+                _ => runs.push((element.clone(), 1)),
+            }
+        }
+        RunLengthEncoded {
+            runs,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Decodes a vecgrid from its run-length encoded form. Returns an error
+    /// if the number of elements overflows a `usize` or the runs do not add
+    /// up to the number of elements given by the dimensions, which can only
+    /// happen for a deserialized encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::filled_with(0u8, 2048, 2048);
+    /// let rle = map.to_rle();
+    /// assert_eq!(rle.runs().len(), 1);
+    /// assert_eq!(Vecgrid::from_rle(rle)?, map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_rle(encoded: RunLengthEncoded<T>) -> Result<Self, Error>
+    where
+        T: Clone,
+    {
+        let overflow = Error::ElementCountOverflow {
+            num_rows: encoded.num_rows,
+            num_columns: encoded.num_columns,
+        };
+        let total_len = encoded
+            .num_rows
+            .checked_mul(encoded.num_columns)
+            .ok_or(overflow)?;
+        let actual = encoded
+            .runs
+            .iter()
+            .try_fold(0usize, |sum, (_, count)| sum.checked_add(*count))
+            .ok_or(overflow)?;
+        if actual != total_len {
+            return Err(Error::dimension_mismatch(total_len, actual));
+        }
+        // The encoding may come from untrusted serialized data: like the
+        // header of a row stream, reserve no more than a small buffer up
+        // front and let the buffer grow as the runs are decoded.
+        let initial_capacity = total_len.min(MAX_INITIAL_RESERVATION / size_of::<T>().max(1));
+        let mut vecgrid = Vec::with_capacity(initial_capacity);
+        for (element, count) in encoded.runs {
+            vecgrid.extend(std::iter::repeat_n(element, count));
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows: encoded.num_rows,
+            num_columns: encoded.num_columns,
+        })
+    }
+}
@@ -0,0 +1,657 @@
+//! Views of a rectangular region of interest of a [`Vecgrid`], indexed
+//! relative to the corner of the region.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{resolve_range, Error, GridIndex, Vecgrid};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, IndexMut, Range, RangeBounds};
+
+/// A read-only view of a rectangular region of a [`Vecgrid`], as returned
+/// by [`roi`] and [`enumerate_windows`].
+///
+/// Rows and columns are indexed relative to the region, so its top left
+/// element is at `(0, 0)`; [`first_row`] and [`first_column`] give where the
+/// region starts in the vecgrid.
+///
+/// Like a [`VecgridViewMut`], a view can be narrowed further with its own
+/// [`roi`][sub], and [`offset_in_parent`] refers to the view the region was
+/// taken from.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`VecgridViewMut`]: struct.VecgridViewMut.html
+/// [`roi`]: struct.Vecgrid.html#method.roi
+/// [sub]: struct.VecgridView.html#method.roi
+/// [`enumerate_windows`]: struct.Vecgrid.html#method.enumerate_windows
+/// [`first_row`]: struct.VecgridView.html#method.first_row
+/// [`first_column`]: struct.VecgridView.html#method.first_column
+/// [`offset_in_parent`]: struct.VecgridView.html#method.offset_in_parent
+#[derive(Clone, Copy)]
+pub struct VecgridView<'a, T> {
+    /// The elements from the top left to the bottom right element of the
+    /// region, including the parts of rows between them outside of it.
+    elements: &'a [T],
+    stride: usize,
+    first_row: usize,
+    first_column: usize,
+    offset_in_parent: (usize, usize),
+    num_rows: usize,
+    num_columns: usize,
+}
+
+/// A mutable view of a rectangular region of a [`Vecgrid`], as returned by
+/// [`roi_mut`]. Writes go straight to the vecgrid.
+///
+/// Rows and columns are indexed relative to the region, so its top left
+/// element is at `(0, 0)`; [`first_row`] and [`first_column`] give where the
+/// region starts in the vecgrid.
+///
+/// A view can be narrowed further with its own [`roi_mut`][sub], for nested
+/// windows. The offsets of the nested views compose, so [`first_row`] and
+/// [`first_column`] still refer to the vecgrid, while
+/// [`offset_in_parent`] refers to the view the region was taken from.
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`roi_mut`]: struct.Vecgrid.html#method.roi_mut
+/// [sub]: struct.VecgridViewMut.html#method.roi_mut
+/// [`first_row`]: struct.VecgridViewMut.html#method.first_row
+/// [`first_column`]: struct.VecgridViewMut.html#method.first_column
+/// [`offset_in_parent`]: struct.VecgridViewMut.html#method.offset_in_parent
+pub struct VecgridViewMut<'a, T> {
+    /// The elements from the top left to the bottom right element of the
+    /// region, including the parts of rows between them outside of it.
+    elements: &'a mut [T],
+    stride: usize,
+    first_row: usize,
+    first_column: usize,
+    offset_in_parent: (usize, usize),
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl<'a, T> VecgridView<'a, T> {
+    /// The index, in the vecgrid, of the first row of the region.
+    pub fn first_row(&self) -> usize {
+        self.first_row
+    }
+
+    /// The index, in the vecgrid, of the first column of the region.
+    pub fn first_column(&self) -> usize {
+        self.first_column
+    }
+
+    /// The `(row, column)` of the top left element of the region in the
+    /// view or vecgrid it was taken from.
+    pub fn offset_in_parent(&self) -> (usize, usize) {
+        self.offset_in_parent
+    }
+
+    /// The number of rows in the region.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the region.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// Returns a reference to the element at the given `row` and `column`,
+    /// relative to the region, or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        (row < self.num_rows && column < self.num_columns)
+            .then(|| &self.elements[row * self.stride + column])
+    }
+
+    /// Returns an [`Iterator`] over references to the elements of the given
+    /// row of the region. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter(
+        &self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &'a T> + Clone, Error> {
+        if row_index >= self.num_rows {
+            return Err(Error::indices_out_of_bounds(
+                row_index,
+                0,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[row_index * self.stride..][..self.num_columns].iter())
+    }
+
+    /// Returns an [`Iterator`] over the rows of the region as slices, from
+    /// top to bottom.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn rows_iter(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + Clone {
+        let Self {
+            elements,
+            stride,
+            num_columns,
+            ..
+        } = *self;
+        (0..self.num_rows).map(move |row| &elements[row * stride..][..num_columns])
+    }
+
+    /// Returns an [`Iterator`] over references to all elements of the
+    /// region in [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + Clone {
+        self.rows_iter().flatten()
+    }
+
+    /// Returns a [`VecgridView`] of the part of this region covering the
+    /// rows in `row_range` and the columns in `column_range`, both relative
+    /// to this region. Returns an error if either range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_iter_row_major(0.., 4, 5)?;
+    /// let panel = map.roi(1.., 2..)?;
+    /// let cell = panel.roi(2..3, 1..2)?;
+    /// assert_eq!(cell.offset_in_parent(), (2, 1));
+    /// assert_eq!((cell.first_row(), cell.first_column()), (3, 3));
+    /// assert_eq!(cell[(0, 0)], map[(3, 3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VecgridView`]: struct.VecgridView.html
+    pub fn roi<R, C>(&self, row_range: R, column_range: C) -> Result<VecgridView<'a, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let rows = resolve_range(row_range, self.num_rows)?;
+        let columns = resolve_range(column_range, self.num_columns)?;
+        let mut view = view_region(self.elements, self.stride, rows, columns);
+        view.first_row += self.first_row;
+        view.first_column += self.first_column;
+        Ok(view)
+    }
+
+    /// Copies the elements of the region into a new, owned [`Vecgrid`].
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn to_vecgrid(&self) -> Vecgrid<T>
+    where
+        T: Clone,
+    {
+        Vecgrid {
+            vecgrid: self.elements_row_major_iter().cloned().collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+impl<T> VecgridViewMut<'_, T> {
+    /// The index, in the vecgrid, of the first row of the region.
+    pub fn first_row(&self) -> usize {
+        self.first_row
+    }
+
+    /// The index, in the vecgrid, of the first column of the region.
+    pub fn first_column(&self) -> usize {
+        self.first_column
+    }
+
+    /// The `(row, column)` of the top left element of the region in the
+    /// view or vecgrid it was taken from.
+    pub fn offset_in_parent(&self) -> (usize, usize) {
+        self.offset_in_parent
+    }
+
+    /// The number of rows in the region.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns in the region.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// Returns a reference to the element at the given `row` and `column`,
+    /// relative to the region, or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.get_index(row, column)
+            .map(|index| &self.elements[index])
+    }
+
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column`, relative to the region, or [`None`] if the index is out of
+    /// bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.get_index(row, column)
+            .map(move |index| &mut self.elements[index])
+    }
+
+    /// Changes the element at the given `row` and `column`, relative to the
+    /// region, to `element`. Returns an error if the indices are out of
+    /// bounds.
+    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
+        let index = self.get_index(row, column).ok_or_else(|| {
+            Error::indices_out_of_bounds(row, column, self.num_rows, self.num_columns)
+        })?;
+        self.elements[index] = element;
+        Ok(())
+    }
+
+    /// Changes every element of the region to `element`.
+    pub fn fill(&mut self, element: T)
+    where
+        T: Clone,
+    {
+        for row in 0..self.num_rows {
+            let start = row * self.stride;
+            self.elements[start..start + self.num_columns].fill(element.clone());
+        }
+    }
+
+    /// Returns an [`Iterator`] over references to the elements of the given
+    /// row of the region. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter(&self, row_index: usize) -> Result<impl DoubleEndedIterator<Item = &T>, Error> {
+        if row_index >= self.num_rows {
+            return Err(Error::indices_out_of_bounds(
+                row_index,
+                0,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[row_index * self.stride..][..self.num_columns].iter())
+    }
+
+    /// Returns an [`Iterator`] over mutable references to the elements of
+    /// the given row of the region. Returns an error if the index is out of
+    /// bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter_mut(
+        &mut self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        if row_index >= self.num_rows {
+            return Err(Error::indices_out_of_bounds(
+                row_index,
+                0,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[row_index * self.stride..][..self.num_columns].iter_mut())
+    }
+
+    /// Returns a [`VecgridViewMut`] of the part of this region covering the
+    /// rows in `row_range` and the columns in `column_range`, both relative
+    /// to this region. Returns an error if either range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut map = Vecgrid::filled_with('.', 6, 6);
+    /// let mut panel = map.roi_mut(1..5, 2..)?;
+    /// let mut button = panel.roi_mut(2..3, 1..3)?;
+    /// assert_eq!(button.offset_in_parent(), (2, 1));
+    /// assert_eq!((button.first_row(), button.first_column()), (3, 3));
+    /// button.fill('#');
+    /// assert_eq!(map[(3, 3)], '#');
+    /// assert_eq!(map[(3, 4)], '#');
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VecgridViewMut`]: struct.VecgridViewMut.html
+    pub fn roi_mut<R, C>(
+        &mut self,
+        row_range: R,
+        column_range: C,
+    ) -> Result<VecgridViewMut<'_, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let rows = resolve_range(row_range, self.num_rows)?;
+        let columns = resolve_range(column_range, self.num_columns)?;
+        let mut view = region(self.elements, self.stride, rows, columns);
+        view.first_row += self.first_row;
+        view.first_column += self.first_column;
+        Ok(view)
+    }
+
+    /// Copies the elements of the region into a new, owned [`Vecgrid`].
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn to_vecgrid(&self) -> Vecgrid<T>
+    where
+        T: Clone,
+    {
+        let mut vecgrid = Vec::with_capacity(self.num_rows * self.num_columns);
+        for row in 0..self.num_rows {
+            vecgrid.extend_from_slice(&self.elements[row * self.stride..][..self.num_columns]);
+        }
+        Vecgrid {
+            vecgrid,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    fn get_index(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.num_rows && column < self.num_columns).then(|| row * self.stride + column)
+    }
+
+    /// Returns the rows of the region, leaving out the elements between
+    /// them that are outside of it.
+    fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.num_rows).map(move |row| &self.elements[row * self.stride..][..self.num_columns])
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Returns a [`VecgridView`] of the region of the vecgrid covering the
+    /// rows in `row_range` and the columns in `column_range`, indexed
+    /// relative to the top left corner of the region. Returns an error if
+    /// either range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])?;
+    /// let roi = vecgrid.roi(1.., ..2)?;
+    /// assert_eq!(roi[(0, 1)], 5);
+    /// assert_eq!(roi.to_vecgrid().as_rows(), vec![vec![4, 5], vec![7, 8]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VecgridView`]: struct.VecgridView.html
+    pub fn roi<R, C>(&self, row_range: R, column_range: C) -> Result<VecgridView<'_, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let rows = resolve_range(row_range, self.num_rows)?;
+        let columns = resolve_range(column_range, self.num_columns)?;
+        Ok(view_region(&self.vecgrid, self.num_columns, rows, columns))
+    }
+
+    /// Returns an [`Iterator`] over every window of `num_rows` by
+    /// `num_columns` elements of the vecgrid, with the `(row, column)` of
+    /// its top left element, like [`slice::windows`] in two dimensions.
+    /// The windows are in row major order of their positions, and there
+    /// are none if the vecgrid is smaller than a window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows` or `num_columns` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])?;
+    /// let sums = vecgrid
+    ///     .enumerate_windows(2, 2)
+    ///     .map(|(position, window)| (position, window.elements_row_major_iter().sum::<i32>()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![((0, 0), 12), ((0, 1), 16), ((1, 0), 24), ((1, 1), 28)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`slice::windows`]: https://doc.rust-lang.org/std/primitive.slice.html#method.windows
+    pub fn enumerate_windows(
+        &self,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> impl Iterator<Item = ((usize, usize), VecgridView<'_, T>)> + Clone {
+        assert!(num_rows > 0, "window rows must be greater than 0");
+        assert!(num_columns > 0, "window columns must be greater than 0");
+        let row_positions = (self.num_rows + 1).saturating_sub(num_rows);
+        let column_positions = (self.num_columns + 1).saturating_sub(num_columns);
+        (0..row_positions * column_positions).map(move |index| {
+            let position = (index / column_positions, index % column_positions);
+            let window = view_region(
+                &self.vecgrid,
+                self.num_columns,
+                (position.0, position.0 + num_rows),
+                (position.1, position.1 + num_columns),
+            );
+            (position, window)
+        })
+    }
+
+    /// Returns a [`VecgridViewMut`] of the region of the vecgrid covering
+    /// the rows in `row_range` and the columns in `column_range`, indexed
+    /// relative to the top left corner of the region. Returns an error if
+    /// either range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    /// let mut roi = canvas.roi_mut(1.., 1..3)?;
+    /// roi[(0, 0)] = 1;
+    /// roi.set(1, 1, 2)?;
+    /// assert!(roi.get(0, 2).is_none());
+    /// assert_eq!(
+    ///     canvas.as_rows(),
+    ///     vec![vec![0, 0, 0, 0], vec![0, 1, 0, 0], vec![0, 0, 2, 0]]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VecgridViewMut`]: struct.VecgridViewMut.html
+    pub fn roi_mut<R, C>(
+        &mut self,
+        row_range: R,
+        column_range: C,
+    ) -> Result<VecgridViewMut<'_, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let rows = resolve_range(row_range, self.num_rows)?;
+        let columns = resolve_range(column_range, self.num_columns)?;
+        Ok(region(&mut self.vecgrid, self.num_columns, rows, columns))
+    }
+}
+
+/// Returns the span of row-major elements, whose rows start `stride`
+/// elements apart, from the top left to the bottom right element of the
+/// rows from `top` to `bottom` and the columns from `left` to `right`.
+/// The span is empty if the region has no rows, but still reaches the last
+/// row if it only has no columns, so every row starts inside of it. The
+/// ranges must be in bounds.
+fn region_span(
+    stride: usize,
+    (top, bottom): (usize, usize),
+    (left, right): (usize, usize),
+) -> Range<usize> {
+    if top < bottom {
+        top * stride + left..(bottom - 1) * stride + right
+    } else {
+        0..0
+    }
+}
+
+/// Creates a read-only view of the rows from `top` to `bottom` and the
+/// columns from `left` to `right` of the row-major `elements`, whose rows
+/// start `stride` elements apart. The ranges must be in bounds.
+fn view_region<T>(
+    elements: &[T],
+    stride: usize,
+    (top, bottom): (usize, usize),
+    (left, right): (usize, usize),
+) -> VecgridView<'_, T> {
+    VecgridView {
+        elements: &elements[region_span(stride, (top, bottom), (left, right))],
+        stride,
+        first_row: top,
+        first_column: left,
+        offset_in_parent: (top, left),
+        num_rows: bottom - top,
+        num_columns: right - left,
+    }
+}
+
+/// Creates a mutable view of the rows from `top` to `bottom` and the
+/// columns from `left` to `right` of the row-major `elements`, whose rows
+/// start `stride` elements apart. The ranges must be in bounds.
+fn region<T>(
+    elements: &mut [T],
+    stride: usize,
+    (top, bottom): (usize, usize),
+    (left, right): (usize, usize),
+) -> VecgridViewMut<'_, T> {
+    VecgridViewMut {
+        elements: &mut elements[region_span(stride, (top, bottom), (left, right))],
+        stride,
+        first_row: top,
+        first_column: left,
+        offset_in_parent: (top, left),
+        num_rows: bottom - top,
+        num_columns: right - left,
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for VecgridView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecgridView")
+            .field("first_row", &self.first_row)
+            .field("first_column", &self.first_column)
+            .field("rows", &self.rows_iter().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Views are equal if their regions have the same dimensions and elements,
+/// wherever the regions are.
+impl<T: PartialEq> PartialEq for VecgridView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.num_rows, self.num_columns) == (other.num_rows, other.num_columns)
+            && self.rows_iter().eq(other.rows_iter())
+    }
+}
+
+impl<T: Eq> Eq for VecgridView<'_, T> {}
+
+impl<T: Hash> Hash for VecgridView<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.num_rows, self.num_columns).hash(state);
+        for row in self.rows_iter() {
+            row.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for VecgridViewMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecgridViewMut")
+            .field("first_row", &self.first_row)
+            .field("first_column", &self.first_column)
+            .field("rows", &self.rows().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Views are equal if their regions have the same dimensions and elements,
+/// wherever the regions are.
+impl<T: PartialEq> PartialEq for VecgridViewMut<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.num_rows, self.num_columns) == (other.num_rows, other.num_columns)
+            && self.rows().eq(other.rows())
+    }
+}
+
+impl<T: Eq> Eq for VecgridViewMut<'_, T> {}
+
+impl<T: Hash> Hash for VecgridViewMut<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.num_rows, self.num_columns).hash(state);
+        for row in self.rows() {
+            row.hash(state);
+        }
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for VecgridView<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, relative to the region,
+    /// given as `(row, column)` or any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for VecgridViewMut<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, relative to the region,
+    /// given as `(row, column)` or any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for VecgridViewMut<'_, T> {
+    /// Returns a mutable version of the element at the given indices,
+    /// relative to the region, given as `(row, column)` or any other
+    /// [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
@@ -0,0 +1,177 @@
+//! Sampling a [`Vecgrid`] as a continuous field at fractional coordinates,
+//! for example a heightmap or a texture, and resampling it to a new
+//! resolution.
+//!
+//! Coordinates are given as `x` along the columns and `y` along the rows,
+//! with the element at row `r` and column `c` at exactly `(c, r)`.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+
+/// How [`resampled`] computes the elements of the new vecgrid.
+///
+/// [`resampled`]: struct.Vecgrid.html#method.resampled
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Resampling {
+    /// Copies the nearest element, see [`sample_nearest`].
+    ///
+    /// [`sample_nearest`]: struct.Vecgrid.html#method.sample_nearest
+    Nearest,
+    /// Interpolates between the four surrounding elements, see
+    /// [`sample_bilinear`].
+    ///
+    /// [`sample_bilinear`]: struct.Vecgrid.html#method.sample_bilinear
+    Bilinear,
+}
+
+impl<T> Vecgrid<T> {
+    /// Returns a reference to the element nearest to the coordinates `x`
+    /// (along the columns) and `y` (along the rows), or [`None`] if that is
+    /// outside of the vecgrid or either coordinate is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let texture = Vecgrid::from_rows(vec![vec!['a', 'b'], vec!['c', 'd']])?;
+    /// assert_eq!(texture.sample_nearest(0.8, 0.2), Some(&'b'));
+    /// assert_eq!(texture.sample_nearest(0.4, 1.3), Some(&'c'));
+    /// assert_eq!(texture.sample_nearest(1.6, 0.0), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn sample_nearest(&self, x: f64, y: f64) -> Option<&T> {
+        let column = nearest_index(x, self.num_columns)?;
+        let row = nearest_index(y, self.num_rows)?;
+        self.get(row, column)
+    }
+}
+
+/// Returns the coordinate in the old vecgrid of `index` in the new one, so
+/// that the first and last elements of both line up.
+fn resampled_coordinate(index: usize, len: usize, new_len: usize) -> f64 {
+    let last = len.saturating_sub(1) as f64;
+    if new_len > 1 {
+        index as f64 * last / (new_len - 1) as f64
+    } else {
+        last / 2.0
+    }
+}
+
+/// Rounds `coordinate` to the nearest index below `len`.
+fn nearest_index(coordinate: f64, len: usize) -> Option<usize> {
+    let index = coordinate.round();
+    (index >= 0.0 && index < len as f64).then_some(index as usize)
+}
+
+macro_rules! impl_float_sampling {
+    ($($float:ty),*) => {
+        $(
+            impl Vecgrid<$float> {
+                /// Returns the value at the coordinates `x` (along the
+                /// columns) and `y` (along the rows), interpolated bilinearly
+                /// between the four surrounding elements. Returns [`None`] if
+                /// the coordinates are outside of the range covered by the
+                /// elements, from `0.0` to the number of columns or rows minus
+                /// one, or `NaN`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heights: Vecgrid<f64> =
+                ///     Vecgrid::from_rows(vec![vec![0.0, 10.0], vec![20.0, 30.0]])?;
+                /// assert_eq!(heights.sample_bilinear(0.5, 0.0), Some(5.0));
+                /// assert_eq!(heights.sample_bilinear(0.5, 0.5), Some(15.0));
+                /// assert_eq!(heights.sample_bilinear(1.0, 1.0), Some(30.0));
+                /// assert_eq!(heights.sample_bilinear(1.5, 0.0), None);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+                pub fn sample_bilinear(&self, x: $float, y: $float) -> Option<$float> {
+                    let in_range = |coordinate: $float, len: usize| {
+                        coordinate >= 0.0 && coordinate <= len as $float - 1.0
+                    };
+                    if !in_range(x, self.num_columns) || !in_range(y, self.num_rows) {
+                        return None;
+                    }
+                    let (column, row) = (x.floor() as usize, y.floor() as usize);
+                    let (x_fraction, y_fraction) = (x - column as $float, y - row as $float);
+                    let next_column = (column + 1).min(self.num_columns - 1);
+                    let next_row = (row + 1).min(self.num_rows - 1);
+                    let lerp = |a: $float, b: $float, t: $float| a + (b - a) * t;
+                    let top = lerp(self[(row, column)], self[(row, next_column)], x_fraction);
+                    let bottom = lerp(
+                        self[(next_row, column)],
+                        self[(next_row, next_column)],
+                        x_fraction,
+                    );
+                    Some(lerp(top, bottom, y_fraction))
+                }
+
+                /// Returns a copy of the vecgrid scaled to `num_rows` rows and
+                /// `num_columns` columns, such as a thumbnail or a terrain at
+                /// a different level of detail. The corner elements of both
+                /// vecgrids line up, and every new element is sampled with
+                /// `method` at the matching coordinates.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the vecgrid has no elements but the new
+                /// dimensions do.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Resampling, Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![vec![0.0, 4.0]])?;
+                /// let finer = heights.resampled(1, 5, Resampling::Bilinear);
+                /// assert_eq!(finer.as_row_major(), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+                /// let blocky = heights.resampled(2, 3, Resampling::Nearest);
+                /// assert_eq!(blocky.as_rows(), vec![vec![0.0, 4.0, 4.0]; 2]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn resampled(
+                    &self,
+                    num_rows: usize,
+                    num_columns: usize,
+                    method: Resampling,
+                ) -> Vecgrid<$float> {
+                    assert!(
+                        !self.vecgrid.is_empty() || num_rows * num_columns == 0,
+                        "cannot resample a vecgrid without elements"
+                    );
+                    let sample = |(row, column)| {
+                        let x = resampled_coordinate(column, self.num_columns, num_columns);
+                        let y = resampled_coordinate(row, self.num_rows, num_rows);
+                        let sample = match method {
+                            Resampling::Nearest => self.sample_nearest(x, y).copied(),
+                            Resampling::Bilinear => {
+                                self.sample_bilinear(x as $float, y as $float)
+                            }
+                        };
+                        sample.expect("the coordinates are inside of the vecgrid")
+                    };
+                    Vecgrid {
+                        vecgrid: crate::indices_row_major(num_rows, num_columns)
+                            .map(sample)
+                            .collect(),
+                        num_rows,
+                        num_columns,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_sampling!(f32, f64);
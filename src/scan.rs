@@ -0,0 +1,132 @@
+//! Running accumulations along the rows or columns of a [`Vecgrid`], such
+//! as prefix sums or the tables of dynamic programming on grids.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::ops::Add;
+
+impl<T> Vecgrid<T> {
+    /// Creates a new [`Vecgrid`] by accumulating along every row from left
+    /// to right. Each element of the result is `f` applied to the result to
+    /// its left, or to `init` in the first column, and the element at the
+    /// same position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![3, 1, 4], vec![1, 5, 9]])?;
+    /// let running_max = vecgrid.scan_rows(0, |&max, &element| max.max(element));
+    /// assert_eq!(running_max.as_rows(), vec![vec![3, 3, 4], vec![1, 5, 9]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn scan_rows<U, F>(&self, init: U, mut f: F) -> Vecgrid<U>
+    where
+        F: FnMut(&U, &T) -> U,
+    {
+        let mut vecgrid: Vec<U> = Vec::with_capacity(self.vecgrid.len());
+        for (index, element) in self.vecgrid.iter().enumerate() {
+            let previous = if index % self.num_columns > 0 {
+                &vecgrid[index - 1]
+            } else {
+                &init
+            };
+            let next = f(previous, element);
+            vecgrid.push(next);
+        }
+        Vecgrid {
+            vecgrid,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Creates a new [`Vecgrid`] by accumulating along every column from top
+    /// to bottom. Each element of the result is `f` applied to the result
+    /// above it, or to `init` in the first row, and the element at the same
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    /// let products = vecgrid.scan_columns(1, |&product, &element| product * element);
+    /// assert_eq!(products.as_rows(), vec![vec![1, 2], vec![3, 8], vec![15, 48]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn scan_columns<U, F>(&self, init: U, mut f: F) -> Vecgrid<U>
+    where
+        F: FnMut(&U, &T) -> U,
+    {
+        let mut vecgrid: Vec<U> = Vec::with_capacity(self.vecgrid.len());
+        for (index, element) in self.vecgrid.iter().enumerate() {
+            let previous = if index >= self.num_columns {
+                &vecgrid[index - self.num_columns]
+            } else {
+                &init
+            };
+            let next = f(previous, element);
+            vecgrid.push(next);
+        }
+        Vecgrid {
+            vecgrid,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+impl<T> Vecgrid<T>
+where
+    T: Copy + Default + Add<Output = T>,
+{
+    /// Creates a new [`Vecgrid`] holding the running sums of every row, from
+    /// left to right, treating [`Default::default`] as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.row_prefix_sums().as_rows(), vec![vec![1, 3, 6], vec![4, 9, 15]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Default::default`]: https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default
+    pub fn row_prefix_sums(&self) -> Vecgrid<T> {
+        self.scan_rows(T::default(), |&sum, &element| sum + element)
+    }
+
+    /// Creates a new [`Vecgrid`] holding the running sums of every column,
+    /// from top to bottom, treating [`Default::default`] as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.column_prefix_sums().as_rows(), vec![vec![1, 2, 3], vec![5, 7, 9]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    /// [`Default::default`]: https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default
+    pub fn column_prefix_sums(&self) -> Vecgrid<T> {
+        self.scan_columns(T::default(), |&sum, &element| sum + element)
+    }
+}
@@ -0,0 +1,133 @@
+//! Content-aware resizing of a [`Vecgrid`] by [seam carving]: removing one
+//! cell per row along a connected path of low energy, so an image can get
+//! narrower while its important features keep their shape.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [seam carving]: https://en.wikipedia.org/wiki/Seam_carving
+
+use crate::{Error, Vecgrid};
+
+impl<T> Vecgrid<T> {
+    /// Finds a vertical seam of smallest total energy, with `energy`
+    /// returning the energy of every cell, for example the gradient
+    /// magnitude of an image. The seam has one cell per row, and the cells
+    /// of consecutive rows are at most one column apart. Totals saturate at
+    /// [`u64::MAX`].
+    ///
+    /// Returns the column of the seam in every row, from top to bottom, or
+    /// an empty seam if the vecgrid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let energy = Vecgrid::from_rows(vec![
+    ///     vec![5, 1, 5, 5],
+    ///     vec![5, 5, 1, 5],
+    ///     vec![5, 1, 5, 5],
+    /// ])?;
+    /// assert_eq!(energy.find_vertical_seam(|&energy| energy), vec![1, 2, 1]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`u64::MAX`]: https://doc.rust-lang.org/std/primitive.u64.html#associatedconstant.MAX
+    pub fn find_vertical_seam<E>(&self, energy: E) -> Vec<usize>
+    where
+        E: Fn(&T) -> u64,
+    {
+        let num_columns = self.num_columns;
+        if self.vecgrid.is_empty() {
+            return Vec::new();
+        }
+        let mut totals: Vec<u64> = Vec::with_capacity(self.vecgrid.len());
+        for (index, element) in self.vecgrid.iter().enumerate() {
+            let so_far = if index >= num_columns {
+                let above = index - num_columns;
+                self.seam_parents(above)
+                    .map(|parent| totals[parent])
+                    .min()
+                    .unwrap_or(0)
+            } else {
+                0
+            };
+            totals.push(so_far.saturating_add(energy(element)));
+        }
+
+        let last_row = (self.num_rows - 1) * num_columns;
+        let mut index = (last_row..last_row + num_columns)
+            .min_by_key(|&index| totals[index])
+            .expect("the last row is not empty");
+        let mut seam = vec![index % num_columns];
+        while index >= num_columns {
+            index = self
+                .seam_parents(index - num_columns)
+                .min_by_key(|&parent| totals[parent])
+                .expect("every cell has a cell above it");
+            seam.push(index % num_columns);
+        }
+        seam.reverse();
+        seam
+    }
+
+    /// Removes the cell at the given column of every row, such as a seam
+    /// found by [`find_vertical_seam`], so the vecgrid loses one column.
+    /// The cells right of the seam move one column to the left.
+    ///
+    /// Returns an error, without changing the vecgrid, if the seam does not
+    /// have one column per row or a column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut image = Vecgrid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']])?;
+    /// image.remove_vertical_seam(&[2, 1])?;
+    /// assert_eq!(image.as_rows(), vec![vec!['a', 'b'], vec!['d', 'f']]);
+    /// assert!(image.remove_vertical_seam(&[0]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_vertical_seam`]: struct.Vecgrid.html#method.find_vertical_seam
+    pub fn remove_vertical_seam(&mut self, seam: &[usize]) -> Result<(), Error> {
+        if seam.len() != self.num_rows {
+            return Err(Error::dimension_mismatch(self.num_rows, seam.len()));
+        }
+        if self.num_columns == 0 {
+            return Err(self.indices_out_of_bounds(0, 0));
+        }
+        if let Some((row, &column)) = seam
+            .iter()
+            .enumerate()
+            .find(|(_, &column)| column >= self.num_columns)
+        {
+            return Err(self.indices_out_of_bounds(row, column));
+        }
+        let num_columns = self.num_columns;
+        let mut index = 0;
+        self.vecgrid.retain(|_| {
+            let keep = index % num_columns != seam[index / num_columns];
+            index += 1;
+            keep
+        });
+        self.num_columns -= 1;
+        trace_reshape!(self, "removed vertical seam");
+        Ok(())
+    }
+
+    /// Returns `index` and the cells beside it in the same row, the
+    /// possible predecessors in a seam of the cell below `index`.
+    fn seam_parents(&self, index: usize) -> impl Iterator<Item = usize> {
+        let column = index % self.num_columns;
+        [
+            Some(index),
+            (column > 0).then(|| index - 1),
+            (column + 1 < self.num_columns).then(|| index + 1),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
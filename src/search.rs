@@ -0,0 +1,450 @@
+//! Finding the locations of elements and sub-grids in a [`Vecgrid`].
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::cmp::Ordering;
+
+impl<T> Vecgrid<T> {
+    /// Returns an [`Iterator`] over the indices, as `(row, column)`, of
+    /// every element equal to `value`, in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Vecgrid::from_rows(vec![vec!['X', '.', 'O'], vec!['.', 'X', '.']])?;
+    /// let crosses = board.positions_of(&'X').collect::<Vec<_>>();
+    /// assert_eq!(crosses, vec![(0, 0), (1, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn positions_of<'a>(
+        &'a self,
+        value: &'a T,
+    ) -> impl DoubleEndedIterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        let num_columns = self.num_columns;
+        self.vecgrid
+            .iter()
+            .enumerate()
+            .filter(move |(_, element)| *element == value)
+            .map(move |(index, _)| (index / num_columns, index % num_columns))
+    }
+
+    /// Returns the indices, as `(row, column)`, of the first element in [row
+    /// major order] equal to `value`, or [`None`] if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Vecgrid::from_rows(vec![vec!['.', '.', 'K'], vec!['K', '.', '.']])?;
+    /// assert_eq!(board.first_position_of(&'K'), Some((0, 2)));
+    /// assert_eq!(board.first_position_of(&'Q'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn first_position_of(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    /// Returns the offset, as `(row, column)` of its top-left element, of
+    /// the first place in [row major order] where `pattern` occurs in the
+    /// vecgrid, or [`None`] if it does not occur. See [`find_subgrid_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['.', '.', '.', '.'],
+    ///     vec!['.', '#', '#', '.'],
+    ///     vec!['.', '#', '#', '.'],
+    /// ])?;
+    /// let block = Vecgrid::filled_with('#', 2, 2);
+    /// assert_eq!(map.find_subgrid(&block), Some((1, 1)));
+    /// assert_eq!(map.find_subgrid(&Vecgrid::filled_with('#', 3, 1)), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`find_subgrid_all`]: struct.Vecgrid.html#method.find_subgrid_all
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn find_subgrid(&self, pattern: &Vecgrid<T>) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find_subgrid_all(pattern).next()
+    }
+
+    /// Returns an [`Iterator`] over the offsets, as `(row, column)` of the
+    /// top-left element, of every place where `pattern` occurs in the
+    /// vecgrid, in [row major order]. Occurrences may overlap. A pattern
+    /// without elements occurs at every offset where it fits, like an empty
+    /// string does in a string.
+    ///
+    /// The search follows the [Baker–Bird algorithm]: every distinct row of
+    /// the pattern gets an id, [KMP] over every row of the vecgrid labels
+    /// each column with the id of the pattern row starting there, and KMP
+    /// over the labels down every column finds the ids of the pattern rows
+    /// in order. This takes `O(R·C·d)` time for a vecgrid of `R` by `C`
+    /// elements and a pattern with `d` distinct rows, instead of comparing
+    /// the whole pattern at every offset, and only needs [`PartialEq`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 1, 2], vec![3, 4, 3, 4]])?;
+    /// let pattern = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    /// let offsets = vecgrid.find_subgrid_all(&pattern).collect::<Vec<_>>();
+    /// assert_eq!(offsets, vec![(0, 0), (0, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    /// [Baker–Bird algorithm]: https://en.wikipedia.org/wiki/Baker%E2%80%93Bird_algorithm
+    /// [KMP]: https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm
+    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    pub fn find_subgrid_all<'a>(
+        &'a self,
+        pattern: &'a Vecgrid<T>,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        let (pattern_rows, pattern_columns) = (pattern.num_rows, pattern.num_columns);
+        let offset_rows = (self.num_rows + 1).saturating_sub(pattern_rows);
+        let offset_columns = (self.num_columns + 1).saturating_sub(pattern_columns);
+        let mut matches =
+            vec![pattern_rows == 0 || pattern_columns == 0; offset_rows * offset_columns];
+        if offset_rows > 0 && offset_columns > 0 && pattern_rows > 0 && pattern_columns > 0 {
+            // Give every distinct pattern row an id, the index of its first
+            // occurrence among the distinct rows.
+            let pattern_row =
+                |row: usize| &pattern.vecgrid[row * pattern_columns..][..pattern_columns];
+            let mut distinct: Vec<usize> = Vec::new();
+            let ids: Vec<Option<usize>> = (0..pattern_rows)
+                .map(|row| {
+                    let id = distinct
+                        .iter()
+                        .position(|&other| pattern_row(other) == pattern_row(row))
+                        .unwrap_or_else(|| {
+                            distinct.push(row);
+                            distinct.len() - 1
+                        });
+                    Some(id)
+                })
+                .collect();
+
+            // Label every offset with the id of the pattern row that
+            // occurs there. Distinct rows have the same length, so at most
+            // one of them can start at any column.
+            let mut labels = vec![None; self.num_rows * offset_columns];
+            for (id, &row) in distinct.iter().enumerate() {
+                let needle = pattern_row(row);
+                let failure = kmp_failure(needle);
+                for (grid_row, elements) in self.vecgrid.chunks_exact(self.num_columns).enumerate()
+                {
+                    kmp_search(needle, &failure, elements, |column| {
+                        labels[grid_row * offset_columns + column] = Some(id);
+                    });
+                }
+            }
+
+            // Find the pattern rows, in order, down every column of labels.
+            let failure = kmp_failure(&ids);
+            let mut column_labels = Vec::with_capacity(self.num_rows);
+            for column in 0..offset_columns {
+                column_labels.clear();
+                column_labels.extend(labels[column..].iter().step_by(offset_columns).copied());
+                kmp_search(&ids, &failure, &column_labels, |row| {
+                    matches[row * offset_columns + column] = true;
+                });
+            }
+        }
+        matches
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, matched)| matched)
+            .map(move |(index, _)| (index / offset_columns, index % offset_columns))
+    }
+
+    /// Returns the offset, as `(row, column)` of its top-left element, of
+    /// the first place in [row major order] where `pattern` matches the
+    /// vecgrid, treating [`None`] in the pattern as a wildcard that matches
+    /// any element. Returns [`None`] if the pattern matches nowhere. See
+    /// [`find_where_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let map = Vecgrid::from_rows(vec![
+    ///     vec!['#', '#', '#'],
+    ///     vec!['.', '.', '#'],
+    ///     vec!['#', '.', '#'],
+    /// ])?;
+    /// // A wall with floor below it, whatever is below that.
+    /// let pattern = Vecgrid::from_rows(vec![vec![Some('#')], vec![Some('.')], vec![None]])?;
+    /// assert_eq!(map.find_where(&pattern), Some((0, 0)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`find_where_all`]: struct.Vecgrid.html#method.find_where_all
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn find_where(&self, pattern: &Vecgrid<Option<T>>) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find_where_all(pattern).next()
+    }
+
+    /// Returns an [`Iterator`] over the offsets, as `(row, column)` of the
+    /// top-left element, of every place where `pattern` matches the
+    /// vecgrid, in [row major order]. [`None`] in the pattern is a wildcard
+    /// that matches any element, and [`Some`] matches an equal element.
+    /// Matches may overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 1], vec![3, 1, 4]])?;
+    /// let pattern = Vecgrid::from_rows(vec![vec![Some(1), None]])?;
+    /// let offsets = vecgrid.find_where_all(&pattern).collect::<Vec<_>>();
+    /// assert_eq!(offsets, vec![(0, 0), (1, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn find_where_all<'a>(
+        &'a self,
+        pattern: &'a Vecgrid<Option<T>>,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.subgrid_offsets(pattern, |row, pattern_row| {
+            row.iter()
+                .zip(pattern_row)
+                .all(|(element, cell)| cell.as_ref().is_none_or(|cell| cell == element))
+        })
+    }
+
+    /// Binary searches the given row, which must be sorted in ascending
+    /// order, for `value`, like [`slice::binary_search`]: the inner result
+    /// is `Ok` with the column of a matching element, or `Err` with the
+    /// column where `value` could be inserted to keep the row sorted.
+    ///
+    /// Returns an error if the row index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 3, 5], vec![2, 4, 6]])?;
+    /// assert_eq!(vecgrid.binary_search_row(0, &3)?, Ok(1));
+    /// assert_eq!(vecgrid.binary_search_row(1, &5)?, Err(2));
+    /// assert!(vecgrid.binary_search_row(2, &5).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`slice::binary_search`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search
+    pub fn binary_search_row(&self, row: usize, value: &T) -> Result<Result<usize, usize>, Error>
+    where
+        T: Ord,
+    {
+        if row >= self.num_rows {
+            return Err(self.indices_out_of_bounds(row, 0));
+        }
+        let start = row * self.num_columns;
+        Ok(self.vecgrid[start..start + self.num_columns].binary_search(value))
+    }
+
+    /// Binary searches the given column, which must be sorted in ascending
+    /// order from top to bottom, for `value`, like [`slice::binary_search`]:
+    /// the inner result is `Ok` with the row of a matching element, or
+    /// `Err` with the row where `value` could be inserted to keep the column
+    /// sorted.
+    ///
+    /// Returns an error if the column index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 3], vec![2, 4], vec![7, 8]])?;
+    /// assert_eq!(vecgrid.binary_search_column(0, &7)?, Ok(2));
+    /// assert_eq!(vecgrid.binary_search_column(1, &5)?, Err(2));
+    /// assert!(vecgrid.binary_search_column(2, &5).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`slice::binary_search`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search
+    pub fn binary_search_column(
+        &self,
+        column: usize,
+        value: &T,
+    ) -> Result<Result<usize, usize>, Error>
+    where
+        T: Ord,
+    {
+        if column >= self.num_columns {
+            return Err(self.indices_out_of_bounds(0, column));
+        }
+        let (mut low, mut high) = (0, self.num_rows);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.vecgrid[middle * self.num_columns + column].cmp(value) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Ok(Ok(middle)),
+            }
+        }
+        Ok(Err(low))
+    }
+
+    /// Returns the indices, as `(row, column)`, of an element equal to
+    /// `value` in a vecgrid whose rows and columns are all sorted in
+    /// ascending order, or [`None`] if there is none. This [saddleback
+    /// search] walks from the top right corner and looks at no more than
+    /// `num_rows + num_columns` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let table = Vecgrid::from_rows(vec![
+    ///     vec![1, 4, 7, 11],
+    ///     vec![2, 5, 8, 12],
+    ///     vec![3, 6, 9, 16],
+    /// ])?;
+    /// assert_eq!(table.saddleback_search(&6), Some((2, 1)));
+    /// assert_eq!(table.saddleback_search(&10), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [saddleback search]: https://en.wikipedia.org/wiki/Young_tableau
+    pub fn saddleback_search(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: Ord,
+    {
+        let (mut row, mut column) = (0, self.num_columns.checked_sub(1)?);
+        while row < self.num_rows {
+            match self.vecgrid[row * self.num_columns + column].cmp(value) {
+                Ordering::Less => row += 1,
+                Ordering::Greater => column = column.checked_sub(1)?,
+                Ordering::Equal => return Some((row, column)),
+            }
+        }
+        None
+    }
+
+    /// Returns the offsets at which every row of `pattern` matches the part
+    /// of the vecgrid it covers according to `row_matches`.
+    fn subgrid_offsets<'a, U, F>(
+        &'a self,
+        pattern: &'a Vecgrid<U>,
+        row_matches: F,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        F: Fn(&[T], &[U]) -> bool + 'a,
+    {
+        let (num_columns, pattern_columns) = (self.num_columns, pattern.num_columns);
+        let last_row = self.num_rows.checked_sub(pattern.num_rows);
+        let last_column = num_columns.checked_sub(pattern_columns);
+        last_row
+            .zip(last_column)
+            .into_iter()
+            .flat_map(|(last_row, last_column)| {
+                (0..=last_row)
+                    .flat_map(move |row| (0..=last_column).map(move |column| (row, column)))
+            })
+            .filter(move |&(row, column)| {
+                (0..pattern.num_rows).all(|pattern_row| {
+                    let start = (row + pattern_row) * num_columns + column;
+                    row_matches(
+                        &self.vecgrid[start..start + pattern_columns],
+                        &pattern.vecgrid[pattern_row * pattern_columns..][..pattern_columns],
+                    )
+                })
+            })
+    }
+}
+
+/// Returns the [KMP] failure function of `needle`: for every prefix, the
+/// length of its longest proper prefix that is also a suffix of it.
+///
+/// [KMP]: https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm
+fn kmp_failure<U: PartialEq>(needle: &[U]) -> Vec<usize> {
+    let mut failure = vec![0; needle.len()];
+    let mut matched = 0;
+    for index in 1..needle.len() {
+        while matched > 0 && needle[index] != needle[matched] {
+            matched = failure[matched - 1];
+        }
+        if needle[index] == needle[matched] {
+            matched += 1;
+        }
+        failure[index] = matched;
+    }
+    failure
+}
+
+/// Calls `found` with the start of every occurrence of the non-empty
+/// `needle` in `haystack`, in order, given the [`kmp_failure`] of `needle`.
+fn kmp_search<U: PartialEq, F: FnMut(usize)>(
+    needle: &[U],
+    failure: &[usize],
+    haystack: &[U],
+    mut found: F,
+) {
+    let mut matched = 0;
+    for (index, element) in haystack.iter().enumerate() {
+        while matched > 0 && *element != needle[matched] {
+            matched = failure[matched - 1];
+        }
+        if *element == needle[matched] {
+            matched += 1;
+        }
+        if matched == needle.len() {
+            found(index + 1 - matched);
+            matched = failure[matched - 1];
+        }
+    }
+}
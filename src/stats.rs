@@ -0,0 +1,287 @@
+//! Statistical summaries for numeric [`Vecgrid`]s, enabled with the `stats`
+//! feature.
+//!
+//! All summaries are computed in a single pass over the underlying row major
+//! buffer and are reported as [`f64`].
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`f64`]: https://doc.rust-lang.org/std/primitive.f64.html
+
+use crate::Vecgrid;
+
+/// Running mean and variance accumulator using Welford's algorithm.
+#[derive(Clone, Copy, Default)]
+struct Accumulator {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Accumulator {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn mean(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count == 0 {
+            f64::NAN
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+}
+
+impl<T> Vecgrid<T>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the arithmetic mean of all elements, or [`None`] if the
+    /// vecgrid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.mean(), Some(3.5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn mean(&self) -> Option<f64> {
+        let accumulator = self.accumulate();
+        (accumulator.count > 0).then(|| accumulator.mean())
+    }
+
+    /// Returns the population variance of all elements, or [`None`] if the
+    /// vecgrid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![2, 4], vec![4, 6]])?;
+    /// assert_eq!(vecgrid.variance(), Some(2.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn variance(&self) -> Option<f64> {
+        let accumulator = self.accumulate();
+        (accumulator.count > 0).then(|| accumulator.variance())
+    }
+
+    /// Returns the smallest and the largest element, or [`None`] if the
+    /// vecgrid is empty. Elements that cannot be compared (such as `NaN`) are
+    /// skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![3, 9, 1], vec![4, 7, 2]])?;
+    /// assert_eq!(vecgrid.minmax(), Some((1, 9)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn minmax(&self) -> Option<(T, T)>
+    where
+        T: PartialOrd,
+    {
+        let mut elements = self
+            .vecgrid
+            .iter()
+            .copied()
+            .filter(|element| element.partial_cmp(element).is_some());
+        let first = elements.next()?;
+        Some(elements.fold((first, first), |(min, max), element| {
+            match (element < min, element > max) {
+                (true, _) => (element, max),
+                (_, true) => (min, element),
+                _ => (min, max),
+            }
+        }))
+    }
+
+    /// Counts the elements falling into each of `bins` equally wide buckets
+    /// spanning the range from the smallest to the largest element. The
+    /// largest element is counted in the last bucket.
+    ///
+    /// Returns an empty [`Vec`] if `bins` is zero or the vecgrid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![0, 1, 2], vec![3, 4, 4]])?;
+    /// assert_eq!(vecgrid.histogram(2), vec![2, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn histogram(&self, bins: usize) -> Vec<usize> {
+        let (min, max) = self
+            .vecgrid
+            .iter()
+            .map(|&element| element.into())
+            .filter(|value: &f64| !value.is_nan())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        if bins == 0 || min > max {
+            return Vec::new();
+        }
+        let mut histogram = vec![0; bins];
+        let width = (max - min) / bins as f64;
+        for &element in &self.vecgrid {
+            let value: f64 = element.into();
+            if value.is_nan() {
+                continue;
+            }
+            let bin = if width > 0.0 {
+                (((value - min) / width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            histogram[bin] += 1;
+        }
+        histogram
+    }
+
+    /// Returns the arithmetic mean of every row. Rows without any elements
+    /// have a mean of `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.row_means(), vec![2.0, 5.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn row_means(&self) -> Vec<f64> {
+        self.row_accumulators()
+            .iter()
+            .map(Accumulator::mean)
+            .collect()
+    }
+
+    /// Returns the arithmetic mean of every column. Columns without any
+    /// elements have a mean of `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(vecgrid.column_means(), vec![2.5, 3.5, 4.5]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn column_means(&self) -> Vec<f64> {
+        self.column_accumulators()
+            .iter()
+            .map(Accumulator::mean)
+            .collect()
+    }
+
+    /// Returns the population variance of every row. Rows without any
+    /// elements have a variance of `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 3], vec![5, 5]])?;
+    /// assert_eq!(vecgrid.row_variances(), vec![1.0, 0.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn row_variances(&self) -> Vec<f64> {
+        self.row_accumulators()
+            .iter()
+            .map(Accumulator::variance)
+            .collect()
+    }
+
+    /// Returns the population variance of every column. Columns without any
+    /// elements have a variance of `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 3], vec![5, 3]])?;
+    /// assert_eq!(vecgrid.column_variances(), vec![4.0, 0.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn column_variances(&self) -> Vec<f64> {
+        self.column_accumulators()
+            .iter()
+            .map(Accumulator::variance)
+            .collect()
+    }
+
+    fn accumulate(&self) -> Accumulator {
+        let mut accumulator = Accumulator::default();
+        for &element in &self.vecgrid {
+            accumulator.push(element.into());
+        }
+        accumulator
+    }
+
+    fn row_accumulators(&self) -> Vec<Accumulator> {
+        let mut accumulators = vec![Accumulator::default(); self.num_rows];
+        if self.num_columns == 0 {
+            return accumulators;
+        }
+        for (accumulator, row) in accumulators
+            .iter_mut()
+            .zip(self.vecgrid.chunks(self.num_columns))
+        {
+            for &element in row {
+                accumulator.push(element.into());
+            }
+        }
+        accumulators
+    }
+
+    fn column_accumulators(&self) -> Vec<Accumulator> {
+        let mut accumulators = vec![Accumulator::default(); self.num_columns];
+        if self.num_columns == 0 {
+            return accumulators;
+        }
+        for row in self.vecgrid.chunks(self.num_columns) {
+            for (accumulator, &element) in accumulators.iter_mut().zip(row) {
+                accumulator.push(element.into());
+            }
+        }
+        accumulators
+    }
+}
@@ -0,0 +1,259 @@
+//! Stencil computations, such as convolutions, cellular automata and
+//! finite differences, that map every cell of a [`Vecgrid`] to a new value
+//! computed from the window of cells around it.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+
+/// How a [`CenterView`] reads cells of its window that are outside of the
+/// vecgrid.
+///
+/// [`CenterView`]: struct.CenterView.html
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EdgeMode {
+    /// Reads the nearest cell on the border instead.
+    Clamp,
+    /// Wraps around to the opposite side, as on a torus.
+    Wrap,
+}
+
+/// Read access to the square window of cells around one cell of a
+/// [`Vecgrid`], as passed to the closure of [`map_neighborhood`].
+///
+/// Cells are addressed by their `(row, column)` offset from the center,
+/// each between `-radius` and `radius`. Offsets that fall outside of the
+/// vecgrid are resolved according to the [`EdgeMode`].
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`map_neighborhood`]: struct.Vecgrid.html#method.map_neighborhood
+/// [`EdgeMode`]: enum.EdgeMode.html
+#[derive(Debug)]
+pub struct CenterView<'a, T> {
+    vecgrid: &'a Vecgrid<T>,
+    row: usize,
+    column: usize,
+    radius: usize,
+    edges: EdgeMode,
+}
+
+impl<T> Clone for CenterView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CenterView<'_, T> {}
+
+impl<'a, T> CenterView<'a, T> {
+    /// The row of the center cell in the vecgrid.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// The column of the center cell in the vecgrid.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The largest offset from the center in any direction.
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Returns a reference to the center cell.
+    pub fn center(&self) -> &'a T {
+        &self.vecgrid[(self.row, self.column)]
+    }
+
+    /// Returns a reference to the cell at the given offset from the center,
+    /// resolving offsets outside of the vecgrid according to the
+    /// [`EdgeMode`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either offset is larger than the radius.
+    ///
+    /// [`EdgeMode`]: enum.EdgeMode.html
+    pub fn get(&self, row_offset: isize, column_offset: isize) -> &'a T {
+        assert!(
+            row_offset.unsigned_abs() <= self.radius && column_offset.unsigned_abs() <= self.radius,
+            "offset {}, {} is larger than the radius {}",
+            row_offset,
+            column_offset,
+            self.radius
+        );
+        let row = resolve(self.row, row_offset, self.vecgrid.num_rows, self.edges);
+        let column = resolve(
+            self.column,
+            column_offset,
+            self.vecgrid.num_columns,
+            self.edges,
+        );
+        &self.vecgrid.vecgrid[row * self.vecgrid.num_columns + column]
+    }
+
+    /// Returns an [`Iterator`] over the offsets, as `(row, column)`, and
+    /// references to every cell of the window, including the center, in
+    /// [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &'a T)> + Clone {
+        let view = *self;
+        let radius = self.radius as isize;
+        (-radius..=radius).flat_map(move |row_offset| {
+            (-radius..=radius).map(move |column_offset| {
+                (
+                    (row_offset, column_offset),
+                    view.get(row_offset, column_offset),
+                )
+            })
+        })
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Creates a new vecgrid of the same dimensions by calling `f` with a
+    /// [`CenterView`] of the window of `radius` cells around every cell.
+    /// Cells of the window outside of the vecgrid are read according to
+    /// `edges`.
+    ///
+    /// The new values are written to a separate vecgrid, so `f` always sees
+    /// the original values of the neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{EdgeMode, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let life = Vecgrid::from_rows(vec![
+    ///     vec![false, true, false],
+    ///     vec![false, true, false],
+    ///     vec![false, true, false],
+    /// ])?;
+    /// let next = life.map_neighborhood(1, EdgeMode::Wrap, |view| {
+    ///     let alive = view.iter().filter(|&(offset, &cell)| offset != (0, 0) && cell).count();
+    ///     alive == 3 || (alive == 2 && *view.center())
+    /// });
+    /// assert!(next.elements_row_major_iter().all(|&cell| cell));
+    ///
+    /// let heights = Vecgrid::from_rows(vec![vec![1, 5, 3]])?;
+    /// let peaks = heights.map_neighborhood(1, EdgeMode::Clamp, |view| {
+    ///     view.iter().all(|(_, height)| height <= view.center())
+    /// });
+    /// assert_eq!(peaks.as_row_major(), vec![false, true, false]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CenterView`]: struct.CenterView.html
+    pub fn map_neighborhood<R, F>(&self, radius: usize, edges: EdgeMode, f: F) -> Vecgrid<R>
+    where
+        F: Fn(CenterView<'_, T>) -> R,
+    {
+        Vecgrid {
+            vecgrid: self
+                .indices_row_major()
+                .map(|(row, column)| {
+                    f(CenterView {
+                        vecgrid: self,
+                        row,
+                        column,
+                        radius,
+                        edges,
+                    })
+                })
+                .collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+macro_rules! impl_finite_differences {
+    ($($float:ty),*) => {
+        $(
+            impl Vecgrid<$float> {
+                /// Returns the rate of change along the columns at every
+                /// element, as the central difference `(right - left) / 2`.
+                /// Neighbors outside of the vecgrid are read according to
+                /// `edges`, so with [`EdgeMode::Clamp`] the border gets half
+                /// of the one-sided difference.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{EdgeMode, Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![vec![0.0, 1.0, 4.0, 9.0]])?;
+                /// let slope = heights.gradient_x(EdgeMode::Clamp);
+                /// assert_eq!(slope.as_row_major(), vec![0.5, 2.0, 4.0, 2.5]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [`EdgeMode::Clamp`]: enum.EdgeMode.html#variant.Clamp
+                pub fn gradient_x(&self, edges: EdgeMode) -> Vecgrid<$float> {
+                    self.map_neighborhood(1, edges, |view| (view.get(0, 1) - view.get(0, -1)) / 2.0)
+                }
+
+                /// Returns the rate of change along the rows at every
+                /// element, as the central difference `(below - above) / 2`.
+                /// Neighbors outside of the vecgrid are read according to
+                /// `edges`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{EdgeMode, Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![vec![0.0], vec![2.0], vec![4.0]])?;
+                /// let slope = heights.gradient_y(EdgeMode::Wrap);
+                /// assert_eq!(slope.as_row_major(), vec![-1.0, 2.0, -1.0]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn gradient_y(&self, edges: EdgeMode) -> Vecgrid<$float> {
+                    self.map_neighborhood(1, edges, |view| (view.get(1, 0) - view.get(-1, 0)) / 2.0)
+                }
+
+                /// Returns the discrete Laplacian at every element: the sum
+                /// of its four orthogonal neighbors minus four times the
+                /// element. Neighbors outside of the vecgrid are read
+                /// according to `edges`.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{EdgeMode, Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let mut heat: Vecgrid<f64> = Vecgrid::filled_with(0.0, 3, 3);
+                /// heat[(1, 1)] = 1.0;
+                /// let laplacian = heat.laplacian(EdgeMode::Clamp);
+                /// assert_eq!(laplacian[(1, 1)], -4.0);
+                /// assert_eq!(laplacian[(0, 1)], 1.0);
+                /// assert_eq!(laplacian[(0, 0)], 0.0);
+                /// # Ok(())
+                /// # }
+                /// ```
+                pub fn laplacian(&self, edges: EdgeMode) -> Vecgrid<$float> {
+                    self.map_neighborhood(1, edges, |view| {
+                        view.get(-1, 0) + view.get(1, 0) + view.get(0, -1) + view.get(0, 1)
+                            - 4.0 * view.center()
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_finite_differences!(f32, f64);
+
+/// Resolves `index + offset` into an index below `len`.
+fn resolve(index: usize, offset: isize, len: usize, edges: EdgeMode) -> usize {
+    match edges {
+        EdgeMode::Clamp => index.saturating_add_signed(offset).min(len - 1),
+        EdgeMode::Wrap => (index as isize + offset).rem_euclid(len as isize) as usize,
+    }
+}
@@ -0,0 +1,152 @@
+//! Writing and reading a [`Vecgrid`] one row at a time, so that very large
+//! grids can be saved and loaded without a second copy of all elements in
+//! memory.
+//!
+//! The format starts with the magic bytes `VGRS`, followed by the number of
+//! rows and the number of columns as little endian `u64`s. Every row follows
+//! in order as its length in bytes, again a little endian `u64`, and the
+//! bytes the caller encoded the row into. The same grid and encoding always
+//! produce the same bytes.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::Vecgrid;
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 4] = b"VGRS";
+
+/// The most bytes reserved for the elements before any row has been read.
+pub(crate) const MAX_INITIAL_RESERVATION: usize = 64 * 1024;
+
+impl<T> Vecgrid<T> {
+    /// Writes the vecgrid to `writer` one row at a time, with
+    /// `encode_row` appending the bytes of each row to the buffer it is
+    /// given. Only one encoded row is held in memory at a time. The
+    /// vecgrid can be read back with [`deserialize_rows_from`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// # fn main() -> std::io::Result<()> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1u16, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let mut bytes = Vec::new();
+    /// vecgrid.serialize_rows_to(&mut bytes, |row, buffer| {
+    ///     buffer.extend(row.iter().flat_map(|element| element.to_le_bytes()));
+    /// })?;
+    /// let read = Vecgrid::deserialize_rows_from(bytes.as_slice(), |bytes| {
+    ///     Ok(bytes.chunks(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect())
+    /// })?;
+    /// assert_eq!(read, vecgrid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`deserialize_rows_from`]: struct.Vecgrid.html#method.deserialize_rows_from
+    pub fn serialize_rows_to<W, F>(&self, mut writer: W, mut encode_row: F) -> io::Result<()>
+    where
+        W: Write,
+        F: FnMut(&[T], &mut Vec<u8>),
+    {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(self.num_rows as u64).to_le_bytes())?;
+        writer.write_all(&(self.num_columns as u64).to_le_bytes())?;
+        let mut buffer = Vec::new();
+        for row in 0..self.num_rows {
+            buffer.clear();
+            encode_row(
+                &self.vecgrid[row * self.num_columns..][..self.num_columns],
+                &mut buffer,
+            );
+            writer.write_all(&(buffer.len() as u64).to_le_bytes())?;
+            writer.write_all(&buffer)?;
+        }
+        writer.flush()
+    }
+
+    /// Reads a vecgrid written by [`serialize_rows_to`] from `reader` one row
+    /// at a time, with `decode_row` turning the bytes of each row back into
+    /// its elements. Only one encoded row is held in memory at a time.
+    ///
+    /// Returns an error of kind [`InvalidData`] if the data does not start
+    /// with the header, or if `decode_row` returns a row of the wrong
+    /// length, and any error of `reader` or `decode_row`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Vecgrid;
+    /// # fn main() -> std::io::Result<()> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+    /// let mut bytes = Vec::new();
+    /// vecgrid.serialize_rows_to(&mut bytes, |row, buffer| {
+    ///     buffer.extend(row.iter().map(|&cell| cell as u8));
+    /// })?;
+    /// let read = Vecgrid::deserialize_rows_from(bytes.as_slice(), |bytes| {
+    ///     Ok(bytes.iter().map(|&byte| byte != 0).collect())
+    /// })?;
+    /// assert_eq!(read, vecgrid);
+    ///
+    /// let error = Vecgrid::deserialize_rows_from(&bytes[1..], |_| Ok(vec![true]));
+    /// assert_eq!(error.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`serialize_rows_to`]: struct.Vecgrid.html#method.serialize_rows_to
+    /// [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn deserialize_rows_from<R, F>(mut reader: R, mut decode_row: F) -> io::Result<Self>
+    where
+        R: Read,
+        F: FnMut(&[u8]) -> io::Result<Vec<T>>,
+    {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("missing vecgrid row stream header"));
+        }
+        let num_rows = read_length(&mut reader)?;
+        let num_columns = read_length(&mut reader)?;
+        let num_elements = num_rows
+            .checked_mul(num_columns)
+            .ok_or_else(|| invalid_data("too many elements"))?;
+        // The header is not trusted: reserve no more than a small buffer up
+        // front and let the buffer grow as rows actually arrive.
+        let initial_capacity = num_elements.min(MAX_INITIAL_RESERVATION / size_of::<T>().max(1));
+        let mut vecgrid = Vec::with_capacity(initial_capacity);
+        let mut buffer = Vec::new();
+        for row in 0..num_rows {
+            let len = read_length(&mut reader)?;
+            buffer.clear();
+            reader.by_ref().take(len as u64).read_to_end(&mut buffer)?;
+            if buffer.len() != len {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+            let elements = decode_row(&buffer)?;
+            if elements.len() != num_columns {
+                return Err(invalid_data(&format!(
+                    "row {} has {} elements instead of {}",
+                    row,
+                    elements.len(),
+                    num_columns
+                )));
+            }
+            vecgrid.extend(elements);
+        }
+        Ok(Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns,
+        })
+    }
+}
+
+fn read_length<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    usize::try_from(u64::from_le_bytes(bytes)).map_err(|_| invalid_data("length does not fit"))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
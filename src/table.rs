@@ -0,0 +1,211 @@
+//! A [`Vecgrid`] wrapper with named columns, for light tabular data.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+use std::ops::{Deref, Index};
+
+/// A [`Vecgrid`] with a header naming each column, so columns can be looked
+/// up by name like in a spreadsheet or a CSV file with a header line.
+///
+/// Read access to the elements is available through [`Deref`]; changes that
+/// add columns go through the table so that every column keeps a header. If
+/// several columns have the same header, lookups by name find the first.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Table, Vecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let people = Vecgrid::from_rows(vec![vec![31, 180], vec![27, 165]])?;
+/// let mut table = Table::new(vec!["age".to_string(), "height".to_string()], people)?;
+/// assert_eq!(table.column_by_name("age").unwrap().sum::<i32>(), 58);
+/// assert_eq!(table[(1, "height")], 165);
+///
+/// table.insert_column_named(0, "id", vec![1, 2])?;
+/// let first = table.row_iter(0)?.collect::<Vec<_>>();
+/// assert_eq!(first, vec![("id", &1), ("age", &31), ("height", &180)]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Table<T> {
+    headers: Vec<String>,
+    vecgrid: Vecgrid<T>,
+}
+
+impl<T> Table<T> {
+    /// Creates a table from the column `headers` and the `vecgrid` holding
+    /// the elements. Returns an error if the number of headers is not the
+    /// number of columns.
+    pub fn new(headers: Vec<String>, vecgrid: Vecgrid<T>) -> Result<Self, Error> {
+        if headers.len() != vecgrid.num_columns() {
+            return Err(Error::dimension_mismatch(
+                vecgrid.num_columns(),
+                headers.len(),
+            ));
+        }
+        Ok(Table { headers, vecgrid })
+    }
+
+    /// Splits the table into its headers and its [`Vecgrid`].
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn into_parts(self) -> (Vec<String>, Vecgrid<T>) {
+        (self.headers, self.vecgrid)
+    }
+
+    /// Returns the column headers, from left to right.
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Returns the index of the first column named `name`, or [`None`] if
+    /// there is no such column.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.headers.iter().position(|header| header == name)
+    }
+
+    /// Returns an [`Iterator`] over references to the elements of the
+    /// column named `name`, from top to bottom, or [`None`] if there is no
+    /// such column.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn column_by_name(&self, name: &str) -> Option<impl DoubleEndedIterator<Item = &T>> {
+        self.vecgrid.column_iter(self.column_index(name)?).ok()
+    }
+
+    /// Returns an [`Iterator`] over mutable references to the elements of
+    /// the column named `name`, from top to bottom, or [`None`] if there is
+    /// no such column.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn column_by_name_mut(
+        &mut self,
+        name: &str,
+    ) -> Option<impl DoubleEndedIterator<Item = &mut T>> {
+        let column = self.column_index(name)?;
+        self.vecgrid.column_iter_mut(column).ok()
+    }
+
+    /// Returns a reference to the element in the given `row` of the column
+    /// named `name`, or [`None`] if there is no such row or column.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, name: &str) -> Option<&T> {
+        self.vecgrid.get(row, self.column_index(name)?)
+    }
+
+    /// Returns a mutable reference to the element in the given `row` of the
+    /// column named `name`, or [`None`] if there is no such row or column.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, name: &str) -> Option<&mut T> {
+        let column = self.column_index(name)?;
+        self.vecgrid.get_mut(row, column)
+    }
+
+    /// Returns an [`Iterator`] over the elements of the given row, each
+    /// paired with the header of its column. Returns an error if the index
+    /// is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter(&self, row_index: usize) -> Result<impl Iterator<Item = (&str, &T)>, Error> {
+        let row = self.vecgrid.row_iter(row_index)?;
+        Ok(self.headers.iter().map(String::as_str).zip(row))
+    }
+
+    /// Returns an [`Iterator`] over all rows, from top to bottom, each of
+    /// which is an [`Iterator`] over its elements paired with the header of
+    /// their column, as returned by [`row_iter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`row_iter`]: struct.Table.html#method.row_iter
+    pub fn rows_iter(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = (&str, &T)>> {
+        (0..self.vecgrid.num_rows()).map(move |row_index| {
+            self.row_iter(row_index)
+                .expect("rows_iter should never fail")
+        })
+    }
+
+    /// Inserts a column named `name` with the given elements, from top to
+    /// bottom, so that it becomes the column at index `at`. Pass the number
+    /// of columns as `at` to add the column on the right.
+    ///
+    /// Returns an error if `at` is greater than the number of columns, or if
+    /// the column does not have one element per row. A table without
+    /// columns takes the number of rows from the column.
+    pub fn insert_column_named(
+        &mut self,
+        at: usize,
+        name: impl Into<String>,
+        column: Vec<T>,
+    ) -> Result<(), Error> {
+        let num_columns = self.vecgrid.num_columns;
+        if at > num_columns {
+            return Err(Error::IndexOutOfBounds {
+                index: at,
+                len: num_columns + 1,
+            });
+        }
+        let num_rows = if num_columns == 0 {
+            column.len()
+        } else {
+            self.vecgrid.num_rows
+        };
+        if column.len() != num_rows {
+            return Err(Error::dimension_mismatch(num_rows, column.len()));
+        }
+        let mut elements = std::mem::take(&mut self.vecgrid.vecgrid).into_iter();
+        let mut vecgrid = Vec::with_capacity(num_rows * (num_columns + 1));
+        for element in column {
+            vecgrid.extend(elements.by_ref().take(at));
+            vecgrid.push(element);
+            vecgrid.extend(elements.by_ref().take(num_columns - at));
+        }
+        self.vecgrid = Vecgrid {
+            vecgrid,
+            num_rows,
+            num_columns: num_columns + 1,
+        };
+        self.headers.insert(at, name.into());
+        Ok(())
+    }
+
+    /// Appends a row of elements at the bottom, in the order of the
+    /// headers. Returns an error if the row does not have one element per
+    /// column.
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), Error> {
+        self.vecgrid.append_rows(vec![row])
+    }
+}
+
+impl<T> Deref for Table<T> {
+    type Target = Vecgrid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vecgrid
+    }
+}
+
+impl<T> Index<(usize, &str)> for Table<T> {
+    type Output = T;
+
+    /// Returns the element in the given row of the column with the given
+    /// name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such row or column.
+    fn index(&self, (row, name): (usize, &str)) -> &Self::Output {
+        self.get(row, name)
+            .unwrap_or_else(|| panic!("Index row {}, column {:?} out of bounds", row, name))
+    }
+}
@@ -0,0 +1,178 @@
+//! Rendering a [`Vecgrid`] for a terminal with [ANSI escape codes], enabled
+//! with the `term` feature.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [ANSI escape codes]: https://en.wikipedia.org/wiki/ANSI_escape_code
+
+use crate::Vecgrid;
+use std::fmt::Write;
+
+/// A terminal color.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Color {
+    /// Black.
+    Black,
+    /// Red.
+    Red,
+    /// Green.
+    Green,
+    /// Yellow.
+    Yellow,
+    /// Blue.
+    Blue,
+    /// Magenta.
+    Magenta,
+    /// Cyan.
+    Cyan,
+    /// White.
+    White,
+    /// A 24-bit color given as red, green and blue, for terminals that
+    /// support true color.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn write_code(self, output: &mut String, base: u8) {
+        let _ = match self {
+            Color::Rgb(red, green, blue) => {
+                write!(output, "{};2;{};{};{}", base + 8, red, green, blue)
+            }
+            named => write!(output, "{}", base + named.offset()),
+        };
+    }
+
+    fn offset(self) -> u8 {
+        match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White | Color::Rgb(..) => 7,
+        }
+    }
+}
+
+/// How a single cell is drawn by [`to_ansi_string`]: a symbol and optional
+/// foreground and background colors.
+///
+/// [`to_ansi_string`]: struct.Vecgrid.html#method.to_ansi_string
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CellStyle {
+    /// The character drawn for the cell.
+    pub symbol: char,
+    /// The color of the symbol, or [`None`] for the terminal default.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub foreground: Option<Color>,
+    /// The color behind the symbol, or [`None`] for the terminal default.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub background: Option<Color>,
+}
+
+impl CellStyle {
+    /// A cell drawn as `symbol` in the terminal's default colors.
+    pub fn new(symbol: char) -> Self {
+        CellStyle {
+            symbol,
+            foreground: None,
+            background: None,
+        }
+    }
+
+    /// Sets the color of the symbol.
+    pub fn foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Sets the color behind the symbol.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+}
+
+impl From<char> for CellStyle {
+    fn from(symbol: char) -> Self {
+        CellStyle::new(symbol)
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Renders the vecgrid as a [`String`] of rows separated by newlines,
+    /// drawing every element as described by the [`CellStyle`] that `style`
+    /// returns for it. Colors are set with [ANSI escape codes] and reset
+    /// after every colored cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{CellStyle, Color, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Vecgrid::from_rows(vec![vec![true, false], vec![false, true]])?;
+    /// let plain = board.to_ansi_string(|&alive| CellStyle::new(if alive { '#' } else { '.' }));
+    /// assert_eq!(plain, "#.\n.#");
+    ///
+    /// let colored = board.to_ansi_string(|&alive| match alive {
+    ///     true => CellStyle::new('#').foreground(Color::Green),
+    ///     false => CellStyle::new('.'),
+    /// });
+    /// assert_eq!(colored, "\x1b[32m#\x1b[0m.\n.\x1b[32m#\x1b[0m");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`CellStyle`]: struct.CellStyle.html
+    /// [ANSI escape codes]: https://en.wikipedia.org/wiki/ANSI_escape_code
+    pub fn to_ansi_string<F>(&self, style: F) -> String
+    where
+        F: Fn(&T) -> CellStyle,
+    {
+        let mut output = String::new();
+        for (row_index, row) in self.vecgrid.chunks(self.num_columns.max(1)).enumerate() {
+            if row_index > 0 {
+                output.push('\n');
+            }
+            for element in row {
+                write_cell(&mut output, style(element));
+            }
+        }
+        output
+    }
+
+    /// Prints the vecgrid to standard output as rendered by
+    /// [`to_ansi_string`], followed by a newline.
+    ///
+    /// [`to_ansi_string`]: struct.Vecgrid.html#method.to_ansi_string
+    pub fn render_to_terminal<F>(&self, style: F)
+    where
+        F: Fn(&T) -> CellStyle,
+    {
+        println!("{}", self.to_ansi_string(style));
+    }
+}
+
+fn write_cell(output: &mut String, style: CellStyle) {
+    if style.foreground.is_none() && style.background.is_none() {
+        output.push(style.symbol);
+        return;
+    }
+    output.push_str("\x1b[");
+    if let Some(color) = style.foreground {
+        color.write_code(output, 30);
+    }
+    if let Some(color) = style.background {
+        if style.foreground.is_some() {
+            output.push(';');
+        }
+        color.write_code(output, 40);
+    }
+    output.push('m');
+    output.push(style.symbol);
+    output.push_str("\x1b[0m");
+}
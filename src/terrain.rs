@@ -0,0 +1,117 @@
+//! Water flow over heightmaps stored in a [`Vecgrid`], for procedural
+//! terrain such as rivers and erosion.
+//!
+//! Water flows from every cell to its steepest downhill neighbor among the
+//! eight surrounding cells (the D8 method), where the slope to a diagonal
+//! neighbor is the height difference divided by `√2`.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Neighborhood, Vecgrid};
+
+macro_rules! impl_terrain {
+    ($($float:ty),*) => {
+        $(
+            impl Vecgrid<$float> {
+                /// Returns, for every cell of the heightmap, the indices of
+                /// the neighbor that water flows to, or [`None`] for pits and
+                /// flats without a lower neighbor.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![
+                ///     vec![5.0, 4.0, 3.0],
+                ///     vec![4.0, 3.0, 2.0],
+                ///     vec![3.0, 2.0, 0.0],
+                /// ])?;
+                /// let directions = heights.downhill_direction_grid();
+                /// assert_eq!(directions[(0, 0)], Some((1, 1)));
+                /// assert_eq!(directions[(0, 2)], Some((1, 2)));
+                /// assert_eq!(directions[(2, 2)], None);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+                pub fn downhill_direction_grid(&self) -> Vecgrid<Option<(usize, usize)>> {
+                    Vecgrid {
+                        vecgrid: (0..self.vecgrid.len())
+                            .map(|index| self.downhill_neighbor(index))
+                            .collect(),
+                        num_rows: self.num_rows,
+                        num_columns: self.num_columns,
+                    }
+                }
+
+                /// Returns, for every cell of the heightmap, the number of
+                /// cells whose water flows through it, including itself,
+                /// following [`downhill_direction_grid`]. Rivers are the
+                /// cells with a large accumulation.
+                ///
+                /// Cells are processed from the highest to the lowest, so
+                /// every cell passes on its total once, in `O(n log n)` time
+                /// for `n` cells.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![
+                ///     vec![3.0, 1.0, 3.0],
+                ///     vec![3.0, 0.5, 3.0],
+                ///     vec![3.0, 0.0, 3.0],
+                /// ])?;
+                /// let flow = heights.flow_accumulation();
+                /// assert_eq!(flow.column_iter(1)?.copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [`downhill_direction_grid`]: struct.Vecgrid.html#method.downhill_direction_grid
+                pub fn flow_accumulation(&self) -> Vecgrid<u32> {
+                    let mut order = (0..self.vecgrid.len()).collect::<Vec<_>>();
+                    order.sort_by(|&a, &b| self.vecgrid[b].total_cmp(&self.vecgrid[a]));
+                    let mut flow = vec![1; self.vecgrid.len()];
+                    for index in order {
+                        if let Some((row, column)) = self.downhill_neighbor(index) {
+                            flow[row * self.num_columns + column] += flow[index];
+                        }
+                    }
+                    Vecgrid {
+                        vecgrid: flow,
+                        num_rows: self.num_rows,
+                        num_columns: self.num_columns,
+                    }
+                }
+
+                fn downhill_neighbor(&self, index: usize) -> Option<(usize, usize)> {
+                    let (row, column) = self.position(index);
+                    let height = self.vecgrid[index];
+                    Neighborhood::Eight
+                        .neighbors(row, column, self.num_rows, self.num_columns)
+                        .flatten()
+                        .fold(None, |steepest: Option<((usize, usize), $float)>, neighbor| {
+                            let distance = if neighbor.0 != row && neighbor.1 != column {
+                                std::f64::consts::SQRT_2 as $float
+                            } else {
+                                1.0
+                            };
+                            let slope = (height - self[neighbor]) / distance;
+                            match steepest {
+                                Some((_, steepest_slope)) if steepest_slope >= slope => steepest,
+                                _ if slope > 0.0 => Some((neighbor, slope)),
+                                _ => steepest,
+                            }
+                        })
+                        .map(|(neighbor, _)| neighbor)
+                }
+            }
+        )*
+    };
+}
+
+impl_terrain!(f32, f64);
@@ -0,0 +1,209 @@
+//! A [`Vecgrid`] wrapper that keeps track of the region modified through it.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, GridIndex, Vecgrid};
+use std::ops::{Deref, Index, IndexMut, Range};
+
+/// A wrapper around a [`Vecgrid`] that records which cells were modified
+/// through it, so renderers can redraw only the smallest rectangle containing
+/// every change.
+///
+/// Read access is available through [`Deref`]; all mutation goes through the
+/// wrapper so that no change goes unnoticed.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{Vecgrid, TrackedVecgrid, Error};
+/// # fn main() -> Result<(), Error> {
+/// let mut tracked = TrackedVecgrid::new(Vecgrid::filled_with(0, 4, 4));
+/// tracked.set(1, 2, 5)?;
+/// tracked[(2, 1)] = 7;
+/// assert_eq!(tracked.take_dirty_region(), Some((1..3, 1..3)));
+/// assert_eq!(tracked.take_dirty_region(), None);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TrackedVecgrid<T> {
+    vecgrid: Vecgrid<T>,
+    dirty: Option<DirtyRegion>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct DirtyRegion {
+    rows: (usize, usize),
+    columns: (usize, usize),
+}
+
+impl<T> TrackedVecgrid<T> {
+    /// Wraps `vecgrid`, starting with no dirty region.
+    pub fn new(vecgrid: Vecgrid<T>) -> Self {
+        TrackedVecgrid {
+            vecgrid,
+            dirty: None,
+        }
+    }
+
+    /// Unwraps the underlying [`Vecgrid`], discarding the dirty region.
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn into_inner(self) -> Vecgrid<T> {
+        self.vecgrid
+    }
+
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column`, marking the cell as dirty. Returns [`None`] if the index is
+    /// out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        let element = self.vecgrid.get_mut(row, column)?;
+        extend(&mut self.dirty, row..row + 1, column..column + 1);
+        Some(element)
+    }
+
+    /// Changes the element at given `row` and `column` to `element`, marking
+    /// the cell as dirty. Returns [`Ok(())`] if the indices were in bounds and
+    /// returns an [`Err`] otherwise.
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
+        self.vecgrid.set(row, column, element)?;
+        extend(&mut self.dirty, row..row + 1, column..column + 1);
+        Ok(())
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// given row, marking the whole row as dirty. Returns an error if the
+    /// index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter_mut(
+        &mut self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        let num_columns = self.vecgrid.num_columns();
+        let row_iter = self.vecgrid.row_iter_mut(row_index)?;
+        extend(&mut self.dirty, row_index..row_index + 1, 0..num_columns);
+        Ok(row_iter)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// given column, marking the whole column as dirty. Returns an error if
+    /// the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_iter_mut(
+        &mut self,
+        column_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        let num_rows = self.vecgrid.num_rows();
+        let column_iter = self.vecgrid.column_iter_mut(column_index)?;
+        extend(&mut self.dirty, 0..num_rows, column_index..column_index + 1);
+        Ok(column_iter)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in
+    /// [row major order], marking the whole vecgrid as dirty.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.mark_all_dirty();
+        self.vecgrid.elements_row_major_iter_mut()
+    }
+
+    /// Marks the given region as dirty without changing any element.
+    pub fn mark_dirty(&mut self, rows: Range<usize>, columns: Range<usize>) {
+        extend(&mut self.dirty, rows, columns);
+    }
+
+    /// Marks the whole vecgrid as dirty without changing any element.
+    pub fn mark_all_dirty(&mut self) {
+        let (num_rows, num_columns) = (self.vecgrid.num_rows(), self.vecgrid.num_columns());
+        extend(&mut self.dirty, 0..num_rows, 0..num_columns);
+    }
+
+    /// Returns the smallest region, as `(rows, columns)`, containing every
+    /// cell modified since the last call, and resets the tracking. Returns
+    /// [`None`] if nothing was modified.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn take_dirty_region(&mut self) -> Option<(Range<usize>, Range<usize>)> {
+        self.dirty
+            .take()
+            .map(|dirty| (dirty.rows.0..dirty.rows.1, dirty.columns.0..dirty.columns.1))
+    }
+}
+
+fn extend(dirty: &mut Option<DirtyRegion>, rows: Range<usize>, columns: Range<usize>) {
+    if rows.is_empty() || columns.is_empty() {
+        return;
+    }
+    *dirty = Some(match *dirty {
+        None => DirtyRegion {
+            rows: (rows.start, rows.end),
+            columns: (columns.start, columns.end),
+        },
+        Some(region) => DirtyRegion {
+            rows: (region.rows.0.min(rows.start), region.rows.1.max(rows.end)),
+            columns: (
+                region.columns.0.min(columns.start),
+                region.columns.1.max(columns.end),
+            ),
+        },
+    });
+}
+
+impl<T> From<Vecgrid<T>> for TrackedVecgrid<T> {
+    fn from(vecgrid: Vecgrid<T>) -> Self {
+        TrackedVecgrid::new(vecgrid)
+    }
+}
+
+impl<T> Deref for TrackedVecgrid<T> {
+    type Target = Vecgrid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.vecgrid
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for TrackedVecgrid<T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)` or
+    /// any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        &self.vecgrid[index]
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for TrackedVecgrid<T> {
+    /// Returns a mutable version of the element at the given indices, given
+    /// as `(row, column)` or any other [`GridIndex`], marking the cell as
+    /// dirty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
@@ -0,0 +1,128 @@
+//! Transactional editing of a [`Vecgrid`] with cheap rollback.
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, Vecgrid};
+
+/// A guard over a mutably borrowed [`Vecgrid`] that records the previous
+/// value of every element changed through it, so the changes can be undone
+/// without cloning the whole vecgrid.
+///
+/// Changes are written to the vecgrid immediately. Calling [`commit`] keeps
+/// them, while calling [`rollback`] or dropping the guard restores the
+/// previous values in reverse order.
+///
+/// Created by [`Vecgrid::transaction`].
+///
+/// [`Vecgrid`]: struct.Vecgrid.html
+/// [`commit`]: struct.Transaction.html#method.commit
+/// [`rollback`]: struct.Transaction.html#method.rollback
+/// [`Vecgrid::transaction`]: struct.Vecgrid.html#method.transaction
+#[derive(Debug)]
+pub struct Transaction<'a, T> {
+    vecgrid: &'a mut Vecgrid<T>,
+    log: Vec<(usize, T)>,
+}
+
+impl<T> Vecgrid<T> {
+    /// Starts a [`Transaction`] that records every change made through it so
+    /// that the changes can be rolled back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(0, 2, 2);
+    ///
+    /// let mut transaction = vecgrid.transaction();
+    /// transaction.set(0, 0, 1)?;
+    /// transaction.set(1, 1, 2)?;
+    /// transaction.commit();
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 0], vec![0, 2]]);
+    ///
+    /// let mut transaction = vecgrid.transaction();
+    /// transaction.set(0, 1, 3)?;
+    /// transaction.rollback();
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 0], vec![0, 2]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Transaction`]: struct.Transaction.html
+    pub fn transaction(&mut self) -> Transaction<'_, T> {
+        Transaction {
+            vecgrid: self,
+            log: Vec::new(),
+        }
+    }
+}
+
+impl<'a, T> Transaction<'a, T> {
+    /// Returns a reference to the element at the given `row` and `column`,
+    /// including changes made in this transaction. Returns [`None`] if the
+    /// index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.vecgrid.get(row, column)
+    }
+
+    /// Changes the element at given `row` and `column` to `element`,
+    /// remembering the previous element. Returns [`Ok(())`] if the indices
+    /// were in bounds and returns an [`Err`] otherwise.
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
+        let index = self
+            .vecgrid
+            .get_index(row, column)
+            .ok_or_else(|| self.vecgrid.indices_out_of_bounds(row, column))?;
+        self.replace(index, element);
+        Ok(())
+    }
+
+    /// Changes the element at the given `index` to `element`, in row major
+    /// order, remembering the previous element. Returns [`Ok(())`] if the
+    /// index is in bounds and returns an [`Err`] otherwise.
+    ///
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn set_row_major(&mut self, index: usize, element: T) -> Result<(), Error> {
+        if index >= self.vecgrid.num_elements() {
+            return Err(self.vecgrid.index_out_of_bounds(index));
+        }
+        self.replace(index, element);
+        Ok(())
+    }
+
+    /// The number of changes recorded so far.
+    pub fn num_changes(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Keeps all changes made in this transaction.
+    pub fn commit(mut self) {
+        self.log.clear();
+    }
+
+    /// Undoes all changes made in this transaction, restoring the elements
+    /// as they were when the transaction started.
+    pub fn rollback(self) {
+        // Dropping the guard restores the recorded elements.
+    }
+
+    fn replace(&mut self, index: usize, element: T) {
+        let previous = std::mem::replace(&mut self.vecgrid.vecgrid[index], element);
+        self.log.push((index, previous));
+    }
+}
+
+impl<'a, T> Drop for Transaction<'a, T> {
+    fn drop(&mut self) {
+        while let Some((index, previous)) = self.log.pop() {
+            self.vecgrid.vecgrid[index] = previous;
+        }
+    }
+}
@@ -0,0 +1,490 @@
+//! Traversal orders other than row and column major order: an inward
+//! spiral, a boustrophedon (snake) order that reverses every other row,
+//! strided traversal that skips rows and columns, the two colors of a
+//! checkerboard, and the interior and border of the vecgrid.
+
+use crate::Vecgrid;
+
+/// One color of a checkerboard: the cells whose sum of row and column is
+/// even or odd, as used by red-black update schemes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Parity {
+    /// The cells where `row + column` is even, including `(0, 0)`.
+    Even,
+    /// The cells where `row + column` is odd.
+    Odd,
+}
+
+impl Parity {
+    /// Returns the parity of the cell at `row` and `column`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::Parity;
+    /// assert_eq!(Parity::of(2, 3), Parity::Odd);
+    /// assert_eq!(Parity::of(1, 1), Parity::Even);
+    /// ```
+    pub fn of(row: usize, column: usize) -> Self {
+        if (row + column).is_multiple_of(2) {
+            Parity::Even
+        } else {
+            Parity::Odd
+        }
+    }
+
+    /// The first column with this parity in the given row.
+    fn first_column(self, row: usize) -> usize {
+        (row + (self == Parity::Odd) as usize) % 2
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Returns the indices of the vecgrid in clockwise spiral order, from
+    /// the top-left corner inwards. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let vecgrid = Vecgrid::filled_with(0, 3, 3);
+    /// assert_eq!(
+    ///     vecgrid.indices_spiral().collect::<Vec<_>>(),
+    ///     vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0), (1, 0), (1, 1)]
+    /// );
+    /// ```
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn indices_spiral(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        let num_layers = num_rows.min(num_columns).div_ceil(2);
+        (0..num_layers).flat_map(move |layer| ring_indices(num_rows, num_columns, layer))
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in clockwise
+    /// spiral order, from the top-left corner inwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let spiral = vecgrid.spiral_iter().copied().collect::<Vec<_>>();
+    /// assert_eq!(spiral, vec![1, 2, 3, 6, 5, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn spiral_iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
+        self.indices_spiral()
+            .map(move |(row, column)| &self.vecgrid[row * self.num_columns + column])
+    }
+
+    /// Returns the indices of the vecgrid in boustrophedon order: the rows
+    /// from top to bottom, with the even rows from left to right and the
+    /// odd rows from right to left. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// let vecgrid = Vecgrid::filled_with(0, 2, 3);
+    /// assert_eq!(
+    ///     vecgrid.indices_boustrophedon().collect::<Vec<_>>(),
+    ///     vec![(0, 0), (0, 1), (0, 2), (1, 2), (1, 1), (1, 0)]
+    /// );
+    /// ```
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn indices_boustrophedon(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
+        let num_columns = self.num_columns;
+        (0..self.num_rows).flat_map(move |row| {
+            (0..num_columns).map(move |step| {
+                let column = if row % 2 == 0 {
+                    step
+                } else {
+                    num_columns - 1 - step
+                };
+                (row, column)
+            })
+        })
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in
+    /// boustrophedon order, see [`indices_boustrophedon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let snake = vecgrid.boustrophedon_iter().copied().collect::<Vec<_>>();
+    /// assert_eq!(snake, vec![1, 2, 3, 6, 5, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`indices_boustrophedon`]: struct.Vecgrid.html#method.indices_boustrophedon
+    pub fn boustrophedon_iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
+        self.indices_boustrophedon()
+            .map(move |(row, column)| &self.vecgrid[row * self.num_columns + column])
+    }
+
+    /// Returns an [`Iterator`] over the indices and references to every
+    /// element whose row is a multiple of `row_step` and whose column is a
+    /// multiple of `column_step`, in [row major order]. Each index is a
+    /// tuple of [`usize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either step is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let image = Vecgrid::filled_by_row_major(|| 1, 4, 5);
+    /// let preview = image.strided_iter(2, 2).map(|(index, _)| index).collect::<Vec<_>>();
+    /// assert_eq!(preview, vec![(0, 0), (0, 2), (0, 4), (2, 0), (2, 2), (2, 4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn strided_iter(
+        &self,
+        row_step: usize,
+        column_step: usize,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
+        assert!(
+            row_step > 0 && column_step > 0,
+            "steps must be greater than 0"
+        );
+        self.vecgrid
+            .chunks(self.num_columns.max(1))
+            .enumerate()
+            .step_by(row_step)
+            .flat_map(move |(row, elements)| {
+                elements
+                    .iter()
+                    .enumerate()
+                    .step_by(column_step)
+                    .map(move |(column, element)| ((row, column), element))
+            })
+    }
+
+    /// Returns an [`Iterator`] over the indices and mutable references to
+    /// every element whose row is a multiple of `row_step` and whose column
+    /// is a multiple of `column_step`, in [row major order]. Each index is a
+    /// tuple of [`usize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either step is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with(0, 3, 3);
+    /// for (_, element) in vecgrid.strided_iter_mut(2, 1) {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 1, 1], vec![0, 0, 0], vec![1, 1, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn strided_iter_mut(
+        &mut self,
+        row_step: usize,
+        column_step: usize,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &mut T)> {
+        assert!(
+            row_step > 0 && column_step > 0,
+            "steps must be greater than 0"
+        );
+        self.vecgrid
+            .chunks_mut(self.num_columns.max(1))
+            .enumerate()
+            .step_by(row_step)
+            .flat_map(move |(row, elements)| {
+                elements
+                    .iter_mut()
+                    .enumerate()
+                    .step_by(column_step)
+                    .map(move |(column, element)| ((row, column), element))
+            })
+    }
+
+    /// Returns an [`Iterator`] over the indices and references to every
+    /// element whose cell has the given [`Parity`], in [row major order].
+    /// Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Parity, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let red = vecgrid.cells_where_parity(Parity::Even).map(|(_, &x)| x).collect::<Vec<_>>();
+    /// assert_eq!(red, vec![1, 3, 5]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Parity`]: enum.Parity.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn cells_where_parity(
+        &self,
+        parity: Parity,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
+        self.vecgrid
+            .chunks(self.num_columns.max(1))
+            .enumerate()
+            .flat_map(move |(row, elements)| {
+                let first_column = parity.first_column(row);
+                elements
+                    .iter()
+                    .enumerate()
+                    .skip(first_column)
+                    .step_by(2)
+                    .map(move |(column, element)| ((row, column), element))
+            })
+    }
+
+    /// Returns an [`Iterator`] over the indices and mutable references to
+    /// every element whose cell has the given [`Parity`], in [row major
+    /// order]. Each index is a tuple of [`usize`].
+    ///
+    /// The iterator borrows the whole vecgrid, so the other cells cannot be
+    /// read while it is alive; see [`update_parity_with_neighbors`] to
+    /// update cells from their neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Parity, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut board = Vecgrid::filled_with('.', 2, 3);
+    /// for (_, cell) in board.cells_where_parity_mut(Parity::Odd) {
+    ///     *cell = '#';
+    /// }
+    /// assert_eq!(board.as_rows(), vec![vec!['.', '#', '.'], vec!['#', '.', '#']]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Parity`]: enum.Parity.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [`update_parity_with_neighbors`]: struct.Vecgrid.html#method.update_parity_with_neighbors
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn cells_where_parity_mut(
+        &mut self,
+        parity: Parity,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &mut T)> {
+        self.vecgrid
+            .chunks_mut(self.num_columns.max(1))
+            .enumerate()
+            .flat_map(move |(row, elements)| {
+                let first_column = parity.first_column(row);
+                elements
+                    .iter_mut()
+                    .enumerate()
+                    .skip(first_column)
+                    .step_by(2)
+                    .map(move |(column, element)| ((row, column), element))
+            })
+    }
+
+    /// Calls `f` for every cell with the given [`Parity`], in [row major
+    /// order], with its `(row, column)`, a mutable reference to its element
+    /// and references to its orthogonal neighbors, in the order above,
+    /// left, right and below, or [`None`] where a neighbor is outside of
+    /// the vecgrid.
+    ///
+    /// The orthogonal neighbors of every such cell have the other parity,
+    /// so a red-black relaxation solver can update all cells of one color
+    /// in place from the values of the other color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Parity, Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut heat = Vecgrid::from_rows(vec![vec![0.0, 4.0, 0.0], vec![2.0, 0.0, 6.0]])?;
+    /// heat.update_parity_with_neighbors(Parity::Even, |_, cell, neighbors| {
+    ///     let known = neighbors.into_iter().flatten().copied().collect::<Vec<f64>>();
+    ///     *cell = known.iter().sum::<f64>() / known.len() as f64;
+    /// });
+    /// assert_eq!(heat.as_rows(), vec![vec![3.0, 4.0, 5.0], vec![2.0, 4.0, 6.0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Parity`]: enum.Parity.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn update_parity_with_neighbors<F>(&mut self, parity: Parity, mut f: F)
+    where
+        F: FnMut((usize, usize), &mut T, [Option<&T>; 4]),
+    {
+        let num_columns = self.num_columns;
+        for row in 0..self.num_rows {
+            for column in (parity.first_column(row)..num_columns).step_by(2) {
+                let index = row * num_columns + column;
+                let (before, rest) = self.vecgrid.split_at_mut(index);
+                let (cell, after) = rest.split_first_mut().expect("index is in bounds");
+                let neighbors = [
+                    (row > 0).then(|| &before[index - num_columns]),
+                    (column > 0).then(|| &before[index - 1]),
+                    (column + 1 < num_columns).then(|| &after[0]),
+                    (row + 1 < self.num_rows).then(|| &after[num_columns - 1]),
+                ];
+                f((row, column), cell, neighbors);
+            }
+        }
+    }
+
+    /// Returns an [`Iterator`] over the indices and references to every
+    /// element that is not on the border, in [row major order]. Each index
+    /// is a tuple of [`usize`].
+    ///
+    /// Every such element has all 8 neighbors, so a stencil can index them
+    /// directly, knowing the indices are in bounds, and handle the border
+    /// separately with [`edges_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_iter_row_major(0.., 3, 4)?;
+    /// let interior = vecgrid.interior_iter().collect::<Vec<_>>();
+    /// assert_eq!(interior, vec![((1, 1), &5), ((1, 2), &6)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [`edges_iter`]: struct.Vecgrid.html#method.edges_iter
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn interior_iter(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
+        let interior_columns = 1..self.num_columns.saturating_sub(1).max(1);
+        self.vecgrid
+            .chunks(self.num_columns.max(1))
+            .enumerate()
+            .take(self.num_rows.saturating_sub(1))
+            .skip(1)
+            .flat_map(move |(row, elements)| {
+                let start = interior_columns.start;
+                elements[interior_columns.clone()]
+                    .iter()
+                    .enumerate()
+                    .map(move |(column, element)| ((row, start + column), element))
+            })
+    }
+
+    /// Returns an [`Iterator`] over the indices and mutable references to
+    /// every element that is not on the border, in [row major order]. Each
+    /// index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut vecgrid = Vecgrid::filled_with('#', 3, 3);
+    /// for (_, cell) in vecgrid.interior_iter_mut() {
+    ///     *cell = '.';
+    /// }
+    /// assert_eq!(vecgrid.as_rows()[1], vec!['#', '.', '#']);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn interior_iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &mut T)> {
+        let interior_columns = 1..self.num_columns.saturating_sub(1).max(1);
+        let num_rows = self.num_rows;
+        self.vecgrid
+            .chunks_mut(self.num_columns.max(1))
+            .enumerate()
+            .take(num_rows.saturating_sub(1))
+            .skip(1)
+            .flat_map(move |(row, elements)| {
+                let start = interior_columns.start;
+                elements[interior_columns.clone()]
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(column, element)| ((row, start + column), element))
+            })
+    }
+
+    /// Returns an [`Iterator`] over the indices and references to every
+    /// element on the border, clockwise from the top-left corner, which is
+    /// the outermost ring of [`spiral_iter`]. Each index is a tuple of
+    /// [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let vecgrid = Vecgrid::from_iter_row_major(0.., 3, 3)?;
+    /// let border = vecgrid.edges_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    /// assert_eq!(border, vec![0, 1, 2, 5, 8, 7, 6, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`spiral_iter`]: struct.Vecgrid.html#method.spiral_iter
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn edges_iter(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        let num_rings = usize::from(num_rows > 0 && num_columns > 0);
+        (0..num_rings)
+            .flat_map(move |layer| ring_indices(num_rows, num_columns, layer))
+            .map(move |(row, column)| ((row, column), &self.vecgrid[row * num_columns + column]))
+    }
+}
+
+/// Returns the indices of the ring of cells `layer` cells away from the
+/// border, clockwise from its top-left corner. The ring must exist.
+fn ring_indices(
+    num_rows: usize,
+    num_columns: usize,
+    layer: usize,
+) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
+    let (top, left) = (layer, layer);
+    let (bottom, right) = (num_rows - 1 - layer, num_columns - 1 - layer);
+    // The bottom row and left column only exist apart from the top row and
+    // right column if the ring is more than one cell thick.
+    let bottom_columns = if bottom > top { left..right } else { 0..0 };
+    let left_rows = if right > left { top + 1..bottom } else { 0..0 };
+    (left..=right)
+        .map(move |column| (top, column))
+        .chain((top + 1..=bottom).map(move |row| (row, right)))
+        .chain(bottom_columns.rev().map(move |column| (bottom, column)))
+        .chain(left_rows.rev().map(move |row| (row, left)))
+}
@@ -0,0 +1,499 @@
+//! Borrowed grid views over external buffers, such as a memory-mapped file
+//! or a staging buffer, that are read as a grid without copying them into a
+//! [`Vecgrid`].
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+
+use crate::{Error, GridIndex, Vecgrid};
+use std::cell::Cell;
+use std::ops::{Index, IndexMut};
+
+/// A read-only grid view of a borrowed slice of elements in [row major
+/// order].
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{VecgridRef, Error};
+/// # fn main() -> Result<(), Error> {
+/// let buffer = [1u8, 2, 3, 4, 5, 6];
+/// let view = VecgridRef::new(&buffer, 2, 3)?;
+/// assert_eq!(view[(1, 0)], 4);
+/// assert_eq!(view.column_iter(2)?.copied().collect::<Vec<_>>(), vec![3, 6]);
+/// assert_eq!(view.to_vecgrid().as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct VecgridRef<'a, T> {
+    elements: &'a [T],
+    num_rows: usize,
+    num_columns: usize,
+}
+
+/// A mutable grid view of a borrowed slice of elements in [row major
+/// order]. The elements can be changed but the dimensions cannot.
+///
+/// # Examples
+///
+/// ```
+/// # use vecgrid::{VecgridRefMut, Error};
+/// # fn main() -> Result<(), Error> {
+/// let mut buffer = [0u8; 6];
+/// let mut view = VecgridRefMut::new(&mut buffer, 2, 3)?;
+/// view[(1, 2)] = 9;
+/// view.set(0, 0, 1)?;
+/// assert_eq!(buffer, [1, 0, 0, 0, 0, 9]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct VecgridRefMut<'a, T> {
+    elements: &'a mut [T],
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl<'a, T> VecgridRef<'a, T> {
+    /// Creates a view of `elements` in [row major order] with the given
+    /// number of rows and columns. Returns an error if the number of
+    /// elements is not the product of `num_rows` and `num_columns`.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn new(elements: &'a [T], num_rows: usize, num_columns: usize) -> Result<Self, Error> {
+        check_len(elements.len(), num_rows, num_columns)?;
+        Ok(VecgridRef {
+            elements,
+            num_rows,
+            num_columns,
+        })
+    }
+
+    /// The number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The total number of elements, i.e. the product of `num_rows` and
+    /// `num_columns`.
+    pub fn num_elements(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns a reference to the element at the given `row` and `column`,
+    /// or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, column: usize) -> Option<&'a T> {
+        get_index(row, column, self.num_rows, self.num_columns).map(|index| &self.elements[index])
+    }
+
+    /// Returns the elements in [row major order], with the lifetime of the
+    /// borrowed buffer.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_row_major_slice(&self) -> &'a [T] {
+        self.elements
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in [row major
+    /// order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + Clone {
+        self.elements.iter()
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in the given
+    /// row. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter(
+        &self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &'a T>, Error> {
+        if row_index >= self.num_rows {
+            return Err(Error::indices_out_of_bounds(
+                row_index,
+                0,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[row_index * self.num_columns..][..self.num_columns].iter())
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in the given
+    /// column. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_iter(
+        &self,
+        column_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &'a T>, Error> {
+        if column_index >= self.num_columns {
+            return Err(Error::indices_out_of_bounds(
+                0,
+                column_index,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[column_index..]
+            .iter()
+            .step_by(self.num_columns))
+    }
+
+    /// Returns an [`Iterator`] over all rows, each as a slice of its
+    /// elements.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn rows_iter(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + Clone {
+        let (elements, num_columns) = (self.elements, self.num_columns);
+        (0..self.num_rows).map(move |row| &elements[row * num_columns..][..num_columns])
+    }
+
+    /// Copies the viewed elements into a new, owned [`Vecgrid`].
+    ///
+    /// [`Vecgrid`]: struct.Vecgrid.html
+    pub fn to_vecgrid(&self) -> Vecgrid<T>
+    where
+        T: Clone,
+    {
+        Vecgrid {
+            vecgrid: self.elements.to_vec(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+impl<'a, T> VecgridRefMut<'a, T> {
+    /// Creates a mutable view of `elements` in [row major order] with the
+    /// given number of rows and columns. Returns an error if the number of
+    /// elements is not the product of `num_rows` and `num_columns`.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn new(elements: &'a mut [T], num_rows: usize, num_columns: usize) -> Result<Self, Error> {
+        check_len(elements.len(), num_rows, num_columns)?;
+        Ok(VecgridRefMut {
+            elements,
+            num_rows,
+            num_columns,
+        })
+    }
+
+    /// The number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The total number of elements, i.e. the product of `num_rows` and
+    /// `num_columns`.
+    pub fn num_elements(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns a read-only view of the same elements, for the read and
+    /// iteration methods of [`VecgridRef`].
+    ///
+    /// [`VecgridRef`]: struct.VecgridRef.html
+    pub fn as_view(&self) -> VecgridRef<'_, T> {
+        VecgridRef {
+            elements: self.elements,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Converts the view into a [`VecgridRef`] of [`Cell`]s over the same
+    /// elements, like [`Vecgrid::as_cells`].
+    ///
+    /// [`VecgridRef`]: struct.VecgridRef.html
+    /// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+    /// [`Vecgrid::as_cells`]: struct.Vecgrid.html#method.as_cells
+    pub fn into_cells(self) -> VecgridRef<'a, Cell<T>> {
+        VecgridRef {
+            elements: Cell::from_mut(self.elements).as_slice_of_cells(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Returns a reference to the element at the given `row` and `column`,
+    /// or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        get_index(row, column, self.num_rows, self.num_columns).map(|index| &self.elements[index])
+    }
+
+    /// Returns a mutable reference to the element at the given `row` and
+    /// `column`, or [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        get_index(row, column, self.num_rows, self.num_columns)
+            .map(move |index| &mut self.elements[index])
+    }
+
+    /// Changes the element at the given `row` and `column` to `element`.
+    /// Returns an error if the indices are out of bounds.
+    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
+        let error = Error::indices_out_of_bounds(row, column, self.num_rows, self.num_columns);
+        let location = self.get_mut(row, column).ok_or(error)?;
+        *location = element;
+        Ok(())
+    }
+
+    /// Returns the elements in [row major order].
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_row_major_slice(&self) -> &[T] {
+        self.elements
+    }
+
+    /// Returns the elements in [row major order] as a mutable slice.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn as_mut_row_major_slice(&mut self) -> &mut [T] {
+        self.elements
+    }
+
+    /// Consumes the view, returning its elements in [row major order] with
+    /// the lifetime of the borrowed buffer.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn into_row_major_slice(self) -> &'a mut [T] {
+        self.elements
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in
+    /// the given row. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter_mut(
+        &mut self,
+        row_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        if row_index >= self.num_rows {
+            return Err(Error::indices_out_of_bounds(
+                row_index,
+                0,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[row_index * self.num_columns..][..self.num_columns].iter_mut())
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in
+    /// the given column. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_iter_mut(
+        &mut self,
+        column_index: usize,
+    ) -> Result<impl DoubleEndedIterator<Item = &mut T>, Error> {
+        if column_index >= self.num_columns {
+            return Err(Error::indices_out_of_bounds(
+                0,
+                column_index,
+                self.num_rows,
+                self.num_columns,
+            ));
+        }
+        Ok(self.elements[column_index..]
+            .iter_mut()
+            .step_by(self.num_columns))
+    }
+}
+
+impl<T> Vecgrid<T> {
+    /// Returns a [`VecgridRef`] borrowing the elements of the vecgrid, for
+    /// code written against borrowed views.
+    ///
+    /// [`VecgridRef`]: struct.VecgridRef.html
+    pub fn as_view(&self) -> VecgridRef<'_, T> {
+        VecgridRef {
+            elements: &self.vecgrid,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Returns an [`Iterator`] over overlapping [`VecgridRef`]s of
+    /// `window_len` consecutive rows, from top to bottom, like
+    /// [`slice::windows`] over the rows. There are no windows if the
+    /// vecgrid has fewer than `window_len` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_len` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let series = Vecgrid::from_rows(vec![vec![1, 10], vec![2, 20], vec![3, 30], vec![4, 40]])?;
+    /// let rolling_sums = series
+    ///     .row_windows(3)
+    ///     .map(|window| window.column_iter(1).unwrap().sum::<i32>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(rolling_sums, vec![60, 90]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`VecgridRef`]: struct.VecgridRef.html
+    /// [`slice::windows`]: https://doc.rust-lang.org/std/primitive.slice.html#method.windows
+    pub fn row_windows(
+        &self,
+        window_len: usize,
+    ) -> impl DoubleEndedIterator<Item = VecgridRef<'_, T>> + Clone {
+        assert!(window_len > 0, "window length must be greater than 0");
+        let num_columns = self.num_columns;
+        (0..(self.num_rows + 1).saturating_sub(window_len)).map(move |first_row| VecgridRef {
+            elements: &self.vecgrid
+                [first_row * num_columns..(first_row + window_len) * num_columns],
+            num_rows: window_len,
+            num_columns,
+        })
+    }
+
+    /// Returns a [`VecgridRefMut`] borrowing the elements of the vecgrid,
+    /// which can modify the elements but not the dimensions.
+    ///
+    /// [`VecgridRefMut`]: struct.VecgridRefMut.html
+    pub fn as_view_mut(&mut self) -> VecgridRefMut<'_, T> {
+        VecgridRefMut {
+            elements: &mut self.vecgrid,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
+    /// Returns a [`VecgridRef`] of [`Cell`]s borrowing the elements of the
+    /// vecgrid, like [`Cell::from_mut`] and [`Cell::as_slice_of_cells`], so
+    /// several cells can be read and written through shared references at
+    /// the same time, for example to swap elements while scanning
+    /// neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut sand = Vecgrid::from_rows(vec![
+    ///     vec![1, 0, 1],
+    ///     vec![0, 1, 0],
+    /// ])?;
+    /// let cells = sand.as_cells();
+    /// for column in 0..cells.num_columns() {
+    ///     let (above, below) = (&cells[(0, column)], &cells[(1, column)]);
+    ///     if above.get() == 1 && below.get() == 0 {
+    ///         above.swap(below);
+    ///     }
+    /// }
+    /// assert_eq!(sand.as_rows(), vec![vec![0, 0, 0], vec![1, 1, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VecgridRef`]: struct.VecgridRef.html
+    /// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+    /// [`Cell::from_mut`]: https://doc.rust-lang.org/std/cell/struct.Cell.html#method.from_mut
+    /// [`Cell::as_slice_of_cells`]: https://doc.rust-lang.org/std/cell/struct.Cell.html#method.as_slice_of_cells
+    pub fn as_cells(&mut self) -> VecgridRef<'_, Cell<T>> {
+        VecgridRef {
+            elements: Cell::from_mut(self.vecgrid.as_mut_slice()).as_slice_of_cells(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+}
+
+fn check_len(len: usize, num_rows: usize, num_columns: usize) -> Result<(), Error> {
+    let total_len = num_rows * num_columns;
+    if total_len != len {
+        return Err(Error::dimension_mismatch(total_len, len));
+    }
+    Ok(())
+}
+
+fn get_index(row: usize, column: usize, num_rows: usize, num_columns: usize) -> Option<usize> {
+    if row < num_rows && column < num_columns {
+        Some(row * num_columns + column)
+    } else {
+        None
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for VecgridRef<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)` or
+    /// any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Into<GridIndex>> Index<I> for VecgridRefMut<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given indices, given as `(row, column)` or
+    /// any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index(&self, index: I) -> &Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get(row, column)
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Into<GridIndex>> IndexMut<I> for VecgridRefMut<'_, T> {
+    /// Returns a mutable version of the element at the given indices, given
+    /// as `(row, column)` or any other [`GridIndex`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`GridIndex`]: struct.GridIndex.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let GridIndex { row, column } = index.into();
+        self.get_mut(row, column)
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
@@ -0,0 +1,179 @@
+//! Exchanging [`Vecgrid`]s of numbers with JavaScript as typed arrays
+//! through [`js_sys`], enabled with the `wasm` feature.
+//!
+//! The typed arrays hold the elements in [row major order], like
+//! [`as_row_major_slice`]. A `Vecgrid<u8>` of RGBA pixels can back a canvas
+//! without a copy per frame by viewing its slice as a
+//! `js_sys::Uint8ClampedArray` for `ImageData` with [`view_clamped_array`].
+//!
+//! [`Vecgrid`]: ../struct.Vecgrid.html
+//! [`view_clamped_array`]: ../struct.Vecgrid.html#method.view_clamped_array
+//! [`js_sys`]: https://docs.rs/js-sys
+//! [`as_row_major_slice`]: ../struct.Vecgrid.html#method.as_row_major_slice
+//! [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+
+use crate::{Error, Vecgrid};
+
+macro_rules! impl_typed_arrays {
+    ($($element:ty => $array:ident),*) => {
+        $(
+            impl Vecgrid<$element> {
+                #[doc = concat!("Copies the elements into a new `js_sys::", stringify!($array), "`")]
+                /// in [row major order].
+                ///
+                /// # Examples
+                ///
+                /// ```no_run
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let vecgrid = Vecgrid::from_rows(vec![vec![1.0f64, 2.0], vec![3.0, 4.0]])?;
+                /// let array = vecgrid.to_typed_array();
+                /// assert_eq!(array.to_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+                pub fn to_typed_array(&self) -> js_sys::$array {
+                    js_sys::$array::from(self.as_row_major_slice())
+                }
+
+                #[doc = concat!("Returns a `js_sys::", stringify!($array), "` that views the elements")]
+                /// in WebAssembly memory in [row major order], without
+                /// copying them.
+                ///
+                /// # Safety
+                ///
+                /// The view is only valid while the vecgrid is neither
+                /// modified, moved nor dropped, and until the WebAssembly
+                /// memory grows, which any allocation may cause. Use it right
+                /// away, for example to hand a frame to JavaScript.
+                ///
+                /// # Examples
+                ///
+                /// ```no_run
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let frame = Vecgrid::filled_with(0u8, 480, 640);
+                /// // Safety: the view is used before anything allocates.
+                /// let pixels = unsafe { frame.view_typed_array() };
+                /// assert_eq!(pixels.length(), 480 * 640);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+                pub unsafe fn view_typed_array(&self) -> js_sys::$array {
+                    js_sys::$array::view(self.as_row_major_slice())
+                }
+
+                #[doc = concat!("Overwrites the elements with the contents of a `js_sys::", stringify!($array), "`")]
+                /// in [row major order]. Returns an error if its length is
+                /// not the number of elements, without changing any element.
+                ///
+                /// # Examples
+                ///
+                /// ```no_run
+                /// # use vecgrid::{Vecgrid, Error};
+                /// # fn main() -> Result<(), Error> {
+                /// let mut vecgrid = Vecgrid::filled_with(0i32, 2, 2);
+                /// let array = js_sys::Int32Array::from(&[1, 2, 3, 4][..]);
+                /// vecgrid.copy_from_typed_array(&array)?;
+                /// assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![3, 4]]);
+                /// # Ok(())
+                /// # }
+                /// ```
+                ///
+                /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+                pub fn copy_from_typed_array(&mut self, array: &js_sys::$array) -> Result<(), Error> {
+                    let length = array.length() as usize;
+                    if length != self.vecgrid.len() {
+                        return Err(Error::dimension_mismatch(self.vecgrid.len(), length));
+                    }
+                    array.copy_to(self.as_mut_row_major_slice());
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_arrays!(
+    u8 => Uint8Array,
+    i8 => Int8Array,
+    u16 => Uint16Array,
+    i16 => Int16Array,
+    u32 => Uint32Array,
+    i32 => Int32Array,
+    f32 => Float32Array,
+    f64 => Float64Array
+);
+
+impl Vecgrid<u8> {
+    /// Copies the elements into a new `js_sys::Uint8ClampedArray` in [row
+    /// major order], the array type taken by `ImageData`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let pixels = Vecgrid::from_rows(vec![vec![255u8, 0, 0, 255], vec![0, 0, 255, 255]])?;
+    /// let array = pixels.to_clamped_array();
+    /// assert_eq!(array.length(), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn to_clamped_array(&self) -> js_sys::Uint8ClampedArray {
+        js_sys::Uint8ClampedArray::from(self.as_row_major_slice())
+    }
+
+    /// Returns a `js_sys::Uint8ClampedArray` that views the elements in
+    /// WebAssembly memory in [row major order], without copying them, for
+    /// example to back the `ImageData` of a canvas.
+    ///
+    /// # Safety
+    ///
+    /// The same as for [`view_typed_array`]: the view is only valid while
+    /// the vecgrid is neither modified, moved nor dropped, and until the
+    /// WebAssembly memory grows.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use vecgrid::{Vecgrid, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let frame = Vecgrid::filled_with(0u8, 480, 640 * 4);
+    /// // Safety: the view is used before anything allocates.
+    /// let pixels = unsafe { frame.view_clamped_array() };
+    /// assert_eq!(pixels.length(), 480 * 640 * 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`view_typed_array`]: struct.Vecgrid.html#method.view_typed_array
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub unsafe fn view_clamped_array(&self) -> js_sys::Uint8ClampedArray {
+        js_sys::Uint8ClampedArray::view(self.as_row_major_slice())
+    }
+
+    /// Overwrites the elements with the contents of a
+    /// `js_sys::Uint8ClampedArray` in [row major order], such as the data of
+    /// an `ImageData`. Returns an error if its length is not the number of
+    /// elements, without changing any element.
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn copy_from_clamped_array(
+        &mut self,
+        array: &js_sys::Uint8ClampedArray,
+    ) -> Result<(), Error> {
+        let length = array.length() as usize;
+        if length != self.vecgrid.len() {
+            return Err(Error::dimension_mismatch(self.vecgrid.len(), length));
+        }
+        array.copy_to(self.as_mut_row_major_slice());
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "approx")]
+
+use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+use vecgrid::{Error, Vecgrid};
+
+#[test]
+fn test_approx_eq() -> Result<(), Error> {
+    let a = Vecgrid::from_rows(vec![vec![0.1 + 0.2, 1.0], vec![2.0, 3.0]])?;
+    let b = Vecgrid::from_rows(vec![vec![0.3, 1.0], vec![2.0, 3.0]])?;
+    assert_ne!(a, b);
+    assert_abs_diff_eq!(a, b);
+    assert_relative_eq!(a, b);
+    assert_ulps_eq!(a, b);
+
+    let c = Vecgrid::from_rows(vec![vec![0.3, 1.0], vec![2.0, 3.1]])?;
+    assert_relative_ne!(a, c);
+    assert_abs_diff_eq!(a, c, epsilon = 0.2);
+    Ok(())
+}
+
+#[test]
+fn test_approx_eq_dimension_mismatch() -> Result<(), Error> {
+    let a = Vecgrid::filled_with(1.0f32, 2, 3);
+    let b = Vecgrid::filled_with(1.0f32, 3, 2);
+    assert_relative_ne!(a, b);
+    assert!(!approx::abs_diff_eq!(a, b, epsilon = 1.0));
+    Ok(())
+}
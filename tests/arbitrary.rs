@@ -0,0 +1,40 @@
+#![cfg(feature = "quickcheck")]
+
+use quickcheck::{quickcheck, Arbitrary, Gen};
+use vecgrid::Vecgrid;
+
+#[test]
+fn test_arbitrary_dimensions_are_consistent() {
+    fn consistent(vecgrid: Vecgrid<i32>) -> bool {
+        vecgrid.elements_row_major_iter().count() == vecgrid.num_elements()
+            && vecgrid.rows_iter().count() == vecgrid.num_rows()
+    }
+    quickcheck(consistent as fn(Vecgrid<i32>) -> bool);
+}
+
+#[test]
+fn test_arbitrary_respects_size() {
+    let mut g = Gen::new(16);
+    for _ in 0..100 {
+        let vecgrid = Vecgrid::<u8>::arbitrary(&mut g);
+        assert!(vecgrid.num_rows() <= 4);
+        assert!(vecgrid.num_columns() <= 4);
+    }
+}
+
+#[test]
+fn test_shrink_removes_rows_and_columns() {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1u8, 2, 3], vec![4, 5, 6]]).unwrap();
+    let shrunk = vecgrid.shrink().collect::<Vec<_>>();
+    assert_eq!(shrunk[0].as_rows(), vec![vec![1, 2, 3]]);
+    assert_eq!(shrunk[1].as_rows(), vec![vec![4, 5, 6]]);
+    assert_eq!(shrunk[2].as_rows(), vec![vec![1, 2], vec![4, 5]]);
+    assert_eq!(shrunk[3].as_rows(), vec![vec![2, 3], vec![5, 6]]);
+    assert!(shrunk[4..]
+        .iter()
+        .all(|smaller| smaller.num_rows() == 2 && smaller.num_columns() == 3));
+    assert!(Vecgrid::<u8>::filled_with(0, 0, 0)
+        .shrink()
+        .next()
+        .is_none());
+}
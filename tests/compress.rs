@@ -0,0 +1,64 @@
+#![cfg(feature = "deflate")]
+
+use std::io::ErrorKind;
+use vecgrid::Vecgrid;
+
+fn encode(row: &[u32], buffer: &mut Vec<u8>) {
+    buffer.extend(row.iter().flat_map(|element| element.to_le_bytes()));
+}
+
+fn decode(bytes: &[u8]) -> std::io::Result<Vec<u32>> {
+    Ok(bytes
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+#[test]
+fn test_compressed_bytes() -> std::io::Result<()> {
+    let vecgrid = Vecgrid::filled_by_row_major(
+        {
+            let mut next = 0;
+            move || {
+                next += 1;
+                next / 50
+            }
+        },
+        100,
+        80,
+    );
+    let bytes = vecgrid.to_compressed_bytes(encode);
+    assert_eq!(&bytes[..5], b"VGRZ\x01");
+    assert!(bytes.len() < vecgrid.num_elements() * 4 / 10);
+    assert_eq!(bytes, vecgrid.to_compressed_bytes(encode));
+    assert_eq!(Vecgrid::from_compressed_bytes(&bytes, decode)?, vecgrid);
+
+    let empty = Vecgrid::filled_with(0, 0, 5);
+    let empty_bytes = empty.to_compressed_bytes(encode);
+    assert_eq!(Vecgrid::from_compressed_bytes(&empty_bytes, decode)?, empty);
+    Ok(())
+}
+
+#[test]
+fn test_compressed_bytes_invalid() {
+    let bytes = Vecgrid::filled_with(3, 4, 4).to_compressed_bytes(encode);
+    let mut unknown_format = bytes.clone();
+    unknown_format[4] = 2;
+    let mut wrong_length = bytes.clone();
+    wrong_length[5] += 1;
+    let mut corrupted = bytes.clone();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 0xff;
+    for invalid in [
+        &bytes[..3],
+        &bytes[..bytes.len() - 2],
+        &unknown_format,
+        &wrong_length,
+        &corrupted,
+    ] {
+        let error = Vecgrid::from_compressed_bytes(invalid, decode).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+    let error = Vecgrid::<u32>::from_compressed_bytes(&bytes, |_| Err(ErrorKind::Other.into()));
+    assert_eq!(error.unwrap_err().kind(), ErrorKind::Other);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "csv")]
+
+use vecgrid::{CsvError, Vecgrid};
+
+#[test]
+fn test_csv_round_trip() -> Result<(), CsvError> {
+    let vecgrid = Vecgrid::from_iter_row_major(-3..9, 3, 4).unwrap();
+    let mut csv = Vec::new();
+    vecgrid.to_csv_writer(&mut csv)?;
+    assert_eq!(
+        String::from_utf8(csv.clone()).unwrap(),
+        "-3,-2,-1,0\n1,2,3,4\n5,6,7,8\n"
+    );
+    assert_eq!(Vecgrid::from_csv_reader(csv.as_slice())?, vecgrid);
+
+    let text =
+        Vecgrid::from_rows(vec![vec!["a \"quoted\"".to_string(), "b\nc".to_string()]]).unwrap();
+    let mut csv = Vec::new();
+    text.to_csv_writer(&mut csv)?;
+    assert_eq!(Vecgrid::<String>::from_csv_reader(csv.as_slice())?, text);
+
+    let empty = Vecgrid::<u8>::from_csv_reader("".as_bytes())?;
+    assert_eq!((empty.num_rows(), empty.num_columns()), (0, 0));
+    let spaced = Vecgrid::<u8>::from_csv_reader(" 1 ,2\n\n3, 4 \n".as_bytes())?;
+    assert_eq!(spaced.as_rows(), vec![vec![1, 2], vec![3, 4]]);
+    Ok(())
+}
+
+#[test]
+fn test_csv_errors() {
+    let error = Vecgrid::<u8>::from_csv_reader("1,2\n3,4\n5,6,7\n".as_bytes()).unwrap_err();
+    assert!(matches!(
+        error,
+        CsvError::RaggedLine {
+            line: 3,
+            expected: 2,
+            actual: 3
+        }
+    ));
+
+    let error = Vecgrid::<u8>::from_csv_reader("1,2\n3,300\n".as_bytes()).unwrap_err();
+    match error {
+        CsvError::Parse {
+            line,
+            column,
+            ref field,
+            ..
+        } => {
+            assert_eq!((line, column, field.as_str()), (2, 1, "300"));
+        }
+        _ => panic!("unexpected error {:?}", error),
+    }
+    assert!(error
+        .to_string()
+        .starts_with("cannot parse \"300\" on line 2, column 1: "));
+
+    let error = Vecgrid::<u8>::from_csv_reader(&b"1,\xff\n"[..]).unwrap_err();
+    assert!(matches!(error, CsvError::Csv(_)));
+    assert!(std::error::Error::source(&error).is_some());
+}
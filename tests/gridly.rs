@@ -0,0 +1,30 @@
+#![cfg(feature = "gridly")]
+
+use gridly::prelude::{Grid, GridBounds, GridMut, GridSetter, Location, Vector};
+use vecgrid::{Error, Vecgrid};
+
+#[test]
+fn test_gridly_grid() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(GridBounds::dimensions(&vecgrid), Vector::new(2, 3));
+    assert_eq!(GridBounds::root(&vecgrid), Location::zero());
+    for (row, column) in vecgrid.indices_row_major() {
+        let location = Location::new(row as isize, column as isize);
+        assert_eq!(Grid::get(&vecgrid, location), Ok(&vecgrid[(row, column)]));
+    }
+    for location in [(-1, 0), (0, -1), (2, 0), (0, 3)] {
+        assert!(Grid::get(&vecgrid, location).is_err());
+    }
+    let columns = Grid::columns(&vecgrid)
+        .iter()
+        .map(|column| column.iter().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(columns, vecgrid.as_columns());
+
+    *GridMut::get_mut(&mut vecgrid, (1, 0)).unwrap() += 10;
+    assert_eq!(GridSetter::set(&mut vecgrid, (0, 2), 30), Ok(()));
+    assert_eq!(GridSetter::replace(&mut vecgrid, (0, 0), 0), Ok(1));
+    assert!(GridSetter::set(&mut vecgrid, (2, 2), 0).is_err());
+    assert_eq!(vecgrid.as_rows(), vec![vec![0, 2, 30], vec![14, 5, 6]]);
+    Ok(())
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "noise")]
+
+use vecgrid::{NoiseCoordinates, Vecgrid};
+
+#[test]
+fn test_from_noise_cells() {
+    let vecgrid = Vecgrid::from_noise(3, 2, |x, y| x - y);
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![vec![0.0, 1.0], vec![-1.0, 0.0], vec![-2.0, -1.0]]
+    );
+}
+
+#[test]
+fn test_from_noise_coordinates() {
+    let scaled = Vecgrid::from_noise_with(2, 2, NoiseCoordinates::Scaled(0.5), |x, y| x + y);
+    assert_eq!(scaled.as_rows(), vec![vec![0.0, 0.5], vec![0.5, 1.0]]);
+
+    let bounds = NoiseCoordinates::Bounds((-1.0, 1.0), (10.0, 20.0));
+    let vecgrid = Vecgrid::from_noise_with(2, 3, bounds, |x, y| x * 100.0 + y);
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![vec![-90.0, 10.0, 110.0], vec![-80.0, 20.0, 120.0]]
+    );
+
+    let single = Vecgrid::from_noise_with(1, 1, NoiseCoordinates::Unit, |x, y| x + y);
+    assert_eq!(single.as_rows(), vec![vec![0.0]]);
+    assert_eq!(Vecgrid::from_noise(0, 4, |x, _| x).num_elements(), 0);
+}
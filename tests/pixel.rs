@@ -0,0 +1,79 @@
+#![cfg(feature = "pixel")]
+
+use vecgrid::{Error, Rgb, Rgba, Vecgrid};
+
+#[test]
+fn test_pixel_channels() {
+    let color = Rgba::new(1u8, 2, 3, 4);
+    assert_eq!((color.r, color.g, color.b, color.a), (1, 2, 3, 4));
+    assert_eq!(color.rgb(), Rgb::from([1, 2, 3]));
+    assert_eq!(Rgba::from(<[u8; 4]>::from(color)), color);
+    assert_eq!(<[u8; 3]>::from(Rgb::new(5u8, 6, 7)), [5, 6, 7]);
+    assert_eq!(std::mem::size_of::<Rgba<u8>>(), 4);
+}
+
+#[test]
+fn test_split_channels() -> Result<(), Error> {
+    let image = Vecgrid::from_rows(vec![
+        vec![Rgba::new(255u8, 0, 0, 255), Rgba::new(0, 255, 0, 128)],
+        vec![Rgba::new(0, 0, 255, 0), Rgba::new(1, 2, 3, 4)],
+    ])?;
+    let [red, green, blue, alpha] = image.split_channels();
+    assert_eq!(red.as_rows(), vec![vec![255, 0], vec![0, 1]]);
+    assert_eq!(green.as_rows(), vec![vec![0, 255], vec![0, 2]]);
+    assert_eq!(blue.as_rows(), vec![vec![0, 0], vec![255, 3]]);
+    assert_eq!(alpha.as_rows(), vec![vec![255, 128], vec![0, 4]]);
+    let channels = [red, green, blue, alpha.clone()];
+    assert_eq!(Vecgrid::<Rgba<u8>>::from_channels(&channels)?, image);
+
+    let rgb = Vecgrid::filled_with(Rgb::new(1.0f32, 0.5, 0.0), 1, 2);
+    let [_, green, _] = rgb.split_channels();
+    assert_eq!(green.as_rows(), vec![vec![0.5, 0.5]]);
+
+    let wrong = [
+        alpha.clone(),
+        alpha.clone(),
+        Vecgrid::filled_with(0, 2, 3),
+        alpha,
+    ];
+    assert_eq!(
+        Vecgrid::<Rgba<u8>>::from_channels(&wrong),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3),
+            index: Some(2)
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_composite_over() -> Result<(), Error> {
+    let opaque = Rgba::new(10u8, 20, 30, 255);
+    let clear = Rgba::new(200u8, 200, 200, 0);
+    assert_eq!(clear.over(opaque), opaque);
+    assert_eq!(opaque.over(clear), opaque);
+    assert_eq!(clear.over(clear), Rgba::default());
+    let half = Rgba::new(255u8, 255, 255, 128);
+    assert_eq!(
+        half.over(Rgba::new(0, 0, 0, 128)),
+        Rgba::new(170, 170, 170, 192)
+    );
+
+    let mut image = Vecgrid::filled_with(opaque, 3, 3);
+    let layer = Vecgrid::from_rows(vec![
+        vec![Rgba::new(255, 0, 0, 255), clear],
+        vec![clear, Rgba::new(0, 255, 0, 255)],
+    ])?;
+    image.composite_over(&layer, (1, 1));
+    assert_eq!(image[(1, 1)], Rgba::new(255, 0, 0, 255));
+    assert_eq!(image[(1, 2)], opaque);
+    assert_eq!(image[(2, 2)], Rgba::new(0, 255, 0, 255));
+    image.composite_over(&layer, (2, 2));
+    image.composite_over(&layer, (-1, -1));
+    image.composite_over(&layer, (5, 0));
+    assert_eq!(image[(2, 2)], Rgba::new(255, 0, 0, 255));
+    assert_eq!(image[(0, 0)], Rgba::new(0, 255, 0, 255));
+    assert_eq!(image[(0, 1)], opaque);
+    Ok(())
+}
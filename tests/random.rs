@@ -0,0 +1,51 @@
+#![cfg(feature = "rand")]
+
+use rand::distributions::Uniform;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use vecgrid::{Error, Vecgrid};
+
+#[test]
+fn test_random() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let vecgrid: Vecgrid<bool> = Vecgrid::random(4, 5, &mut rng);
+    assert_eq!(vecgrid.num_rows(), 4);
+    assert_eq!(vecgrid.num_columns(), 5);
+
+    let mut rng = StdRng::seed_from_u64(1);
+    assert_eq!(Vecgrid::random(4, 5, &mut rng), vecgrid);
+
+    let dice = Vecgrid::random_from(10, 10, &Uniform::new_inclusive(1, 6), &mut rng);
+    assert!(dice.elements_row_major_iter().all(|x| (1..=6).contains(x)));
+}
+
+#[test]
+fn test_shuffle_keeps_elements() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(2);
+    let original = Vecgrid::from_iter_row_major(0.., 5, 4)?;
+    let mut shuffled = original.clone();
+    shuffled.shuffle(&mut rng);
+    assert_eq!(shuffled.num_rows(), 5);
+    assert_ne!(shuffled, original);
+    let mut elements = shuffled.as_row_major();
+    elements.sort();
+    assert_eq!(elements, original.as_row_major());
+    Ok(())
+}
+
+#[test]
+fn test_choose() -> Result<(), Error> {
+    let mut rng = StdRng::seed_from_u64(3);
+    let vecgrid = Vecgrid::from_iter_row_major(0.., 2, 3)?;
+    let mut seen = [false; 6];
+    for _ in 0..200 {
+        let ((row, column), element) = vecgrid.choose(&mut rng).unwrap();
+        assert_eq!(row * 3 + column, *element);
+        seen[*element] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+
+    let empty: Vecgrid<i32> = Vecgrid::from_rows(vec![vec![]])?;
+    assert_eq!(empty.choose(&mut rng), None);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+
+use vecgrid::{Error, RunLengthEncoded, Vecgrid};
+
+fn decode(json: &str) -> Result<Vecgrid<u8>, Error> {
+    let encoded: RunLengthEncoded<u8> = serde_json::from_str(json).unwrap();
+    Vecgrid::from_rle(encoded)
+}
+
+#[test]
+fn test_rle_round_trip_through_serde() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![0u8, 0, 1], vec![1, 1, 0]])?;
+    let json = serde_json::to_string(&vecgrid.to_rle()).unwrap();
+    assert_eq!(decode(&json)?, vecgrid);
+    Ok(())
+}
+
+#[test]
+fn test_rle_untrusted_dimensions() {
+    let max = usize::MAX;
+    assert_eq!(
+        decode(&format!(
+            r#"{{"runs":[],"num_rows":{},"num_columns":{}}}"#,
+            max, max
+        )),
+        Err(Error::ElementCountOverflow {
+            num_rows: max,
+            num_columns: max
+        })
+    );
+    assert_eq!(
+        decode(&format!(
+            r#"{{"runs":[[0,{}],[1,1]],"num_rows":1,"num_columns":{}}}"#,
+            max, max
+        )),
+        Err(Error::ElementCountOverflow {
+            num_rows: 1,
+            num_columns: max
+        })
+    );
+    assert_eq!(
+        decode(r#"{"runs":[[0,1]],"num_rows":1048576,"num_columns":1048576}"#),
+        Err(Error::DimensionMismatch {
+            expected: 1 << 40,
+            actual: 1,
+            index: None
+        })
+    );
+}
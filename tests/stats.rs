@@ -0,0 +1,52 @@
+#![cfg(feature = "stats")]
+
+use vecgrid::{Error, Vecgrid};
+
+#[test]
+fn test_mean_and_variance() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])?;
+    assert_eq!(vecgrid.mean(), Some(3.5));
+    let variance = vecgrid.variance().unwrap();
+    assert!((variance - 35.0 / 12.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_minmax() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![3.0, f64::NAN], vec![-1.5, 8.0]])?;
+    assert_eq!(vecgrid.minmax(), Some((-1.5, 8.0)));
+    Ok(())
+}
+
+#[test]
+fn test_histogram() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_row_major((0u8..10).collect(), 2, 5)?;
+    assert_eq!(vecgrid.histogram(3), vec![3, 3, 4]);
+    assert_eq!(vecgrid.histogram(0), Vec::<usize>::new());
+
+    let uniform = Vecgrid::filled_with(7, 2, 2);
+    assert_eq!(uniform.histogram(4), vec![4, 0, 0, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_row_and_column_summaries() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 3, 5], vec![3, 3, 3]])?;
+    assert_eq!(vecgrid.row_means(), vec![3.0, 3.0]);
+    assert_eq!(vecgrid.column_means(), vec![2.0, 3.0, 4.0]);
+    assert_eq!(vecgrid.row_variances()[1], 0.0);
+    assert_eq!(vecgrid.column_variances(), vec![1.0, 0.0, 1.0]);
+    Ok(())
+}
+
+#[test]
+fn test_stats_empty_vecgrid() -> Result<(), Error> {
+    let vecgrid: Vecgrid<f32> = Vecgrid::from_rows(vec![vec![], vec![]])?;
+    assert_eq!(vecgrid.mean(), None);
+    assert_eq!(vecgrid.variance(), None);
+    assert_eq!(vecgrid.minmax(), None);
+    assert!(vecgrid.histogram(4).is_empty());
+    assert!(vecgrid.row_means().iter().all(|mean| mean.is_nan()));
+    assert!(vecgrid.column_means().is_empty());
+    Ok(())
+}
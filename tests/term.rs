@@ -0,0 +1,29 @@
+#![cfg(feature = "term")]
+
+use vecgrid::{CellStyle, Color, Error, Vecgrid};
+
+#[test]
+fn test_to_ansi_string_plain() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let rendered = vecgrid
+        .to_ansi_string(|&element| CellStyle::from(char::from_digit(element, 10).unwrap_or('?')));
+    assert_eq!(rendered, "123\n456");
+    Ok(())
+}
+
+#[test]
+fn test_to_ansi_string_colors() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![0, 1, 2]])?;
+    let rendered = vecgrid.to_ansi_string(|&element| match element {
+        0 => CellStyle::new('~').background(Color::Blue),
+        1 => CellStyle::new('@')
+            .foreground(Color::Red)
+            .background(Color::White),
+        _ => CellStyle::new('*').foreground(Color::Rgb(255, 128, 0)),
+    });
+    assert_eq!(
+        rendered,
+        "\x1b[44m~\x1b[0m\x1b[31;47m@\x1b[0m\x1b[38;2;255;128;0m*\x1b[0m"
+    );
+    Ok(())
+}
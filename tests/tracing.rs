@@ -0,0 +1,91 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use vecgrid::{Error, Vecgrid};
+
+#[derive(Clone, Default)]
+struct Recorder {
+    spans: Arc<Mutex<Vec<String>>>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!("{}={:?} ", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span.metadata().name().to_string());
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields(String::new());
+        event.record(&mut fields);
+        self.events
+            .lock()
+            .unwrap()
+            .push(fields.0.trim_end().to_string());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_structural_mutations_are_traced() -> Result<(), Error> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || -> Result<(), Error> {
+        let mut vecgrid = Vecgrid::filled_with(0, 2, 2);
+        vecgrid.insert_row(vec![1, 1], 1)?;
+        vecgrid.append_rows(vec![vec![2, 2]])?;
+        vecgrid.insert_column(vec![3, 3, 3, 3], 0)?;
+        vecgrid.remove_row(0)?;
+        assert!(vecgrid.insert_row(vec![4], 0).is_err());
+        Ok(())
+    })?;
+
+    let spans = recorder.spans.lock().unwrap();
+    assert_eq!(
+        *spans,
+        vec![
+            "insert_row",
+            "insert_rows",
+            "insert_column",
+            "remove_rows",
+            "insert_row"
+        ]
+    );
+    let events = recorder.events.lock().unwrap();
+    assert_eq!(
+        events[..4],
+        [
+            "message=inserted row num_rows=3 num_columns=2",
+            "message=inserted rows num_rows=4 num_columns=2",
+            "message=inserted column num_rows=4 num_columns=3",
+            "message=removed rows num_rows=3 num_columns=3",
+        ]
+    );
+    assert_eq!(
+        events[4],
+        "error=DimensionMismatch { expected: 3, actual: 1, index: None }"
+    );
+    Ok(())
+}
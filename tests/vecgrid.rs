@@ -1,4 +1,9 @@
-use vecgrid::{Error, Vecgrid};
+use vecgrid::{
+    CenterView, ChunkedGrid, ColumnCachedVecgrid, ColumnMut, CowVecgrid, EdgeMode, Error, Grid,
+    GridIndex, GridMut, IngestError, LayeredVecgrid, Moves, Neighborhood, OffsetGrid, Parity,
+    Resampling, RowBand, Table, TrackedVecgrid, Vecgrid, VecgridRef, VecgridRefMut, VecgridView,
+    VecgridViewMut, ZobristTable,
+};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Normal Operation ////////////////////////////////////////////////////////////
@@ -21,6 +26,7 @@ fn test_from_columns() -> Result<(), Error> {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_from_row_major() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let row_major = vec![1, 2, 3, 4, 5, 6];
@@ -36,6 +42,7 @@ fn test_from_row_major() -> Result<(), Error> {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_from_column_major() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let column_major = vec![1, 4, 2, 5, 3, 6];
@@ -112,6 +119,16 @@ fn test_from_iter_column_major() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_refill_from_iter_row_major() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::filled_with(0, 2, 3);
+    vecgrid.refill_from_iter_row_major(1..)?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    vecgrid.refill_from_iter_row_major(vec![6, 5, 4, 3, 2, 1])?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+    Ok(())
+}
+
 #[test]
 fn test_dimensions() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
@@ -124,6 +141,7 @@ fn test_dimensions() -> Result<(), Error> {
 }
 
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn test_get() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let vecgrid = Vecgrid::from_rows(rows.clone())?;
@@ -160,10 +178,13 @@ fn test_get_column_major() -> Result<(), Error> {
     assert_eq!(vecgrid.get_column_major(4), Some(&3));
     assert_eq!(vecgrid.get_column_major(5), Some(&6));
     assert_eq!(vecgrid.get_column_major(6), None);
+    let no_rows = Vecgrid::<i32>::from_row_major(vec![], 0, 3)?;
+    assert_eq!(no_rows.get_column_major(0), None);
     Ok(())
 }
 
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn test_get_mut() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let mut vecgrid = Vecgrid::from_rows(rows.clone())?;
@@ -217,6 +238,22 @@ fn test_get_mut_column_major() -> Result<(), Error> {
 }
 
 #[test]
+fn test_get_unchecked() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut vecgrid = Vecgrid::from_rows(rows.clone())?;
+    for (row, column) in vecgrid.indices_row_major() {
+        assert_eq!(
+            unsafe { vecgrid.get_unchecked(row, column) },
+            &rows[row][column]
+        );
+    }
+    unsafe { *vecgrid.get_unchecked_mut(1, 0) = 40 };
+    assert_eq!(vecgrid.get(1, 0), Some(&40));
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::needless_range_loop)]
 fn test_set() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let mut vecgrid = Vecgrid::from_rows(rows.clone())?;
@@ -237,6 +274,7 @@ fn test_set() -> Result<(), Error> {
 }
 
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn test_set_row_major() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let mut vecgrid = Vecgrid::from_rows(rows.clone())?;
@@ -259,6 +297,7 @@ fn test_set_row_major() -> Result<(), Error> {
 }
 
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn test_set_column_major() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let mut vecgrid = Vecgrid::from_rows(rows.clone())?;
@@ -281,6 +320,24 @@ fn test_set_column_major() -> Result<(), Error> {
 }
 
 #[test]
+fn test_apply_updates() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::filled_with(0, 3, 3);
+    let updates: std::collections::HashMap<(usize, usize), i32> =
+        vec![((0, 0), 1), ((1, 1), 2), ((2, 2), 3)]
+            .into_iter()
+            .collect();
+    vecgrid.apply_updates(updates)?;
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![vec![1, 0, 0], vec![0, 2, 0], vec![0, 0, 3]]
+    );
+    vecgrid.apply_updates(vec![((0, 0), 4), ((0, 0), 5)])?;
+    assert_eq!(vecgrid[(0, 0)], 5);
+    Ok(())
+}
+
+#[test]
+#[allow(clippy::useless_vec)]
 fn test_elements_row_major_iter() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let row_major = vec![1, 2, 3, 4, 5, 6];
@@ -297,6 +354,7 @@ fn test_elements_row_major_iter() -> Result<(), Error> {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_elements_row_major_iter_mut() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let row_major = vec![1, 2, 3, 4, 5, 6];
@@ -326,10 +384,17 @@ fn test_elements_column_major_iter() -> Result<(), Error> {
         assert_eq!(element, &rows[row_index][column_index]);
         assert_eq!(element, &column_major[index]);
     }
+    let reversed = vecgrid.elements_column_major_iter().rev().copied();
+    assert!(reversed.eq(column_major.into_iter().rev()));
+
+    let no_rows = Vecgrid::<i32>::from_row_major(vec![], 0, 3)?;
+    assert_eq!(no_rows.elements_column_major_iter().count(), 0);
+    assert_eq!(no_rows.column_iter(2)?.count(), 0);
     Ok(())
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_elements_column_major_iter_mut() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let column_major = vec![1, 4, 2, 5, 3, 6];
@@ -485,6 +550,60 @@ fn test_op_index_mut() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_normalize() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![-1.0f32, 0.0], vec![f32::NAN, 3.0]])?;
+    vecgrid.normalize();
+    assert_eq!(vecgrid[(0, 0)], 0.0);
+    assert_eq!(vecgrid[(0, 1)], 0.25);
+    assert!(vecgrid[(1, 0)].is_nan());
+    assert_eq!(vecgrid[(1, 1)], 1.0);
+
+    let mut uniform = Vecgrid::filled_with(4.0f64, 2, 2);
+    uniform.normalize();
+    assert_eq!(uniform, Vecgrid::filled_with(0.0, 2, 2));
+    Ok(())
+}
+
+#[test]
+fn test_clamp_in_place_and_scale() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![-2.0f64, 0.5, 4.0]])?;
+    vecgrid.clamp_in_place(0.0, 1.0);
+    assert_eq!(vecgrid.as_row_major(), vec![0.0, 0.5, 1.0]);
+    vecgrid.scale(4.0);
+    assert_eq!(vecgrid.as_row_major(), vec![0.0, 2.0, 4.0]);
+    Ok(())
+}
+
+#[test]
+fn test_matmul() -> Result<(), Error> {
+    let a = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    let identity = Vecgrid::from_rows(vec![vec![1, 0], vec![0, 1]])?;
+    assert_eq!(a.matmul(&identity)?, a);
+
+    let b = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let product = a.matmul(&b)?;
+    assert_eq!(product.num_rows(), 3);
+    assert_eq!(product.num_columns(), 3);
+    for row in 0..3 {
+        for column in 0..3 {
+            assert_eq!(product[(row, column)], a.row_column_dot(row, &b, column)?);
+        }
+    }
+    assert_eq!(product.as_rows()[2], vec![29, 40, 51]);
+    Ok(())
+}
+
+#[test]
+fn test_row_and_column_dot() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]])?;
+    assert_eq!(vecgrid.row_dot(0, 1)?, 11.0);
+    assert_eq!(vecgrid.column_dot(1, 1)?, 20.0);
+    assert!(vecgrid.row_dot(0, 2).is_err());
+    assert!(vecgrid.column_dot(2, 0).is_err());
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Error Handling //////////////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////////
@@ -493,14 +612,28 @@ fn test_op_index_mut() -> Result<(), Error> {
 fn test_from_rows_not_all_same_size() {
     let rows = vec![vec![1, 2, 3], vec![4, 5]];
     let result = Vecgrid::from_rows(rows.clone());
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            actual: 2,
+            index: Some(1)
+        })
+    );
 }
 
 #[test]
 fn test_from_columns_not_all_same_size() {
     let columns = vec![vec![1, 4], vec![2, 3], vec![4]];
     let result = Vecgrid::from_columns(columns.clone());
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: Some(2)
+        })
+    );
 }
 
 #[test]
@@ -509,7 +642,14 @@ fn test_from_row_major_dimensions_do_not_match_size() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Vecgrid::from_row_major(row_major, num_rows, num_columns);
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 6,
+            actual: 7,
+            index: None
+        })
+    );
 }
 
 #[test]
@@ -518,7 +658,14 @@ fn test_from_column_major_dimensions_do_not_match_size() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Vecgrid::from_column_major(column_major, num_rows, num_columns);
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 6,
+            actual: 5,
+            index: None
+        })
+    );
 }
 
 #[test]
@@ -527,7 +674,13 @@ fn test_from_iter_row_major_not_enough() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Vecgrid::from_iter_row_major(iter, num_rows, num_columns);
-    assert_eq!(result, Err(Error::NotEnoughElements));
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
 }
 
 #[test]
@@ -536,7 +689,317 @@ fn test_from_iter_column_major_not_enough() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Vecgrid::from_iter_column_major(iter, num_rows, num_columns);
-    assert_eq!(result, Err(Error::NotEnoughElements));
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
+}
+
+#[test]
+fn test_from_iter_short_exact_size_fails_early() {
+    let mut consumed = 0;
+    let iter = vec![1, 2, 3, 4].into_iter().inspect(|_| consumed += 1);
+    let result = Vecgrid::from_iter_column_major(iter, 2, 3);
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
+    assert_eq!(consumed, 0);
+
+    let iter = (1..10).filter(|n| n % 2 == 0);
+    let result = Vecgrid::from_iter_row_major(iter, 2, 3);
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
+}
+
+#[test]
+fn test_refill_from_iter_row_major_not_enough() {
+    let mut vecgrid = Vecgrid::filled_with(0, 2, 3);
+    let result = vecgrid.refill_from_iter_row_major(1..5);
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
+    assert_eq!(vecgrid.num_rows(), 2);
+    assert_eq!(vecgrid.num_columns(), 3);
+}
+
+#[test]
+fn test_matmul_dimension_mismatch() {
+    let a = Vecgrid::filled_with(1, 2, 3);
+    let b = Vecgrid::filled_with(1, 2, 3);
+    let mismatch = Error::DimensionMismatch {
+        expected: 3,
+        actual: 2,
+        index: None,
+    };
+    assert_eq!(a.matmul(&b), Err(mismatch));
+    assert_eq!(a.row_column_dot(0, &b, 0), Err(mismatch));
+}
+
+#[test]
+fn test_apply_updates_out_of_bounds_is_all_or_nothing() {
+    let mut vecgrid = Vecgrid::filled_with(0, 2, 2);
+    let result = vecgrid.apply_updates(vec![((0, 0), 1), ((0, 2), 2), ((3, 0), 3)]);
+    assert_eq!(
+        result,
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 2,
+            num_rows: 2,
+            num_columns: 2
+        })
+    );
+    assert_eq!(vecgrid, Vecgrid::filled_with(0, 2, 2));
+}
+
+#[test]
+fn test_add_elementwise_dimension_mismatch() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::filled_with(1i32, 2, 3);
+    let result = vecgrid.add_elementwise(&Vecgrid::filled_with(1, 3, 2));
+    assert_eq!(
+        result,
+        Err(Error::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2),
+            index: None
+        })
+    );
+    assert_eq!(vecgrid, Vecgrid::filled_with(1, 2, 3));
+    Ok(())
+}
+
+#[test]
+fn test_remove_rows_out_of_bounds() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::filled_with(0, 3, 2);
+    assert_eq!(
+        vecgrid.remove_rows(2, 2),
+        Err(Error::IndexOutOfBounds { index: 4, len: 4 })
+    );
+    assert_eq!(
+        vecgrid.remove_rows(1, usize::MAX),
+        Err(Error::IndexOutOfBounds {
+            index: usize::MAX,
+            len: 4
+        })
+    );
+    assert_eq!(vecgrid.num_rows(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_error_display() {
+    let error: Box<dyn std::error::Error> = Box::new(Error::IndicesOutOfBounds {
+        row: 3,
+        column: 1,
+        num_rows: 2,
+        num_columns: 2,
+    });
+    assert_eq!(
+        error.to_string(),
+        "indices (3, 1) out of bounds for 2 rows and 2 columns"
+    );
+    let error = Error::DimensionMismatch {
+        expected: 3,
+        actual: 2,
+        index: Some(1),
+    };
+    assert_eq!(
+        error.to_string(),
+        "expected length 3 but got 2 at position 1"
+    );
+    let error = Error::ShapeMismatch {
+        expected: (2, 3),
+        actual: (3, 2),
+        index: None,
+    };
+    assert_eq!(
+        error.to_string(),
+        "expected 2 rows and 3 columns but got 3 rows and 2 columns"
+    );
+}
+
+#[test]
+fn test_mask_dimension_mismatch() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::filled_with(1, 2, 3);
+    let mask = Vecgrid::filled_with(true, 3, 2);
+    let mismatch = Error::ShapeMismatch {
+        expected: (2, 3),
+        actual: (3, 2),
+        index: None,
+    };
+    assert_eq!(vecgrid.select(&mask).err(), Some(mismatch));
+    assert_eq!(vecgrid.set_where(&mask, 0), Err(mismatch));
+    assert_eq!(vecgrid, Vecgrid::filled_with(1, 2, 3));
+    Ok(())
+}
+
+#[test]
+fn test_layered_vecgrid_errors() -> Result<(), Error> {
+    assert_eq!(
+        LayeredVecgrid::new(vec![
+            Vecgrid::filled_with(0, 2, 2),
+            Vecgrid::filled_with(0, 2, 3)
+        ]),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3),
+            index: Some(1)
+        })
+    );
+    let mut layers = LayeredVecgrid::filled_with(0, 2, 2, 2);
+    let unchanged = layers.clone();
+    assert_eq!(
+        layers.insert_row(vec![vec![1, 2]], 0),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: None
+        })
+    );
+    assert_eq!(
+        layers.insert_column(vec![vec![1, 2], vec![3]], 0),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: Some(1)
+        })
+    );
+    assert!(layers.insert_row(vec![vec![1, 2], vec![3, 4]], 5).is_err());
+    assert!(layers.remove_rows(1, 2).is_err());
+    assert_eq!(
+        layers.push_layer(Vecgrid::filled_with(0, 3, 2)),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (3, 2),
+            index: Some(2)
+        })
+    );
+    assert_eq!(
+        layers.remove_layer(2),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+        layers.column_view(2, 0).err(),
+        Some(Error::IndicesOutOfBounds {
+            row: 2,
+            column: 0,
+            num_rows: 2,
+            num_columns: 2
+        })
+    );
+    assert_eq!(layers, unchanged);
+    Ok(())
+}
+
+#[test]
+fn test_splice_rows_errors() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    assert_eq!(
+        vecgrid.splice_rows(1..3, vec![]),
+        Err(Error::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(
+        vecgrid.splice_rows(0..1, vec![vec![5, 6], vec![7]]),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: Some(1)
+        })
+    );
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 2], vec![3, 4]]);
+    Ok(())
+}
+
+#[test]
+fn test_replace_row_and_column_errors() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(
+        vecgrid.replace_row(0, vec![1, 2]),
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            actual: 2,
+            index: None
+        })
+    );
+    assert_eq!(
+        vecgrid.replace_row(2, vec![1, 2, 3]),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+        vecgrid.replace_column(0, vec![1, 2, 3]),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 3,
+            index: None
+        })
+    );
+    assert_eq!(
+        vecgrid.replace_column(3, vec![1, 2]),
+        Err(Error::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_from_slices_dimension_mismatch() {
+    let rows: &[&[i32]] = &[&[1, 2], &[3, 4], &[5]];
+    assert_eq!(
+        Vecgrid::from_row_slices(rows),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: Some(2)
+        })
+    );
+    assert_eq!(
+        Vecgrid::from_flat_slice(&[1, 2, 3], 2, 2),
+        Err(Error::DimensionMismatch {
+            expected: 4,
+            actual: 3,
+            index: None
+        })
+    );
+}
+
+#[test]
+fn test_borrowed_views_errors() {
+    let mut buffer = [0; 5];
+    assert_eq!(
+        VecgridRef::new(&buffer, 2, 3),
+        Err(Error::DimensionMismatch {
+            expected: 6,
+            actual: 5,
+            index: None
+        })
+    );
+    assert!(VecgridRefMut::new(&mut buffer, 3, 2).is_err());
+    let view = VecgridRef::new(&buffer, 1, 5).unwrap();
+    assert_eq!(
+        view.row_iter(1).err(),
+        Some(Error::IndicesOutOfBounds {
+            row: 1,
+            column: 0,
+            num_rows: 1,
+            num_columns: 5
+        })
+    );
+    assert!(view.column_iter(5).is_err());
 }
 
 #[test]
@@ -667,6 +1130,16 @@ fn test_empty_vecgrid_from_row_major_non_zero_columns() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_empty_vecgrid_rows_iter_without_columns() -> Result<(), Error> {
+    let mut vecgrid: Vecgrid<i32> = Vecgrid::from_rows(vec![vec![], vec![]])?;
+    assert_eq!(vecgrid.rows_iter().count(), 2);
+    assert_eq!(vecgrid.row_iter(1)?.count(), 0);
+    assert_eq!(vecgrid.row_iter_mut(1)?.count(), 0);
+    assert!(vecgrid.row_iter(2).is_err());
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Double-Ended Iterators //////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////////
@@ -883,6 +1356,3057 @@ fn test_remove_rows() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_concat_horizontal_and_vertical() -> Result<(), Error> {
+    let a = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    let b = Vecgrid::from_rows(vec![vec![5], vec![6]])?;
+    let wide = a.concat_horizontal(&b)?;
+    assert_eq!(wide.as_rows(), vec![vec![1, 2, 5], vec![3, 4, 6]]);
+    assert_eq!(wide.num_columns(), 3);
+
+    let c = Vecgrid::from_rows(vec![vec![7, 8, 9]])?;
+    let tall = wide.concat_vertical(&c)?;
+    assert_eq!(
+        tall.as_rows(),
+        vec![vec![1, 2, 5], vec![3, 4, 6], vec![7, 8, 9]]
+    );
+    assert_eq!(tall.num_rows(), 3);
+
+    let mismatch = Error::DimensionMismatch {
+        expected: 2,
+        actual: 1,
+        index: Some(1),
+    };
+    assert_eq!(a.concat_horizontal(&c), Err(mismatch));
+    assert_eq!(a.concat_vertical(&b), Err(mismatch));
+    Ok(())
+}
+
+#[test]
+fn test_hstack_and_vstack() -> Result<(), Error> {
+    let rooms = vec![
+        Vecgrid::filled_with(1, 2, 2),
+        Vecgrid::filled_with(2, 2, 1),
+        Vecgrid::filled_with(3, 2, 2),
+    ];
+    let row = Vecgrid::hstack(&rooms)?;
+    assert_eq!(
+        row.as_rows(),
+        vec![vec![1, 1, 2, 3, 3], vec![1, 1, 2, 3, 3]]
+    );
+
+    let map = Vecgrid::vstack(&[row.clone(), row.clone()])?;
+    assert_eq!(map.num_rows(), 4);
+    assert_eq!(map.num_columns(), 5);
+    assert_eq!(map.as_rows()[3], vec![1, 1, 2, 3, 3]);
+
+    let empty: Vec<Vecgrid<i32>> = vec![];
+    assert_eq!(Vecgrid::hstack(&empty)?.num_elements(), 0);
+    assert!(Vecgrid::vstack(&rooms).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_tiled() -> Result<(), Error> {
+    let tile = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    let vecgrid = tile.tiled(2, 3);
+    assert_eq!(vecgrid.num_rows(), 4);
+    assert_eq!(vecgrid.num_columns(), 6);
+    assert_eq!(vecgrid.as_rows()[1], vec![3, 4, 3, 4, 3, 4]);
+    assert_eq!(vecgrid.as_rows()[2], vec![1, 2, 1, 2, 1, 2]);
+    assert_eq!(tile.tiled(0, 3).num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_transaction_commit() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    let mut transaction = vecgrid.transaction();
+    transaction.set(0, 0, 10)?;
+    transaction.set_row_major(3, 40)?;
+    assert_eq!(transaction.get(0, 0), Some(&10));
+    assert_eq!(transaction.num_changes(), 2);
+    transaction.commit();
+    assert_eq!(vecgrid.as_rows(), vec![vec![10, 2], vec![3, 40]]);
+    Ok(())
+}
+
+#[test]
+fn test_transaction_rollback() -> Result<(), Error> {
+    let rows = vec![vec!["a".to_string(), "b".to_string()]];
+    let mut vecgrid = Vecgrid::from_rows(rows.clone())?;
+    let mut transaction = vecgrid.transaction();
+    transaction.set(0, 0, "x".to_string())?;
+    transaction.set(0, 0, "y".to_string())?;
+    transaction.set(0, 1, "z".to_string())?;
+    assert_eq!(
+        transaction.set(1, 0, "w".to_string()),
+        Err(Error::IndicesOutOfBounds {
+            row: 1,
+            column: 0,
+            num_rows: 1,
+            num_columns: 2
+        })
+    );
+    assert_eq!(
+        transaction.set_row_major(2, "w".to_string()),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    transaction.rollback();
+    assert_eq!(vecgrid.as_rows(), rows);
+
+    {
+        let mut transaction = vecgrid.transaction();
+        transaction.set(0, 1, "dropped".to_string())?;
+    }
+    assert_eq!(vecgrid.as_rows(), rows);
+    Ok(())
+}
+
+#[test]
+fn test_tracked_vecgrid_dirty_region() -> Result<(), Error> {
+    let mut tracked = TrackedVecgrid::new(Vecgrid::filled_with(0, 5, 5));
+    assert_eq!(tracked.take_dirty_region(), None);
+
+    tracked.set(3, 1, 1)?;
+    *tracked.get_mut(1, 3).unwrap() = 2;
+    assert_eq!(tracked.take_dirty_region(), Some((1..4, 1..4)));
+
+    for element in tracked.row_iter_mut(4)? {
+        *element = 3;
+    }
+    assert_eq!(tracked.take_dirty_region(), Some((4..5, 0..5)));
+
+    for element in tracked.column_iter_mut(0)? {
+        *element = 4;
+    }
+    assert_eq!(tracked.take_dirty_region(), Some((0..5, 0..1)));
+
+    assert!(tracked.set(5, 5, 0).is_err());
+    assert_eq!(tracked.get_mut(5, 0), None);
+    assert_eq!(tracked.take_dirty_region(), None);
+
+    tracked
+        .elements_row_major_iter_mut()
+        .for_each(|element| *element += 1);
+    assert_eq!(tracked.take_dirty_region(), Some((0..5, 0..5)));
+
+    assert_eq!(tracked[(3, 1)], 2);
+    assert_eq!(tracked.num_rows(), 5);
+    let vecgrid = tracked.into_inner();
+    assert_eq!(vecgrid[(4, 4)], 4);
+    Ok(())
+}
+
+#[test]
+fn test_cow_vecgrid_copies_on_write() -> Result<(), Error> {
+    let root = CowVecgrid::from(Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?);
+    let mut child = root.clone();
+    assert!(root.is_shared());
+    assert!(child.ptr_eq(&root));
+
+    assert!(child.set(2, 0, 9).is_err());
+    assert!(child.ptr_eq(&root));
+
+    child[(0, 0)] = 10;
+    assert!(!child.ptr_eq(&root));
+    assert!(!root.is_shared());
+    assert_eq!(root.as_rows(), vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(child.as_rows(), vec![vec![10, 2], vec![3, 4]]);
+
+    child.to_mut().set(1, 1, 40)?;
+    assert_eq!(
+        child.into_vecgrid().as_rows(),
+        vec![vec![10, 2], vec![3, 40]]
+    );
+    assert_eq!(root.into_vecgrid().as_rows(), vec![vec![1, 2], vec![3, 4]]);
+    Ok(())
+}
+
+#[test]
+fn test_zobrist_hash() -> Result<(), Error> {
+    let table = ZobristTable::new(2, 2, 2, 7);
+    let state = |cell: &char| match cell {
+        'x' => Some(0),
+        'o' => Some(1),
+        _ => None,
+    };
+    let mut board = Vecgrid::filled_with('.', 2, 2);
+    assert_eq!(board.zobrist_hash(&table, state)?, 0);
+
+    board[(0, 1)] = 'x';
+    board[(1, 0)] = 'o';
+    let hash = board.zobrist_hash(&table, state)?;
+    let expected = table.toggle(table.toggle(0, 0, 1, 0)?, 1, 0, 1)?;
+    assert_eq!(hash, expected);
+    assert_eq!(table.toggle(hash, 0, 1, 0)?, table.key(1, 0, 1).unwrap());
+
+    assert!(table.toggle(hash, 2, 0, 0).is_err());
+    assert_eq!(
+        table.toggle(hash, 1, 1, 2),
+        Err(Error::StateOutOfBounds {
+            state: 2,
+            num_states: 2
+        })
+    );
+    assert_eq!(table.key(0, 0, 2), None);
+    let wrong_size = Vecgrid::filled_with('.', 3, 2);
+    assert_eq!(
+        wrong_size.zobrist_hash(&table, state),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (3, 2),
+            index: None
+        })
+    );
+    assert_eq!(
+        board.zobrist_hash(&table, |_| Some(5)),
+        Err(Error::StateOutOfBounds {
+            state: 5,
+            num_states: 2
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fingerprint() -> Result<(), Error> {
+    let a = Vecgrid::from_rows(vec![vec!["a".to_string(), "b".to_string()]])?;
+    let mut b = a.clone();
+    assert_eq!(a.fingerprint(), b.fingerprint());
+    b[(0, 1)] = "c".to_string();
+    assert_ne!(a.fingerprint(), b.fingerprint());
+    Ok(())
+}
+
+#[test]
+fn test_partial_eq_nested() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(vecgrid, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], vecgrid);
+    assert_eq!(vecgrid, [[1, 2, 3], [4, 5, 6]]);
+    assert!(vecgrid == *vec![vec![1, 2, 3], vec![4, 5, 6]].as_slice());
+    let slices: &[&[i32]] = &[&[1, 2, 3], &[4, 5, 6]];
+    assert!(vecgrid == *slices);
+
+    assert_ne!(vecgrid, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    assert_ne!(vecgrid, vec![vec![1, 2, 3]]);
+    assert_ne!(vecgrid, vec![vec![1, 2, 3], vec![4, 5, 7]]);
+    assert_ne!(vec![vec![1, 2, 3, 4], vec![5, 6]], vecgrid);
+
+    let strings = Vecgrid::from_rows(vec![vec!["a".to_string()]])?;
+    assert_eq!(strings, vec![vec!["a"]]);
+
+    let no_columns: Vecgrid<i32> = Vecgrid::from_rows(vec![vec![], vec![]])?;
+    assert_eq!(no_columns, vec![vec![], vec![]]);
+    assert_ne!(no_columns, vec![vec![], vec![1]]);
+    assert_ne!(vec![vec![], vec![1]], no_columns);
+    Ok(())
+}
+
+#[test]
+fn test_astar() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![vec![1, 1, 1, 1], vec![0, 0, 9, 1], vec![1, 1, 1, 1]])?;
+    let cost = |cell: &u32| (*cell > 0).then_some(*cell);
+    let (total, path) = map.astar((0, 0), (2, 0), cost, |_| 0)?.unwrap();
+    assert_eq!(total, 8);
+    assert_eq!(path.first(), Some(&(0, 0)));
+    assert_eq!(path.last(), Some(&(2, 0)));
+    assert!(!path.contains(&(1, 2)));
+    for step in path.windows(2) {
+        let (a, b) = (step[0], step[1]);
+        assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+    }
+
+    assert_eq!(
+        map.astar((0, 0), (0, 0), cost, |_| 0)?,
+        Some((0, vec![(0, 0)]))
+    );
+    let walled = Vecgrid::from_rows(vec![vec![1, 0, 1]])?;
+    assert_eq!(walled.astar((0, 0), (0, 2), cost, |_| 0)?, None);
+    assert_eq!(
+        walled.astar((0, 0), (0, 3), cost, |_| 0),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 3,
+            num_rows: 1,
+            num_columns: 3
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fill() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    vecgrid.fill(9);
+    assert_eq!(vecgrid, Vecgrid::filled_with(9, 2, 3));
+    Ok(())
+}
+
+#[test]
+fn test_bulk_sum() -> Result<(), Error> {
+    let bytes = Vecgrid::from_iter_row_major((0..=255u8).cycle(), 7, 53)?;
+    let expected = bytes
+        .elements_row_major_iter()
+        .map(|&x| x as u64)
+        .sum::<u64>();
+    assert_eq!(bytes.sum(), expected);
+    let integers = Vecgrid::from_iter_row_major(-20i32..20, 5, 8)?;
+    assert_eq!(integers.sum(), -20);
+    let floats = Vecgrid::from_iter_row_major((0..).map(|x| x as f32 * 0.5), 3, 17)?;
+    assert_eq!(floats.sum(), 637.5);
+    assert_eq!(Vecgrid::<u8>::from_row_major(vec![], 0, 0)?.sum(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_add_elementwise() -> Result<(), Error> {
+    let mut bytes = Vecgrid::from_rows(vec![vec![1u8, 255], vec![3, 4]])?;
+    bytes.add_elementwise(&Vecgrid::filled_with(1, 2, 2))?;
+    assert_eq!(bytes.as_rows(), vec![vec![2, 0], vec![4, 5]]);
+
+    let mut floats = Vecgrid::from_rows(vec![vec![0.5f32, 1.5]])?;
+    floats.add_elementwise(&Vecgrid::from_rows(vec![vec![1.0, -1.0]])?)?;
+    assert_eq!(floats.as_rows(), vec![vec![1.5, 0.5]]);
+    Ok(())
+}
+
+#[test]
+fn test_distance_field() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![vec![1, 1, 1, 1], vec![1, 0, 0, 1], vec![1, 1, 0, 0]])?;
+    let distances = map.distance_field(&[(0, 0), (2, 1)], |cell| *cell == 1)?;
+    assert_eq!(
+        distances.as_rows(),
+        vec![
+            vec![Some(0), Some(1), Some(2), Some(3)],
+            vec![Some(1), None, None, Some(4)],
+            vec![Some(1), Some(0), None, None],
+        ]
+    );
+
+    let unreachable = map.distance_field(&[], |_| true)?;
+    assert!(unreachable.elements_row_major_iter().all(Option::is_none));
+    assert_eq!(
+        map.distance_field(&[(3, 0)], |_| true),
+        Err(Error::IndicesOutOfBounds {
+            row: 3,
+            column: 0,
+            num_rows: 3,
+            num_columns: 4
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_row_bands() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_iter_row_major(0.., 5, 2)?;
+    let bands = vecgrid.row_bands(2).collect::<Vec<RowBand<_>>>();
+    let shapes = bands
+        .iter()
+        .map(|band| (band.first_row(), band.num_rows(), band.num_columns()))
+        .collect::<Vec<_>>();
+    assert_eq!(shapes, vec![(0, 2, 2), (2, 2, 2), (4, 1, 2)]);
+    assert_eq!(bands[1].as_row_major_slice(), &[4, 5, 6, 7]);
+    assert_eq!(bands[1].get(1, 0), Some(&6));
+    assert_eq!(bands[2].get(1, 0), None);
+
+    for mut band in bands {
+        band[(0, 1)] = -1;
+    }
+    assert_eq!(
+        vecgrid.column_iter(1)?.copied().collect::<Vec<_>>(),
+        vec![-1, 3, -1, 7, -1]
+    );
+
+    let mut empty = Vecgrid::<i32>::from_row_major(vec![], 3, 0)?;
+    assert_eq!(
+        empty
+            .row_bands(2)
+            .map(|band| band.num_rows())
+            .sum::<usize>(),
+        3
+    );
+    Ok(())
+}
+
+#[test]
+fn test_for_each_row_parallel() -> Result<(), Error> {
+    let expected = Vecgrid::from_rows(vec![vec![0, 1, 2], vec![10, 11, 12], vec![20, 21, 22]])?;
+    for num_threads in 1..5 {
+        let mut vecgrid = Vecgrid::filled_with(0, 3, 3);
+        vecgrid.for_each_row_parallel(num_threads, |row_index, row| {
+            for (column_index, element) in row.iter_mut().enumerate() {
+                *element = row_index * 10 + column_index;
+            }
+        });
+        assert_eq!(vecgrid, expected);
+    }
+
+    let mut empty = Vecgrid::<i32>::from_row_major(vec![], 3, 0)?;
+    let calls = std::sync::atomic::AtomicUsize::new(0);
+    empty.for_each_row_parallel(2, |_, row| {
+        assert!(row.is_empty());
+        calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    });
+    assert_eq!(calls.into_inner(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_line_iter() -> Result<(), Error> {
+    let vecgrid = Vecgrid::filled_with(0, 5, 7);
+    for from in vecgrid.indices_row_major() {
+        for to in vecgrid.indices_row_major() {
+            let line = vecgrid.line_iter(from, to)?.collect::<Vec<_>>();
+            let length = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)) + 1;
+            assert_eq!(line.len(), length);
+            assert_eq!(line.first(), Some(&from));
+            assert_eq!(line.last(), Some(&to));
+            for step in line.windows(2) {
+                assert!(step[0].0.abs_diff(step[1].0) <= 1);
+                assert!(step[0].1.abs_diff(step[1].1) <= 1);
+            }
+        }
+    }
+    assert!(vecgrid.line_iter((0, 0), (5, 0)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_has_line_of_sight() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![vec![0, 0, 0, 0], vec![0, 1, 1, 0], vec![0, 0, 0, 0]])?;
+    let wall = |cell: &i32| *cell == 1;
+    assert!(map.has_line_of_sight((0, 0), (0, 3), wall)?);
+    assert!(map.has_line_of_sight((1, 0), (1, 1), wall)?);
+    assert!(!map.has_line_of_sight((1, 0), (1, 3), wall)?);
+    assert!(!map.has_line_of_sight((0, 1), (2, 1), wall)?);
+    assert!(map.has_line_of_sight((2, 2), (2, 2), wall)?);
+    assert_eq!(
+        map.has_line_of_sight((0, 0), (3, 0), wall),
+        Err(Error::IndicesOutOfBounds {
+            row: 3,
+            column: 0,
+            num_rows: 3,
+            num_columns: 4
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_column_major_constructors_without_clone() -> Result<(), Error> {
+    #[derive(Debug, PartialEq)]
+    struct NoClone(i32);
+
+    let expected = vec![vec![1, 3, 5], vec![2, 4, 6]];
+    let values = |vecgrid: Vecgrid<NoClone>| {
+        vecgrid
+            .rows_iter()
+            .map(|row| row.map(|element| element.0).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+
+    let columns = vec![
+        vec![NoClone(1), NoClone(2)],
+        vec![NoClone(3), NoClone(4)],
+        vec![NoClone(5), NoClone(6)],
+    ];
+    assert_eq!(values(Vecgrid::from_columns(columns)?), expected);
+
+    let column_major = (1..=6).map(NoClone).collect();
+    assert_eq!(
+        values(Vecgrid::from_column_major(column_major, 2, 3)?),
+        expected
+    );
+    assert_eq!(
+        values(Vecgrid::from_iter_column_major((1..).map(NoClone), 2, 3)?),
+        expected
+    );
+
+    let mut counter = 0;
+    let generator = || {
+        counter += 1;
+        NoClone(counter)
+    };
+    assert_eq!(
+        values(Vecgrid::filled_by_column_major(generator, 2, 3)),
+        expected
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_column_major_permutation() -> Result<(), Error> {
+    for num_rows in 0..6 {
+        for num_columns in 0..6 {
+            let column_major = (0..num_rows * num_columns).collect::<Vec<_>>();
+            let vecgrid = Vecgrid::from_column_major(column_major, num_rows, num_columns)?;
+            for (row, column) in vecgrid.indices_row_major() {
+                assert_eq!(vecgrid[(row, column)], column * num_rows + row);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_grid_traits() -> Result<(), Error> {
+    fn row_sums<G: Grid<Element = i32>>(grid: &G) -> Vec<i32> {
+        let mut sums = vec![0; grid.num_rows()];
+        for ((row, _), element) in grid.enumerate_row_major() {
+            sums[row] += element;
+        }
+        sums
+    }
+
+    fn increment_all<G: GridMut<Element = i32>>(grid: &mut G) -> Result<(), Error> {
+        let indices = grid.indices_row_major().collect::<Vec<_>>();
+        for (row, column) in indices {
+            let element = grid.get(row, column).copied().unwrap_or_default();
+            grid.set(row, column, element + 1)?;
+        }
+        Ok(())
+    }
+
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])?;
+    assert_eq!(row_sums(&vecgrid), vec![6, 15, 24]);
+    assert_eq!(Grid::num_elements(&vecgrid), 9);
+
+    let mut array = [[1, 2, 3], [4, 5, 6]];
+    assert_eq!(row_sums(&array), vec![6, 15]);
+    increment_all(&mut array)?;
+    assert_eq!(array, [[2, 3, 4], [5, 6, 7]]);
+    assert_eq!(
+        GridMut::set(&mut array, 2, 0, 0),
+        Err(Error::IndicesOutOfBounds {
+            row: 2,
+            column: 0,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+
+    let cow = CowVecgrid::new(vecgrid.clone());
+    assert_eq!(row_sums(&cow), vec![6, 15, 24]);
+    let mut tracked = TrackedVecgrid::new(vecgrid.clone());
+    increment_all(&mut tracked)?;
+    assert_eq!(row_sums(&tracked), vec![9, 18, 27]);
+
+    for mut band in vecgrid.row_bands(2) {
+        increment_all(&mut band)?;
+    }
+    assert_eq!(row_sums(&vecgrid), vec![9, 18, 27]);
+    Ok(())
+}
+
+#[test]
+fn test_masks() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let even = vecgrid.mask_from(|&element| element % 2 == 0);
+    assert_eq!(
+        even.as_rows(),
+        vec![vec![false, true, false], vec![true, false, true]]
+    );
+    assert_eq!(
+        vecgrid.select(&even)?.copied().collect::<Vec<_>>(),
+        vec![2, 4, 6]
+    );
+    vecgrid.set_where(&even, 0)?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 0, 3], vec![0, 5, 0]]);
+    Ok(())
+}
+
+#[test]
+fn test_count_where_and_value_counts() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 2], vec![3, 2, 1]])?;
+    assert_eq!(vecgrid.count_where(|&element| element > 1), 4);
+    assert_eq!(vecgrid.count_where(|&element| element > 3), 0);
+
+    let counts = vecgrid.value_counts();
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&1], 2);
+    assert_eq!(counts[&2], 3);
+    assert_eq!(counts[&3], 1);
+
+    let empty = Vecgrid::<i32>::from_row_major(vec![], 0, 0)?;
+    assert!(empty.value_counts().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_positions_of() -> Result<(), Error> {
+    let board = Vecgrid::from_rows(vec![vec![1, 0, 2], vec![0, 1, 1]])?;
+    assert_eq!(
+        board.positions_of(&1).collect::<Vec<_>>(),
+        vec![(0, 0), (1, 1), (1, 2)]
+    );
+    assert_eq!(board.positions_of(&1).next_back(), Some((1, 2)));
+    assert_eq!(board.positions_of(&3).count(), 0);
+    assert_eq!(board.first_position_of(&0), Some((0, 1)));
+    assert_eq!(board.first_position_of(&3), None);
+    Ok(())
+}
+
+#[test]
+fn test_rle_round_trip() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![0, 0, 1], vec![1, 1, 0], vec![0, 0, 0]])?;
+    let rle = vecgrid.to_rle();
+    assert_eq!(rle.num_rows(), 3);
+    assert_eq!(rle.num_columns(), 3);
+    assert_eq!(rle.runs(), &[(0, 2), (1, 3), (0, 4)]);
+    assert_eq!(Vecgrid::from_rle(rle)?, vecgrid);
+
+    let empty = Vecgrid::<i32>::from_row_major(vec![], 0, 4)?;
+    let rle = empty.to_rle();
+    assert!(rle.runs().is_empty());
+    assert_eq!(Vecgrid::from_rle(rle)?, empty);
+    Ok(())
+}
+
+#[test]
+fn test_write_pgm_and_ppm() -> Result<(), Box<dyn std::error::Error>> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![0, 1, 2], vec![3, 4, 5]])?;
+
+    let mut pgm = Vec::new();
+    vecgrid.write_pgm(&mut pgm, |&element| element * 50)?;
+    let mut expected = b"P5\n3 2\n255\n".to_vec();
+    expected.extend([0, 50, 100, 150, 200, 250]);
+    assert_eq!(pgm, expected);
+
+    let mut ppm = Vec::new();
+    vecgrid.write_ppm(&mut ppm, |&element| [element, 0, 255 - element])?;
+    let mut expected = b"P6\n3 2\n255\n".to_vec();
+    for element in 0..6 {
+        expected.extend([element, 0, 255 - element]);
+    }
+    assert_eq!(ppm, expected);
+    Ok(())
+}
+
+#[test]
+fn test_grid_index() -> Result<(), Error> {
+    #[derive(Clone, Copy)]
+    struct Point {
+        x: usize,
+        y: usize,
+    }
+
+    impl From<Point> for GridIndex {
+        fn from(point: Point) -> Self {
+            GridIndex::new(point.y, point.x)
+        }
+    }
+
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let point = Point { x: 2, y: 1 };
+    assert_eq!(vecgrid[point], 6);
+    assert_eq!(vecgrid[[0, 2]], 3);
+    assert_eq!(vecgrid[GridIndex::new(1, 0)], 4);
+    vecgrid[point] = 60;
+    assert_eq!(vecgrid.get_at(point), Some(&60));
+    assert_eq!(vecgrid.get_at([2, 0]), None);
+    *vecgrid.get_mut_at([0, 0]).unwrap() = 10;
+    vecgrid.set_at(Point { x: 1, y: 0 }, 20)?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![10, 20, 3], vec![4, 5, 60]]);
+    assert_eq!(
+        vecgrid.set_at([0, 3], 0),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(<(usize, usize)>::from(GridIndex::new(1, 2)), (1, 2));
+
+    let cow = CowVecgrid::new(vecgrid.clone());
+    assert_eq!(cow[[1, 2]], 60);
+    let mut tracked = TrackedVecgrid::new(vecgrid.clone());
+    tracked[point] = 6;
+    assert_eq!(tracked.take_dirty_region(), Some((1..2, 2..3)));
+    for mut band in vecgrid.row_bands(1) {
+        band[[0, 0]] = 0;
+    }
+    assert_eq!(
+        vecgrid.column_iter(0)?.copied().collect::<Vec<_>>(),
+        vec![0, 0]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_offset_grid() -> Result<(), Error> {
+    let mut world = OffsetGrid::filled_with(0, -2..3, -1..1);
+    assert_eq!(world.num_rows(), 2);
+    assert_eq!(world.num_columns(), 5);
+    assert_eq!(world.x_range(), -2..3);
+    assert_eq!(world.y_range(), -1..1);
+
+    *world.get_logical_mut(-2, -1).unwrap() = 1;
+    *world.get_logical_mut(0, 0).unwrap() = 2;
+    assert_eq!(world.get_logical(0, 0), Some(&2));
+    assert_eq!(world.get_logical(3, 0), None);
+    assert_eq!(world.get_logical(-3, 0), None);
+    assert_eq!(world.get_logical(isize::MIN, isize::MAX), None);
+    assert_eq!(world.to_indices(0, 0), Some((1, 2)));
+    assert_eq!(world.to_logical(1, 2), (0, 0));
+    assert_eq!(
+        world.as_rows(),
+        vec![vec![1, 0, 0, 0, 0], vec![0, 0, 2, 0, 0]]
+    );
+
+    world.set(0, 4, 3)?;
+    assert_eq!(world.get_logical(2, -1), Some(&3));
+
+    let world = OffsetGrid::new(Vecgrid::filled_with(7, 1, 1), 10, -10);
+    assert_eq!(world.get_logical(10, -10), Some(&7));
+    assert_eq!(world.into_inner(), Vecgrid::filled_with(7, 1, 1));
+    Ok(())
+}
+
+#[test]
+fn test_chunked_grid() -> Result<(), Error> {
+    let mut world = ChunkedGrid::new(2, 3, 0);
+    assert_eq!(world.chunk_rows(), 2);
+    assert_eq!(world.chunk_columns(), 3);
+    assert_eq!(world.get(-100, 100), &0);
+    assert_eq!(world.num_chunks(), 0);
+
+    world.set(-1, -1, 1);
+    world.set(-3, -2, 2);
+    world.set(0, 0, 3);
+    *world.get_mut(2, 1) += 4;
+    assert_eq!(world.num_chunks(), 2);
+    assert_eq!(world.get(-1, -1), &1);
+    assert_eq!(world.get(-3, -2), &2);
+    assert_eq!(world.get(-2, -1), &0);
+    assert_eq!(world.chunk_coordinates(-1, -1), (-1, -1));
+    assert_eq!(world.chunk_coordinates(-4, -3), (-2, -2));
+    assert_eq!(
+        world.chunk(-1, -1).map(Vecgrid::as_rows),
+        Some(vec![vec![2, 0, 0], vec![0, 0, 1]])
+    );
+    assert_eq!(
+        world.chunk(0, 0).map(Vecgrid::as_rows),
+        Some(vec![vec![3, 0, 0], vec![0, 0, 4]])
+    );
+    world.chunk_mut(0, 0).unwrap()[(0, 1)] = 5;
+    assert_eq!(world.get(1, 0), &5);
+
+    let mut coordinates = world
+        .chunks()
+        .map(|(coordinates, _)| coordinates)
+        .collect::<Vec<_>>();
+    coordinates.sort();
+    assert_eq!(coordinates, vec![(-1, -1), (0, 0)]);
+
+    let removed = world.remove_chunk(0, 0);
+    assert_eq!(removed.map(|chunk| chunk[(0, 0)]), Some(3));
+    assert_eq!(world.get(0, 0), &0);
+    assert_eq!(
+        world.insert_chunk(4, 4, Vecgrid::filled_with(9, 2, 3))?,
+        None
+    );
+    assert_eq!(world.get(12, 8), &9);
+    assert_eq!(
+        world.insert_chunk(0, 0, Vecgrid::filled_with(9, 2, 2)),
+        Err(Error::ShapeMismatch {
+            expected: (2, 3),
+            actual: (2, 2),
+            index: None
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_layered_vecgrid() -> Result<(), Error> {
+    let mut layers = LayeredVecgrid::filled_with(0, 2, 2, 2);
+    assert_eq!(layers.num_layers(), 2);
+    assert_eq!((layers.num_rows(), layers.num_columns()), (2, 2));
+    layers.layer_mut(1).unwrap()[(0, 1)] = 5;
+    assert_eq!(
+        layers.column_view(0, 1)?.copied().collect::<Vec<_>>(),
+        vec![0, 5]
+    );
+    assert!(layers.layer_mut(2).is_none());
+
+    layers.insert_row(vec![vec![1, 2], vec![3, 4]], 0)?;
+    layers.insert_column(vec![vec![7, 7, 7], vec![8, 8, 8]], 1)?;
+    assert_eq!(
+        layers.layer(0).map(Vecgrid::as_rows),
+        Some(vec![vec![1, 7, 2], vec![0, 7, 0], vec![0, 7, 0]])
+    );
+    assert_eq!(
+        layers.layer(1).map(Vecgrid::as_rows),
+        Some(vec![vec![3, 8, 4], vec![0, 8, 5], vec![0, 8, 0]])
+    );
+
+    layers.remove_rows(1, 2)?;
+    assert_eq!((layers.num_rows(), layers.num_columns()), (1, 3));
+    layers.push_layer(Vecgrid::filled_with(9, 1, 3))?;
+    assert_eq!(
+        layers.column_view(0, 2)?.copied().collect::<Vec<_>>(),
+        vec![2, 4, 9]
+    );
+    assert_eq!(
+        layers.remove_layer(0)?,
+        Vecgrid::from_rows(vec![vec![1, 7, 2]])?
+    );
+    assert_eq!(layers.into_layers().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_mask_operators() -> Result<(), Error> {
+    let a = Vecgrid::from_rows(vec![vec![true, true], vec![false, false]])?;
+    let b = Vecgrid::from_rows(vec![vec![true, false], vec![true, false]])?;
+    assert_eq!(
+        (&a & &b)?.as_rows(),
+        vec![vec![true, false], vec![false, false]]
+    );
+    assert_eq!(
+        (&a | &b)?.as_rows(),
+        vec![vec![true, true], vec![true, false]]
+    );
+    assert_eq!(
+        (&a ^ &b)?.as_rows(),
+        vec![vec![false, true], vec![true, false]]
+    );
+    assert_eq!((!&a).as_rows(), vec![vec![false, false], vec![true, true]]);
+    assert_eq!((a.clone() & &b)?, (&a & &b)?);
+    assert_eq!(!!a.clone(), a);
+
+    assert!(a.any());
+    assert!(!a.all());
+    assert!(!(&a & &!&a)?.any());
+    assert!((&a | &!&a)?.all());
+    let empty = Vecgrid::<bool>::from_row_major(vec![], 0, 0)?;
+    assert!(!empty.any());
+    assert!(empty.all());
+
+    let mismatch = Vecgrid::filled_with(true, 2, 3);
+    assert_eq!(
+        &a & &mismatch,
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 3),
+            index: None
+        })
+    );
+    assert!((a | &mismatch).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_eq_elementwise_by() -> Result<(), Error> {
+    let a = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let b = Vecgrid::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.5]])?;
+    let close = |a: &i32, b: &f64| (f64::from(*a) - b).abs() <= 0.5;
+    assert!(a.eq_elementwise_by(&b, close)?);
+    assert!(!a.eq_elementwise_by(&b, |a, b| f64::from(*a) == *b)?);
+
+    let calls = std::cell::Cell::new(0);
+    let unequal = a.eq_elementwise_by(&a, |x, y| {
+        calls.set(calls.get() + 1);
+        x != y
+    })?;
+    assert!(!unequal);
+    assert_eq!(calls.get(), 1);
+
+    let transposed = Vecgrid::filled_with(0.0, 3, 2);
+    assert_eq!(
+        a.eq_elementwise_by(&transposed, close),
+        Err(Error::ShapeMismatch {
+            expected: (2, 3),
+            actual: (3, 2),
+            index: None
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_into_rows_and_columns() -> Result<(), Error> {
+    let rows = vec![
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        vec!["d".to_string(), "e".to_string(), "f".to_string()],
+    ];
+    let vecgrid = Vecgrid::from_rows(rows.clone())?;
+    assert_eq!(vecgrid.clone().into_rows(), vecgrid.as_rows());
+    assert_eq!(vecgrid.clone().into_columns(), vecgrid.as_columns());
+    assert_eq!(vecgrid.clone().into_row_major(), vecgrid.as_row_major());
+    assert_eq!(
+        vecgrid.clone().into_column_major(),
+        vecgrid.as_column_major()
+    );
+    assert_eq!(vecgrid.into_rows(), rows);
+
+    for (num_rows, num_columns) in [(0, 0), (0, 3), (3, 0), (1, 4), (4, 1), (3, 5)] {
+        let vecgrid = Vecgrid::from_iter_row_major(0.., num_rows, num_columns)?;
+        assert_eq!(vecgrid.clone().into_rows(), vecgrid.as_rows());
+        assert_eq!(vecgrid.clone().into_columns(), vecgrid.as_columns());
+        assert_eq!(
+            vecgrid.clone().into_column_major(),
+            vecgrid.as_column_major()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_take_rows() -> Result<(), Error> {
+    let rows = vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+    let mut vecgrid = Vecgrid::from_rows(rows)?;
+    assert_eq!(vecgrid.take_rows(1, 2)?, vec![vec![3, 4], vec![5, 6]]);
+    assert_eq!(vecgrid.num_rows(), 2);
+    assert_eq!(vecgrid.take_row(1)?, vec![7, 8]);
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 2]]);
+    assert_eq!(vecgrid.take_rows(0, 0)?, Vec::<Vec<i32>>::new());
+    assert_eq!(
+        vecgrid.take_row(1),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(vecgrid.take_row(0)?, vec![1, 2]);
+    assert_eq!(vecgrid.num_rows(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_splice_rows() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    vecgrid.splice_rows(1..=1, vec![vec![7, 8], vec![9, 10]])?;
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![vec![1, 2], vec![7, 8], vec![9, 10], vec![5, 6]]
+    );
+    vecgrid.splice_rows(4.., vec![vec![11, 12]])?;
+    vecgrid.splice_rows(0..0, vec![vec![0, 0]])?;
+    assert_eq!(vecgrid.num_rows(), 6);
+    vecgrid.splice_rows(1..5, vec![])?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![0, 0], vec![11, 12]]);
+    vecgrid.splice_rows(.., vec![vec![1, 1]])?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 1]]);
+    Ok(())
+}
+
+#[test]
+fn test_replace_row_and_column() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(vecgrid.replace_row(0, vec![7, 8, 9])?, vec![1, 2, 3]);
+    assert_eq!(vecgrid.replace_column(2, vec![0, 0])?, vec![9, 6]);
+    assert_eq!(vecgrid.as_rows(), vec![vec![7, 8, 0], vec![4, 5, 0]]);
+    Ok(())
+}
+
+#[test]
+fn test_copy_row_and_column_from_slice() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::filled_with(0, 3, 2);
+    vecgrid.copy_row_from_slice(0, &[1, 2])?;
+    vecgrid.copy_column_from_slice(1, &[3, 4, 5])?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 3], vec![0, 4], vec![0, 5]]);
+
+    assert_eq!(
+        vecgrid.copy_row_from_slice(0, &[1, 2, 3]),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 3,
+            index: None
+        })
+    );
+    assert_eq!(
+        vecgrid.copy_row_from_slice(3, &[1, 2]),
+        Err(Error::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(
+        vecgrid.copy_column_from_slice(0, &[1, 2]),
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            actual: 2,
+            index: None
+        })
+    );
+    assert_eq!(
+        vecgrid.copy_column_from_slice(2, &[1, 2, 3]),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 3], vec![0, 4], vec![0, 5]]);
+    Ok(())
+}
+
+#[test]
+fn test_from_slices() -> Result<(), Error> {
+    let rows: &[&[&str]] = &[&["a", "b"], &["c", "d"], &["e", "f"]];
+    let vecgrid = Vecgrid::from_row_slices(rows)?;
+    assert_eq!(vecgrid.num_rows(), 3);
+    assert_eq!(vecgrid, *rows);
+    assert_eq!(Vecgrid::try_from(rows)?, vecgrid);
+
+    let flat = ["a", "b", "c", "d", "e", "f"];
+    assert_eq!(Vecgrid::from_flat_slice(&flat, 3, 2)?, vecgrid);
+
+    let no_rows: &[&[i32]] = &[];
+    assert_eq!(Vecgrid::from_row_slices(no_rows)?.num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_borrowed_views() -> Result<(), Error> {
+    let buffer = [1, 2, 3, 4, 5, 6];
+    let view = VecgridRef::new(&buffer, 3, 2)?;
+    assert_eq!(view.num_elements(), 6);
+    assert_eq!(view.get(2, 1), Some(&6));
+    assert_eq!(view.get(3, 0), None);
+    assert_eq!(view.row_iter(1)?.copied().collect::<Vec<_>>(), vec![3, 4]);
+    assert_eq!(
+        view.column_iter(0)?.copied().collect::<Vec<_>>(),
+        vec![1, 3, 5]
+    );
+    assert_eq!(
+        view.rows_iter().collect::<Vec<_>>(),
+        vec![&[1, 2], &[3, 4], &[5, 6]]
+    );
+    let vecgrid = view.to_vecgrid();
+    assert_eq!(vecgrid.as_view(), view);
+    assert_eq!(Grid::get(&view, 0, 1), Some(&2));
+
+    let mut buffer = vec![0; 6];
+    let mut view = VecgridRefMut::new(&mut buffer, 2, 3)?;
+    for element in view.column_iter_mut(1)? {
+        *element = 1;
+    }
+    for element in view.row_iter_mut(1)? {
+        *element += 2;
+    }
+    view[[0, 2]] = 7;
+    assert_eq!(
+        view.as_view().row_iter(0)?.copied().collect::<Vec<_>>(),
+        vec![0, 1, 7]
+    );
+    assert!(view.set(2, 0, 9).is_err());
+    assert_eq!(buffer, vec![0, 1, 7, 2, 3, 2]);
+
+    let mut vecgrid = Vecgrid::filled_with(0, 2, 2);
+    vecgrid.as_view_mut().set(1, 1, 5)?;
+    assert_eq!(vecgrid[(1, 1)], 5);
+    Ok(())
+}
+
+#[test]
+fn test_raw_parts() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    let pointer = vecgrid.as_ptr();
+    unsafe {
+        *vecgrid.as_mut_ptr().add(3) = 40;
+    }
+    let (elements, num_rows, num_columns) = vecgrid.into_raw_parts();
+    assert_eq!(elements.as_ptr(), pointer);
+    assert_eq!((num_rows, num_columns), (3, 2));
+    assert_eq!(elements, vec![1, 2, 3, 40, 5, 6]);
+
+    let vecgrid = Vecgrid::from_raw_parts(elements, 2, 3)?;
+    assert_eq!(vecgrid.as_ptr(), pointer);
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 2, 3], vec![40, 5, 6]]);
+    assert!(Vecgrid::from_raw_parts(vec![1, 2, 3], 2, 2).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_row_major_slice() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(vecgrid.as_row_major_slice().as_ptr(), vecgrid.as_ptr());
+    for (row, column) in vecgrid.indices_row_major() {
+        let index = row * vecgrid.num_columns() + column;
+        assert_eq!(vecgrid.as_row_major_slice()[index], vecgrid[(row, column)]);
+    }
+    for (row, column) in vecgrid.indices_row_major() {
+        let index = row * vecgrid.row_stride() + column * vecgrid.column_stride();
+        assert_eq!(vecgrid.get_row_major(index), Some(&vecgrid[(row, column)]));
+    }
+    vecgrid.as_mut_row_major_slice().reverse();
+    assert_eq!(vecgrid.as_rows(), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+    assert!(Vecgrid::<u8>::filled_with(0, 0, 4)
+        .as_row_major_slice()
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_vecgrid_macro() -> Result<(), Error> {
+    let vecgrid = vecgrid::vecgrid![[1, 2, 3], [4, 5, 6],];
+    assert_eq!(
+        vecgrid,
+        Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?
+    );
+    assert_eq!(vecgrid::vecgrid![[1], [2]].as_columns(), vec![vec![1, 2]]);
+    assert_eq!(
+        vecgrid::vecgrid!['.'; 2, 3],
+        Vecgrid::filled_with('.', 2, 3)
+    );
+    let empty: Vecgrid<i32> = vecgrid::vecgrid![];
+    assert_eq!((empty.num_rows(), empty.num_columns()), (0, 0));
+    assert_eq!(Vecgrid::from([[1, 2], [3, 4]]), [[1, 2], [3, 4]]);
+    Ok(())
+}
+
+#[test]
+fn test_find_subgrid() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![0, 1, 1, 1], vec![0, 1, 1, 1], vec![0, 1, 1, 0]])?;
+    let ones = Vecgrid::filled_with(1, 2, 2);
+    assert_eq!(vecgrid.find_subgrid(&ones), Some((0, 1)));
+    assert_eq!(
+        vecgrid.find_subgrid_all(&ones).collect::<Vec<_>>(),
+        vec![(0, 1), (0, 2), (1, 1)]
+    );
+    assert_eq!(vecgrid.find_subgrid(&vecgrid), Some((0, 0)));
+    assert_eq!(vecgrid.find_subgrid(&Vecgrid::filled_with(1, 4, 1)), None);
+    assert_eq!(vecgrid.find_subgrid(&Vecgrid::filled_with(1, 1, 5)), None);
+    assert_eq!(vecgrid.find_subgrid(&Vecgrid::filled_with(2, 1, 1)), None);
+
+    let empty = Vecgrid::from_rows(vec![vec![]; 2])?;
+    assert_eq!(vecgrid.find_subgrid_all(&empty).count(), 10);
+    Ok(())
+}
+
+#[test]
+fn test_find_subgrid_near_matches() -> Result<(), Error> {
+    // Mostly zeros with a sparse scattering of ones, searched for a block
+    // of zeros with a single one, so almost every offset nearly matches.
+    let (num_rows, num_columns) = (120, 150);
+    let vecgrid = Vecgrid::from_iter_row_major(
+        (0..num_rows * num_columns).map(|index| u8::from(index % 37 == 0 || index % 101 == 3)),
+        num_rows,
+        num_columns,
+    )?;
+    let mut pattern = Vecgrid::filled_with(0u8, 6, 5);
+    pattern[(5, 4)] = 1;
+
+    let naive = |pattern: &Vecgrid<u8>| {
+        let mut offsets = vec![];
+        for row in 0..=num_rows - pattern.num_rows() {
+            for column in 0..=num_columns - pattern.num_columns() {
+                let matches = (0..pattern.num_rows()).all(|pattern_row| {
+                    (0..pattern.num_columns()).all(|pattern_column| {
+                        vecgrid[(row + pattern_row, column + pattern_column)]
+                            == pattern[(pattern_row, pattern_column)]
+                    })
+                });
+                if matches {
+                    offsets.push((row, column));
+                }
+            }
+        }
+        offsets
+    };
+    let expected = naive(&pattern);
+    assert!(!expected.is_empty());
+    assert_eq!(
+        vecgrid.find_subgrid_all(&pattern).collect::<Vec<_>>(),
+        expected
+    );
+
+    // Repeated rows in the pattern exercise the failure function down the
+    // columns.
+    let mut stripes = Vecgrid::filled_with(0u8, 4, 3);
+    stripes[(1, 1)] = 1;
+    stripes[(3, 1)] = 1;
+    assert_eq!(
+        vecgrid.find_subgrid_all(&stripes).collect::<Vec<_>>(),
+        naive(&stripes)
+    );
+    let zeros = Vecgrid::filled_with(0u8, 8, 8);
+    assert_eq!(
+        vecgrid.find_subgrid_all(&zeros).collect::<Vec<_>>(),
+        naive(&zeros)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_find_where() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec!['a', 'a', 'c'], vec!['d', 'a', 'x']])?;
+    let pattern = Vecgrid::from_rows(vec![vec![Some('a'), None], vec![None, Some('x')]])?;
+    assert_eq!(vecgrid.find_where(&pattern), Some((0, 1)));
+
+    let wildcards = Vecgrid::filled_with(None, 2, 2);
+    assert_eq!(
+        vecgrid.find_where_all(&wildcards).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 1)]
+    );
+    let exact = Vecgrid::from_rows(vec![vec![Some('b'), Some('a')]])?;
+    assert_eq!(vecgrid.find_where(&exact), None);
+    assert_eq!(vecgrid.find_where(&Vecgrid::filled_with(None, 3, 1)), None);
+    Ok(())
+}
+
+#[test]
+fn test_neighbor_bitmasks() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![
+        vec![true, true, false],
+        vec![true, true, true],
+        vec![false, false, true],
+    ])?;
+    assert_eq!(
+        map.neighbor_bitmask(1, 1, Neighborhood::Four, |&w| w)?,
+        0b1011
+    );
+    assert_eq!(
+        map.neighbor_bitmask(1, 1, Neighborhood::Eight, |&w| w)?,
+        0b1100_1101
+    );
+    assert_eq!(
+        map.neighbor_bitmask(0, 0, Neighborhood::Eight, |&w| w)?,
+        0b1_1100
+    );
+    assert_eq!(
+        map.neighbor_bitmask(3, 0, Neighborhood::Four, |&w| w),
+        Err(Error::IndicesOutOfBounds {
+            row: 3,
+            column: 0,
+            num_rows: 3,
+            num_columns: 3
+        })
+    );
+
+    let masks = map.map_to_bitmasks(Neighborhood::Eight, |&w| w);
+    for (row, column) in map.indices_row_major() {
+        assert_eq!(
+            masks[(row, column)],
+            map.neighbor_bitmask(row, column, Neighborhood::Eight, |&w| w)?
+        );
+    }
+    assert_eq!(
+        map.map_to_bitmasks(Neighborhood::Four, |_| false),
+        Vecgrid::filled_with(0, 3, 3)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_erode_and_dilate() -> Result<(), Error> {
+    let mut mask = Vecgrid::filled_with(false, 5, 5);
+    mask[(2, 2)] = true;
+    mask.dilate(Neighborhood::Four, 2);
+    assert_eq!(mask.count_where(|&cell| cell), 13);
+    assert!(!mask[(0, 0)] && mask[(0, 2)] && mask[(1, 1)]);
+    mask.erode(Neighborhood::Four, 2);
+    assert_eq!(mask.count_where(|&cell| cell), 1);
+    assert!(mask[(2, 2)]);
+
+    let mut mask = Vecgrid::filled_with(true, 4, 4);
+    mask[(0, 0)] = false;
+    let unchanged = mask.clone();
+    mask.erode(Neighborhood::Eight, 0);
+    assert_eq!(mask, unchanged);
+    mask.erode(Neighborhood::Eight, 1);
+    assert_eq!(mask.count_where(|&cell| !cell), 4);
+    mask.erode(Neighborhood::Eight, 5);
+    assert!(!mask.any());
+
+    let mut empty = Vecgrid::filled_with(true, 0, 3);
+    empty.dilate(Neighborhood::Eight, 3);
+    assert_eq!(empty.num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_trace_boundary() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![
+        vec![0, 0, 0, 0, 1],
+        vec![0, 1, 1, 0, 0],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 1, 1, 1, 0],
+    ])?;
+    let inside = |&cell: &i32| cell == 1;
+    let outline = vec![
+        (1, 1),
+        (1, 2),
+        (2, 3),
+        (3, 3),
+        (3, 2),
+        (3, 1),
+        (3, 0),
+        (2, 1),
+    ];
+    assert_eq!(map.trace_boundary((3, 3), inside)?, outline);
+    assert_eq!(map.trace_boundary((1, 1), inside)?, outline);
+    assert_eq!(map.trace_boundary((0, 4), inside)?, vec![(0, 4)]);
+    assert_eq!(map.trace_boundary((0, 0), inside)?, vec![]);
+
+    let line = Vecgrid::filled_with(true, 1, 3);
+    assert_eq!(
+        line.trace_boundary((0, 1), |&cell| cell)?,
+        vec![(0, 0), (0, 1), (0, 2), (0, 1)]
+    );
+    assert_eq!(
+        line.trace_boundary((1, 0), |&cell| cell),
+        Err(Error::IndicesOutOfBounds {
+            row: 1,
+            column: 0,
+            num_rows: 1,
+            num_columns: 3
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_label_components() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![
+        vec![1, 1, 0, 1],
+        vec![0, 1, 0, 1],
+        vec![1, 0, 0, 1],
+        vec![1, 1, 0, 1],
+    ])?;
+    let (labels, count) = map.label_components(Neighborhood::Four, |a, b| a == b);
+    assert_eq!(count, 5);
+    assert_eq!(
+        labels.as_rows(),
+        vec![
+            vec![0, 0, 1, 2],
+            vec![3, 0, 1, 2],
+            vec![4, 1, 1, 2],
+            vec![4, 4, 1, 2],
+        ]
+    );
+    let (labels, count) = map.label_components(Neighborhood::Eight, |a, b| a == b);
+    assert_eq!(count, 3);
+    assert_eq!(labels[(2, 0)], labels[(0, 0)]);
+    assert_eq!(labels[(1, 0)], labels[(0, 2)]);
+
+    // A U shape joins two branches that were labeled apart at first.
+    let u_shape = Vecgrid::from_rows(vec![vec![1, 0, 1], vec![1, 0, 1], vec![1, 1, 1]])?;
+    let (labels, count) = u_shape.label_components(Neighborhood::Four, |a, b| a == b);
+    assert_eq!(count, 2);
+    assert_eq!(
+        labels.as_rows(),
+        vec![vec![0, 1, 0], vec![0, 1, 0], vec![0, 0, 0]]
+    );
+
+    let empty = Vecgrid::<i32>::filled_with(0, 0, 4);
+    assert_eq!(
+        empty.label_components(Neighborhood::Eight, |a, b| a == b).1,
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rectangles_where() -> Result<(), Error> {
+    let map = Vecgrid::from_rows(vec![
+        vec![0, 1, 1, 0, 0],
+        vec![1, 1, 1, 1, 0],
+        vec![1, 1, 1, 1, 1],
+        vec![0, 1, 0, 1, 1],
+    ])?;
+    assert_eq!(
+        map.bounding_box_where(|&cell| cell == 1),
+        Some(((0, 0), (3, 4)))
+    );
+    assert_eq!(
+        map.bounding_box_where(|&cell| cell == 0),
+        Some(((0, 0), (3, 4)))
+    );
+    assert_eq!(map.bounding_box_where(|&cell| cell == 2), None);
+    assert_eq!(
+        map.largest_rectangle_where(|&cell| cell == 1),
+        Some(((1, 0), (2, 3)))
+    );
+    assert_eq!(
+        map.largest_rectangle_where(|&cell| cell == 0),
+        Some(((0, 3), (0, 4)))
+    );
+    assert_eq!(map.largest_rectangle_where(|&cell| cell == 2), None);
+
+    let column = Vecgrid::from_rows(vec![vec![1], vec![1], vec![0], vec![1]])?;
+    assert_eq!(
+        column.largest_rectangle_where(|&cell| cell == 1),
+        Some(((0, 0), (1, 0)))
+    );
+    let empty = Vecgrid::<i32>::filled_with(1, 3, 0);
+    assert_eq!(empty.largest_rectangle_where(|_| true), None);
+    assert_eq!(empty.bounding_box_where(|_| true), None);
+    Ok(())
+}
+
+#[test]
+fn test_spiral_and_boustrophedon() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+    ])?;
+    assert_eq!(
+        vecgrid.spiral_iter().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 8, 12, 11, 10, 9, 5, 6, 7]
+    );
+    assert_eq!(vecgrid.spiral_iter().next_back(), Some(&7));
+    assert_eq!(
+        vecgrid.boustrophedon_iter().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 8, 7, 6, 5, 9, 10, 11, 12]
+    );
+
+    for (num_rows, num_columns) in [(0, 3), (1, 4), (4, 1), (5, 5), (4, 6), (7, 2)] {
+        let vecgrid = Vecgrid::filled_with(0, num_rows, num_columns);
+        let mut spiral = vecgrid.indices_spiral().collect::<Vec<_>>();
+        let mut snake = vecgrid.indices_boustrophedon().collect::<Vec<_>>();
+        spiral.sort();
+        snake.sort();
+        let indices = vecgrid.indices_row_major().collect::<Vec<_>>();
+        assert_eq!(spiral, indices);
+        assert_eq!(snake, indices);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_strided_iter() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_iter_row_major(0.., 4, 5)?;
+    assert_eq!(
+        vecgrid.strided_iter(3, 2).collect::<Vec<_>>(),
+        vec![
+            ((0, 0), &0),
+            ((0, 2), &2),
+            ((0, 4), &4),
+            ((3, 0), &15),
+            ((3, 2), &17),
+            ((3, 4), &19)
+        ]
+    );
+    assert_eq!(vecgrid.strided_iter(1, 1).count(), 20);
+    assert_eq!(
+        vecgrid.strided_iter(10, 10).collect::<Vec<_>>(),
+        vec![((0, 0), &0)]
+    );
+    assert_eq!(vecgrid.strided_iter(2, 3).next_back(), Some(((2, 3), &13)));
+
+    for ((row, column), element) in vecgrid.strided_iter_mut(2, 2) {
+        *element = -((row * 10 + column) as i32);
+    }
+    assert_eq!(
+        vecgrid.row_iter(2)?.copied().collect::<Vec<_>>(),
+        vec![-20, 11, -22, 13, -24]
+    );
+    assert_eq!(vecgrid[(1, 1)], 6);
+
+    let empty = Vecgrid::filled_with(0, 3, 0);
+    assert_eq!(empty.strided_iter(1, 1).count(), 0);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_strided_iter_zero_step() {
+    let vecgrid = Vecgrid::filled_with(0, 2, 2);
+    let _ = vecgrid.strided_iter(1, 0);
+}
+
+#[test]
+fn test_update_parity_with_neighbors() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_iter_row_major(0.., 3, 4)?;
+    let mut visited = vec![];
+    vecgrid.update_parity_with_neighbors(Parity::Odd, |position, cell, neighbors| {
+        visited.push(position);
+        let [above, left, right, below] = neighbors;
+        if position == (1, 2) {
+            assert_eq!(
+                (above, left, right, below),
+                (Some(&2), Some(&5), Some(&7), Some(&10))
+            );
+        }
+        if position == (2, 3) {
+            assert_eq!(
+                (above, left, right, below),
+                (Some(&7), Some(&10), None, None)
+            );
+        }
+        *cell = -neighbors.iter().flatten().copied().sum::<i32>();
+    });
+    assert_eq!(
+        visited,
+        vec![(0, 1), (0, 3), (1, 0), (1, 2), (2, 1), (2, 3)]
+    );
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![
+            vec![0, -7, 2, -9],
+            vec![-13, 5, -24, 7],
+            vec![8, -23, 10, -17]
+        ]
+    );
+
+    // The other color now reads the values written by the first pass.
+    vecgrid.update_parity_with_neighbors(Parity::Even, |_, cell, neighbors| {
+        *cell = neighbors.iter().flatten().count() as i32;
+    });
+    assert_eq!(vecgrid[(1, 1)], 4);
+    assert_eq!(vecgrid[(0, 0)], 2);
+
+    let mut single_row = Vecgrid::from_rows(vec![vec![1, 2, 3]])?;
+    single_row.update_parity_with_neighbors(
+        Parity::Even,
+        |_, cell, [above, left, right, below]| {
+            assert!(above.is_none() && below.is_none());
+            *cell = left.copied().unwrap_or(0) + right.copied().unwrap_or(0);
+        },
+    );
+    assert_eq!(single_row.as_rows(), vec![vec![2, 2, 2]]);
+    Ok(())
+}
+
+#[test]
+fn test_cells_where_parity() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_iter_row_major(0.., 3, 4)?;
+    let even = vecgrid.cells_where_parity(Parity::Even).collect::<Vec<_>>();
+    let odd = vecgrid.cells_where_parity(Parity::Odd).collect::<Vec<_>>();
+    assert_eq!(even.len() + odd.len(), 12);
+    assert!(even
+        .iter()
+        .all(|&((row, column), _)| Parity::of(row, column) == Parity::Even));
+    assert!(odd
+        .iter()
+        .all(|&((row, column), _)| Parity::of(row, column) == Parity::Odd));
+    assert_eq!(
+        even.iter().map(|&(_, &x)| x).collect::<Vec<_>>(),
+        vec![0, 2, 5, 7, 8, 10]
+    );
+    assert_eq!(odd.last(), Some(&((2, 3), &11)));
+
+    for ((row, column), cell) in vecgrid.cells_where_parity_mut(Parity::Odd) {
+        *cell = -((row * 10 + column) as i32);
+    }
+    assert_eq!(
+        vecgrid.row_iter(1)?.copied().collect::<Vec<_>>(),
+        vec![-10, 5, -12, 7]
+    );
+
+    let single_column = Vecgrid::filled_with(0, 3, 1);
+    assert_eq!(
+        single_column
+            .cells_where_parity(Parity::Odd)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>(),
+        vec![(1, 0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_interior_and_edges() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_iter_row_major(0.., 4, 5)?;
+    let interior = vecgrid.interior_iter().collect::<Vec<_>>();
+    assert_eq!(interior.len(), 6);
+    assert_eq!(interior.first(), Some(&((1, 1), &6)));
+    assert_eq!(vecgrid.interior_iter().next_back(), Some(((2, 3), &13)));
+    let edges = vecgrid.edges_iter().collect::<Vec<_>>();
+    assert_eq!(edges.len(), 14);
+    assert_eq!(
+        &edges[..6],
+        &[
+            ((0, 0), &0),
+            ((0, 1), &1),
+            ((0, 2), &2),
+            ((0, 3), &3),
+            ((0, 4), &4),
+            ((1, 4), &9)
+        ]
+    );
+    assert_eq!(edges.last(), Some(&((1, 0), &5)));
+
+    // A blur that reads the neighbors of interior cells without bounds checks.
+    let source = vecgrid.clone();
+    for ((row, column), element) in vecgrid.interior_iter_mut() {
+        *element = source[(row - 1, column)] + source[(row + 1, column)];
+    }
+    assert_eq!(
+        vecgrid.row_iter(1)?.copied().collect::<Vec<_>>(),
+        vec![5, 12, 14, 16, 9]
+    );
+
+    for (num_rows, num_columns) in [(0, 0), (1, 1), (1, 4), (3, 1), (2, 2), (3, 3)] {
+        let vecgrid = Vecgrid::filled_with(0, num_rows, num_columns);
+        let mut indices = vecgrid
+            .interior_iter()
+            .chain(vecgrid.edges_iter())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        indices.sort();
+        assert_eq!(indices, vecgrid.indices_row_major().collect::<Vec<_>>());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_map_neighborhood() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_iter_row_major(0.., 3, 4)?;
+    let sum = |view: CenterView<'_, i32>| view.iter().map(|(_, &x)| x).sum::<i32>();
+    let clamped = vecgrid.map_neighborhood(1, EdgeMode::Clamp, sum);
+    assert_eq!(clamped[(0, 0)], 15);
+    assert_eq!(clamped[(1, 1)], 45);
+    let wrapped = vecgrid.map_neighborhood(1, EdgeMode::Wrap, sum);
+    assert_eq!(wrapped[(0, 0)], 48);
+
+    let views = vecgrid.map_neighborhood(2, EdgeMode::Wrap, |view| {
+        assert_eq!(view.iter().count(), 25);
+        assert_eq!(view.get(0, 0), view.center());
+        (view.row(), view.column(), view.radius(), *view.get(-2, 2))
+    });
+    assert_eq!(views[(0, 3)], (0, 3, 2, 5));
+    assert_eq!(
+        vecgrid.map_neighborhood(0, EdgeMode::Clamp, |view| *view.center()),
+        vecgrid
+    );
+
+    let empty = Vecgrid::<i32>::filled_with(0, 0, 2);
+    let mapped = empty.map_neighborhood(1, EdgeMode::Clamp, |_| -> i32 { unreachable!() });
+    assert_eq!(mapped.num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_center_view_offset_larger_than_radius() {
+    let vecgrid = Vecgrid::filled_with(0, 3, 3);
+    vecgrid.map_neighborhood(1, EdgeMode::Clamp, |view| *view.get(2, 0));
+}
+
+#[test]
+fn test_sampling() -> Result<(), Error> {
+    let heights: Vecgrid<f64> =
+        Vecgrid::from_rows(vec![vec![0.0, 1.0, 2.0], vec![10.0, 11.0, 12.0]])?;
+    assert_eq!(heights.sample_bilinear(1.0, 0.0), Some(1.0));
+    assert_eq!(heights.sample_bilinear(1.5, 0.0), Some(1.5));
+    assert_eq!(heights.sample_bilinear(0.0, 0.25), Some(2.5));
+    assert_eq!(heights.sample_bilinear(2.0, 0.5), Some(7.0));
+    assert_eq!(heights.sample_bilinear(2.0, 1.0), Some(12.0));
+    assert_eq!(heights.sample_bilinear(-0.1, 0.0), None);
+    assert_eq!(heights.sample_bilinear(0.0, 1.1), None);
+    assert_eq!(heights.sample_bilinear(f64::NAN, 0.0), None);
+
+    let small: Vecgrid<f32> = Vecgrid::filled_with(4.0, 1, 1);
+    assert_eq!(small.sample_bilinear(0.0, 0.0), Some(4.0));
+
+    assert_eq!(heights.sample_nearest(1.4, 0.6), Some(&11.0));
+    assert_eq!(heights.sample_nearest(-0.4, 0.0), Some(&0.0));
+    assert_eq!(heights.sample_nearest(2.5, 0.0), None);
+    assert_eq!(heights.sample_nearest(0.0, f64::NAN), None);
+    Ok(())
+}
+
+#[test]
+fn test_resampled() -> Result<(), Error> {
+    let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![vec![0.0, 2.0], vec![4.0, 6.0]])?;
+    let finer = heights.resampled(3, 3, Resampling::Bilinear);
+    assert_eq!(
+        finer.as_rows(),
+        vec![
+            vec![0.0, 1.0, 2.0],
+            vec![2.0, 3.0, 4.0],
+            vec![4.0, 5.0, 6.0]
+        ]
+    );
+    assert_eq!(finer.resampled(2, 2, Resampling::Bilinear), heights);
+    assert_eq!(finer.resampled(2, 2, Resampling::Nearest), heights);
+    assert_eq!(
+        heights.resampled(1, 1, Resampling::Bilinear).as_row_major(),
+        vec![3.0]
+    );
+    assert_eq!(
+        heights.resampled(3, 4, Resampling::Nearest).as_rows()[1],
+        vec![4.0, 4.0, 6.0, 6.0]
+    );
+    assert_eq!(
+        heights.resampled(0, 5, Resampling::Nearest).num_elements(),
+        0
+    );
+
+    let empty: Vecgrid<f32> = Vecgrid::filled_with(0.0, 0, 0);
+    assert_eq!(empty.resampled(3, 0, Resampling::Bilinear).num_rows(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_finite_differences() -> Result<(), Error> {
+    // A plane rising by 2 along the columns and by 3 along the rows.
+    let plane: Vecgrid<f64> =
+        Vecgrid::from_iter_row_major((0..12).map(|i| (2 * (i % 4) + 3 * (i / 4)) as f64), 3, 4)?;
+    let interior = |grid: &Vecgrid<f64>| {
+        grid.interior_iter()
+            .map(|(_, &value)| value)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(interior(&plane.gradient_x(EdgeMode::Clamp)), vec![2.0; 2]);
+    assert_eq!(interior(&plane.gradient_y(EdgeMode::Clamp)), vec![3.0; 2]);
+    assert_eq!(interior(&plane.laplacian(EdgeMode::Clamp)), vec![0.0; 2]);
+    assert_eq!(plane.gradient_x(EdgeMode::Clamp)[(0, 0)], 1.0);
+    assert_eq!(plane.gradient_y(EdgeMode::Clamp)[(2, 3)], 1.5);
+    assert_eq!(plane.gradient_x(EdgeMode::Wrap)[(0, 0)], (2.0 - 6.0) / 2.0);
+
+    let flat: Vecgrid<f32> = Vecgrid::filled_with(7.0, 2, 2);
+    assert!(flat
+        .laplacian(EdgeMode::Wrap)
+        .elements_row_major_iter()
+        .all(|&value| value == 0.0));
+    Ok(())
+}
+
+#[test]
+fn test_terrain_flow() -> Result<(), Error> {
+    let heights: Vecgrid<f64> = Vecgrid::from_rows(vec![
+        vec![9.0, 8.0, 7.0, 8.0],
+        vec![8.0, 5.0, 4.0, 6.0],
+        vec![7.0, 4.0, 1.0, 5.0],
+        vec![6.0, 5.0, 2.0, 3.0],
+    ])?;
+    let directions = heights.downhill_direction_grid();
+    assert_eq!(directions[(0, 0)], Some((1, 1)));
+    assert_eq!(directions[(1, 2)], Some((2, 2)));
+    assert_eq!(directions[(3, 3)], Some((2, 2)));
+    assert_eq!(directions[(2, 2)], None);
+
+    let flow = heights.flow_accumulation();
+    assert_eq!(
+        flow.as_rows(),
+        vec![
+            vec![1, 1, 1, 1],
+            vec![1, 4, 3, 1],
+            vec![1, 3, 16, 1],
+            vec![1, 1, 2, 1],
+        ]
+    );
+
+    // Flats and pits keep their water.
+    let flat: Vecgrid<f32> = Vecgrid::filled_with(1.0, 2, 3);
+    assert!(flat
+        .downhill_direction_grid()
+        .elements_row_major_iter()
+        .all(Option::is_none));
+    assert_eq!(flat.flow_accumulation(), Vecgrid::filled_with(1, 2, 3));
+    Ok(())
+}
+
+#[test]
+fn test_draw_shapes() -> Result<(), Error> {
+    let mut canvas = Vecgrid::filled_with(0, 4, 5);
+    canvas.draw_rect((3, 4), (1, 1), 1)?;
+    canvas.draw_line((0, 0), (3, 0), 2)?;
+    assert_eq!(
+        canvas.as_rows(),
+        vec![
+            vec![2, 0, 0, 0, 0],
+            vec![2, 1, 1, 1, 1],
+            vec![2, 1, 0, 0, 1],
+            vec![2, 1, 1, 1, 1],
+        ]
+    );
+    canvas.fill_rect((2, 2), (2, 3), 3)?;
+    assert_eq!(
+        canvas.row_iter(2)?.copied().collect::<Vec<_>>(),
+        vec![2, 1, 3, 3, 1]
+    );
+
+    let mut canvas = Vecgrid::filled_with(0, 7, 7);
+    canvas.draw_circle((3, 3), 0, 1)?;
+    assert_eq!(canvas.elements_row_major_iter().sum::<i32>(), 1);
+    canvas.draw_circle((3, 3), 3, 1)?;
+    for row in 0..7 {
+        let cells = canvas.row_iter(row)?.filter(|&&cell| cell == 1).count();
+        assert_eq!(cells, [3, 2, 2, 3, 2, 2, 3][row]);
+    }
+    let mut filled = Vecgrid::filled_with(0, 7, 7);
+    filled.fill_circle((3, 3), 3, 1)?;
+    for (outline, fill) in canvas
+        .elements_row_major_iter()
+        .zip(filled.elements_row_major_iter())
+    {
+        assert!(outline <= fill);
+    }
+    assert_eq!(filled.row_iter(3)?.sum::<i32>(), 7);
+
+    // Circles are clipped at the edges, rectangles and lines are not.
+    let mut canvas = Vecgrid::filled_with(0, 2, 2);
+    canvas.fill_circle((0, 0), 5, 1)?;
+    assert_eq!(canvas, Vecgrid::filled_with(1, 2, 2));
+    assert_eq!(
+        canvas.draw_circle((2, 0), 1, 0),
+        Err(Error::IndicesOutOfBounds {
+            row: 2,
+            column: 0,
+            num_rows: 2,
+            num_columns: 2
+        })
+    );
+    assert!(canvas.draw_rect((0, 0), (0, 2), 0).is_err());
+    assert!(canvas.fill_rect((3, 0), (0, 0), 0).is_err());
+    assert!(canvas.draw_line((0, 0), (2, 2), 0).is_err());
+    assert_eq!(canvas, Vecgrid::filled_with(1, 2, 2));
+    Ok(())
+}
+
+#[test]
+fn test_circle_large_radius() -> Result<(), Error> {
+    let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    canvas.draw_circle((1, 2), usize::MAX, 1)?;
+    canvas.draw_circle((1, 2), 1 << 40, 1)?;
+    assert_eq!(canvas, Vecgrid::filled_with(0, 3, 4));
+    canvas.fill_circle((1, 2), usize::MAX, 1)?;
+    assert_eq!(canvas, Vecgrid::filled_with(1, 3, 4));
+
+    // Spans are clipped to the grid: a circle around a corner reaching
+    // just past the far corner still leaves the far corner empty.
+    let mut canvas = Vecgrid::filled_with(0, 5, 5);
+    canvas.fill_circle((0, 0), 5, 1)?;
+    assert_eq!(canvas[(4, 4)], 0);
+    assert_eq!(canvas.row_iter(0)?.sum::<i32>(), 5);
+    let mut outline = Vecgrid::filled_with(0, 5, 5);
+    outline.draw_circle((0, 0), 5, 1)?;
+    assert_eq!(outline.elements_row_major_iter().sum::<i32>(), 2);
+    assert_eq!((outline[(3, 4)], outline[(4, 3)]), (1, 1));
+    outline.draw_circle((0, 0), 4, 1)?;
+    assert_eq!(outline[(0, 4)], 1);
+    assert_eq!(outline[(4, 0)], 1);
+    Ok(())
+}
+
+#[test]
+fn test_stamp() -> Result<(), Error> {
+    let brush = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let replace = |cell: &mut i32, &element: &i32| *cell = element;
+
+    let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    canvas.stamp(&brush, (1, 1), replace);
+    assert_eq!(
+        canvas.as_rows(),
+        vec![vec![0, 0, 0, 0], vec![0, 1, 2, 3], vec![0, 4, 5, 6]]
+    );
+
+    // Clipped on every side.
+    let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    canvas.stamp(&brush, (-1, -2), replace);
+    canvas.stamp(&brush, (2, 2), |cell, element| *cell += element);
+    assert_eq!(
+        canvas.as_rows(),
+        vec![vec![6, 0, 0, 0], vec![0, 0, 0, 0], vec![0, 0, 1, 2]]
+    );
+    let mut covered = Vecgrid::filled_with(0, 1, 1);
+    covered.stamp(&Vecgrid::filled_with(1, 3, 3), (-1, -1), |cell, element| {
+        *cell += element
+    });
+    assert_eq!(covered, Vecgrid::filled_with(1, 1, 1));
+
+    // Entirely outside.
+    let mut canvas = Vecgrid::filled_with(0, 3, 4);
+    for at in [
+        (3, 0),
+        (0, 4),
+        (-2, 0),
+        (0, -3),
+        (isize::MAX, isize::MAX),
+        (isize::MIN, 0),
+        (0, -10),
+        (1, isize::MIN),
+    ] {
+        canvas.stamp(&brush, at, replace);
+    }
+    canvas.stamp(&Vecgrid::<i32>::filled_with(7, 0, 0), (0, 0), replace);
+    assert_eq!(canvas, Vecgrid::filled_with(0, 3, 4));
+    Ok(())
+}
+
+#[test]
+fn test_column_cached_vecgrid() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let mut cached = ColumnCachedVecgrid::new(vecgrid.clone());
+    assert!(!cached.is_column_major_cached());
+    cached.ensure_column_major_cache();
+    assert!(cached.is_column_major_cached());
+    let pointer = cached.columns_flat().as_ptr();
+    assert_eq!(cached.columns_flat(), vecgrid.as_column_major().as_slice());
+    assert_eq!(cached.column_slice(2)?.as_ptr(), pointer.wrapping_add(4));
+    assert_eq!(
+        cached.column_slice(3),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(cached, ColumnCachedVecgrid::from(vecgrid));
+
+    // Every mutation discards the copy.
+    cached.set(0, 0, 10)?;
+    assert!(!cached.is_column_major_cached());
+    assert_eq!(cached.column_slice(0)?, &[10, 4]);
+    *cached.get_mut(1, 0).unwrap() = 40;
+    assert_eq!(cached.column_slice(0)?, &[10, 40]);
+    cached.row_iter_mut(1)?.for_each(|element| *element += 1);
+    assert_eq!(cached.columns_flat(), &[10, 41, 2, 6, 3, 7]);
+    cached.column_iter_mut(2)?.for_each(|element| *element = 0);
+    assert_eq!(cached.column_slice(2)?, &[0, 0]);
+    cached
+        .elements_row_major_iter_mut()
+        .for_each(|element| *element *= 2);
+    cached[(0, 1)] = 1;
+    assert_eq!(cached.columns_flat(), &[20, 82, 1, 12, 0, 0]);
+    assert!(cached.set(2, 0, 0).is_err());
+    assert!(cached.is_column_major_cached());
+    assert_eq!(
+        cached.into_inner().as_rows(),
+        vec![vec![20, 1, 0], vec![82, 12, 0]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_column_cached_vecgrid_interior_mutability() -> Result<(), Error> {
+    use std::cell::Cell;
+    let vecgrid = Vecgrid::from_rows(vec![vec![Cell::new(1), Cell::new(2)]])?;
+    let mut cached = ColumnCachedVecgrid::new(vecgrid);
+    cached.ensure_column_major_cache();
+    cached[(0, 1)].set(20);
+    // Changed through a shared reference, so the copy is stale.
+    assert_eq!(cached.column_slice(1)?[0].get(), 2);
+    cached.discard_column_major_cache();
+    assert!(!cached.is_column_major_cached());
+    assert_eq!(cached.column_slice(1)?[0].get(), 20);
+    Ok(())
+}
+
+#[test]
+fn test_as_cells() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let cells = vecgrid.as_cells();
+    assert_eq!((cells.num_rows(), cells.num_columns()), (2, 3));
+    // Swap the rows through shared references.
+    for column in cells.row_iter(0)?.zip(cells.row_iter(1)?) {
+        column.0.swap(column.1);
+    }
+    cells[(0, 0)].set(cells[(1, 2)].get() * 10);
+    assert_eq!(vecgrid.as_rows(), vec![vec![30, 5, 6], vec![1, 2, 3]]);
+
+    let mut buffer = [1, 2, 3, 4];
+    let cells = VecgridRefMut::new(&mut buffer, 2, 2)?.into_cells();
+    cells[(1, 1)].set(cells[(0, 0)].replace(0));
+    assert_eq!(buffer, [0, 2, 3, 1]);
+    Ok(())
+}
+
+#[test]
+fn test_row_chunks() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_iter_row_major(0..21, 7, 3)?;
+    for num_chunks in 1..10 {
+        let chunks = vecgrid.clone().into_row_chunks(num_chunks);
+        assert_eq!(chunks.len(), num_chunks);
+        let rows = chunks.iter().map(Vecgrid::num_rows).collect::<Vec<_>>();
+        assert_eq!(rows.iter().sum::<usize>(), 7);
+        assert!(rows
+            .windows(2)
+            .all(|pair| pair[0] >= pair[1] && pair[0] - pair[1] <= 1));
+        assert!(chunks.iter().all(|chunk| chunk.num_columns() == 3));
+        assert_eq!(Vecgrid::from_row_chunks(chunks)?, vecgrid);
+    }
+    let chunks = vecgrid.clone().into_row_chunks(3);
+    assert_eq!(
+        chunks[2].as_rows(),
+        vec![vec![15, 16, 17], vec![18, 19, 20]]
+    );
+
+    assert_eq!(
+        Vecgrid::<i32>::from_row_chunks(Vec::new())?,
+        Vecgrid::filled_with(0, 0, 0)
+    );
+    assert_eq!(
+        Vecgrid::from_row_chunks(vec![
+            vecgrid,
+            Vecgrid::filled_with(0, 1, 3),
+            Vecgrid::filled_with(0, 1, 2)
+        ]),
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            actual: 2,
+            index: Some(2)
+        })
+    );
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_into_row_chunks_zero() {
+    Vecgrid::filled_with(0, 2, 2).into_row_chunks(0);
+}
+
+#[test]
+fn test_row_stream() -> std::io::Result<()> {
+    use std::io::ErrorKind;
+
+    let encode = |row: &[i32], buffer: &mut Vec<u8>| {
+        buffer.extend(row.iter().flat_map(|element| element.to_le_bytes()));
+    };
+    let decode = |bytes: &[u8]| -> std::io::Result<Vec<i32>> {
+        Ok(bytes
+            .chunks(4)
+            .map(|chunk| i32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect())
+    };
+    let vecgrid = Vecgrid::from_iter_row_major(-5..7, 3, 4).unwrap();
+    let mut bytes = Vec::new();
+    vecgrid.serialize_rows_to(&mut bytes, encode)?;
+    assert_eq!(&bytes[..4], b"VGRS");
+    assert_eq!(bytes.len(), 4 + 8 + 8 + 3 * (8 + 4 * 4));
+    let mut again = Vec::new();
+    vecgrid.serialize_rows_to(&mut again, encode)?;
+    assert_eq!(again, bytes);
+    assert_eq!(
+        Vecgrid::deserialize_rows_from(bytes.as_slice(), decode)?,
+        vecgrid
+    );
+
+    for (num_rows, num_columns) in [(0, 0), (0, 3), (3, 0)] {
+        let empty = Vecgrid::filled_with(0, num_rows, num_columns);
+        let mut bytes = Vec::new();
+        empty.serialize_rows_to(&mut bytes, encode)?;
+        assert_eq!(
+            Vecgrid::deserialize_rows_from(bytes.as_slice(), decode)?,
+            empty
+        );
+    }
+
+    let truncated = Vecgrid::deserialize_rows_from(&bytes[..bytes.len() - 1], decode);
+    assert_eq!(truncated.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    let short_row = Vecgrid::deserialize_rows_from(bytes.as_slice(), |bytes| {
+        decode(bytes).map(|mut row| {
+            row.pop();
+            row
+        })
+    });
+    assert_eq!(short_row.unwrap_err().kind(), ErrorKind::InvalidData);
+    let failing = Vecgrid::<i32>::deserialize_rows_from(bytes.as_slice(), |_| {
+        Err(ErrorKind::Unsupported.into())
+    });
+    assert_eq!(failing.unwrap_err().kind(), ErrorKind::Unsupported);
+    let mut huge = bytes.clone();
+    huge[4..20].copy_from_slice(&[0xff; 16]);
+    let huge = Vecgrid::deserialize_rows_from(huge.as_slice(), decode);
+    assert_eq!(huge.unwrap_err().kind(), ErrorKind::InvalidData);
+    // A header claiming a trillion elements in a few bytes fails at the
+    // end of the input instead of allocating for all of them.
+    let mut header = b"VGRS".to_vec();
+    header.extend_from_slice(&(1u64 << 20).to_le_bytes());
+    header.extend_from_slice(&(1u64 << 20).to_le_bytes());
+    let lying = Vecgrid::deserialize_rows_from(header.as_slice(), decode);
+    assert_eq!(lying.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    Ok(())
+}
+
+#[test]
+fn test_from_row_stream() {
+    let rows = (0..4).map(|row| Ok::<_, String>((0..3).map(|column| row * 3 + column).collect()));
+    let vecgrid = Vecgrid::from_row_stream(rows).unwrap();
+    assert_eq!(vecgrid, Vecgrid::from_iter_row_major(0..12, 4, 3).unwrap());
+
+    let empty = Vecgrid::<u8>::from_row_stream(Vec::<Result<_, String>>::new()).unwrap();
+    assert_eq!((empty.num_rows(), empty.num_columns()), (0, 0));
+    let no_columns =
+        Vecgrid::<u8>::from_row_stream(vec![Ok::<_, String>(vec![]), Ok(vec![])]).unwrap();
+    assert_eq!((no_columns.num_rows(), no_columns.num_columns()), (2, 0));
+
+    let rows = vec![Ok(vec![1, 2]), Ok(vec![3, 4]), Err("bad line"), Ok(vec![5])];
+    let error = Vecgrid::from_row_stream(rows).unwrap_err();
+    assert_eq!(
+        error,
+        IngestError::Source {
+            row: 2,
+            error: "bad line"
+        }
+    );
+    assert_eq!(error.to_string(), "row 2: bad line");
+
+    // Nothing after the first error is read.
+    let mut read = 0;
+    let rows = [Ok(vec![1, 2]), Ok(vec![3, 4, 5]), Err("unread")]
+        .into_iter()
+        .inspect(|_| read += 1);
+    let error = Vecgrid::from_row_stream(rows).unwrap_err();
+    assert_eq!(
+        error,
+        IngestError::Vecgrid(Error::DimensionMismatch {
+            expected: 2,
+            actual: 3,
+            index: Some(1)
+        })
+    );
+    assert_eq!(read, 2);
+}
+
+#[test]
+fn test_a1_addresses() -> Result<(), Error> {
+    for (address, row, column) in [
+        ("A1", 0, 0),
+        ("Z9", 8, 25),
+        ("AA1", 0, 26),
+        ("AZ2", 1, 51),
+        ("BA3", 2, 52),
+        ("ZZ10", 9, 701),
+        ("AAA100", 99, 702),
+        ("XFD1048576", 1048575, 16383),
+    ] {
+        let index = GridIndex::new(row, column);
+        assert_eq!(GridIndex::from_a1(address), Some(index));
+        assert_eq!(GridIndex::from_a1(&address.to_lowercase()), Some(index));
+        assert_eq!(index.to_a1(), address);
+    }
+    for column in 0..2000 {
+        let index = GridIndex::new(column * 7, column);
+        assert_eq!(GridIndex::from_a1(&index.to_a1()), Some(index));
+    }
+    for invalid in [
+        "",
+        "A",
+        "1",
+        "A0",
+        "A01",
+        "1A",
+        "A1B",
+        "A-1",
+        "$A$1",
+        " A1",
+        "É1",
+        "AAAAAAAAAAAAAAAAAAAAAA1",
+    ] {
+        assert_eq!(GridIndex::from_a1(invalid), None, "{:?}", invalid);
+    }
+    assert_eq!(
+        GridIndex::new(usize::MAX, usize::MAX).to_a1().len(),
+        14 + 20
+    );
+
+    let mut sheet = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4]])?;
+    assert_eq!(sheet.get_a1("B1"), Some(&2));
+    *sheet.get_mut_a1("a2").unwrap() = 30;
+    assert_eq!(sheet[(1, 0)], 30);
+    assert_eq!(sheet.get_a1("C1"), None);
+    assert_eq!(sheet.get_mut_a1("A3"), None);
+    Ok(())
+}
+
+#[test]
+fn test_table() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![31, 180], vec![27, 165]])?;
+    assert_eq!(
+        Table::new(vec!["age".to_string()], vecgrid.clone()),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: None
+        })
+    );
+    let mut table = Table::new(vec!["age".to_string(), "height".to_string()], vecgrid)?;
+    assert_eq!(table.column_index("height"), Some(1));
+    assert!(table.column_by_name("weight").is_none());
+    assert_eq!(
+        table.column_by_name("age").unwrap().collect::<Vec<_>>(),
+        vec![&31, &27]
+    );
+    for age in table.column_by_name_mut("age").unwrap() {
+        *age += 1;
+    }
+    *table.get_mut(1, "height").unwrap() = 166;
+    assert_eq!(table.get(1, "age"), Some(&28));
+    assert_eq!(table.get(2, "age"), None);
+    assert_eq!(table[(1, "height")], 166);
+
+    table.insert_column_named(2, "weight", vec![80, 60])?;
+    table.insert_column_named(0, "id", vec![1, 2])?;
+    assert_eq!(
+        table.insert_column_named(5, "x", vec![0, 0]),
+        Err(Error::IndexOutOfBounds { index: 5, len: 5 })
+    );
+    assert_eq!(
+        table.insert_column_named(1, "x", vec![0]),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1,
+            index: None
+        })
+    );
+    table.push_row(vec![3, 40, 170, 70])?;
+    assert_eq!(table.headers(), ["id", "age", "height", "weight"]);
+    assert_eq!(
+        table.as_rows(),
+        vec![
+            vec![1, 32, 180, 80],
+            vec![2, 28, 166, 60],
+            vec![3, 40, 170, 70]
+        ]
+    );
+    let rows = table
+        .rows_iter()
+        .map(|row| row.collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(rows[2][1], ("age", &40));
+    assert!(table.row_iter(3).is_err());
+
+    let mut empty = Table::new(Vec::new(), Vecgrid::from_rows(Vec::<Vec<u8>>::new())?)?;
+    empty.insert_column_named(0, "a", vec![1, 2, 3])?;
+    assert_eq!(empty.column_len(), 3);
+    let (headers, vecgrid) = empty.into_parts();
+    assert_eq!(headers, vec!["a".to_string()]);
+    assert_eq!(vecgrid.as_columns(), vec![vec![1, 2, 3]]);
+    Ok(())
+}
+
+#[test]
+fn test_partition_rows_by_key() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![
+        vec!["a", "1"],
+        vec!["b", "2"],
+        vec!["a", "3"],
+        vec!["c", "4"],
+    ])?;
+    let groups = vecgrid.partition_rows_by_key(|row| row[0]);
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups["a"].as_rows(), vec![vec!["a", "1"], vec!["a", "3"]]);
+    assert_eq!(groups["b"].as_rows(), vec![vec!["b", "2"]]);
+    assert_eq!(groups["c"].num_rows(), 1);
+
+    let empty = Vecgrid::from_rows(vec![Vec::<u8>::new(); 3])?;
+    let groups = empty.partition_rows_by_key(|row| row.len());
+    assert_eq!(groups[&0].num_rows(), 3);
+    assert_eq!(groups[&0].num_columns(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_column_projection() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(vecgrid.column_to_vec(0)?, vec![1, 4]);
+    assert_eq!(vecgrid.column_to_vec(2)?, vec![3, 6]);
+    assert_eq!(vecgrid.map_column(1, |element| element * 10)?, vec![20, 50]);
+    assert_eq!(
+        vecgrid.column_to_vec(3),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert!(vecgrid.map_column(3, |element| *element).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rows_and_columns_ranges() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])?;
+    assert_eq!(vecgrid.rows(..)?, vecgrid);
+    assert_eq!(vecgrid.rows(1..2)?.as_rows(), vec![vec![4, 5, 6]]);
+    assert_eq!(vecgrid.rows(1..=2)?.num_rows(), 2);
+    assert_eq!(vecgrid.rows(3..)?.num_rows(), 0);
+    assert_eq!(vecgrid.rows(3..)?.num_columns(), 3);
+    assert_eq!(
+        vecgrid.columns(1..)?.as_rows(),
+        vec![vec![2, 3], vec![5, 6], vec![8, 9]]
+    );
+    assert_eq!(vecgrid.columns(1..1)?.num_rows(), 3);
+    assert_eq!(
+        vecgrid.rows(..4),
+        Err(Error::IndexOutOfBounds { index: 4, len: 4 })
+    );
+    let (start, end) = (2, 1);
+    assert_eq!(
+        vecgrid.columns(start..end),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_interleave_channels() -> Result<(), Error> {
+    let rgba = Vecgrid::from_rows(vec![
+        vec![1, 2, 3, 4, 5, 6, 7, 8],
+        vec![9, 10, 11, 12, 13, 14, 15, 16],
+    ])?;
+    let channels = rgba.deinterleave(4)?;
+    assert_eq!(channels.len(), 4);
+    assert_eq!(channels[0].as_rows(), vec![vec![1, 5], vec![9, 13]]);
+    assert_eq!(channels[3].as_rows(), vec![vec![4, 8], vec![12, 16]]);
+    assert_eq!(Vecgrid::interleave(&channels)?, rgba);
+    assert_eq!(rgba.deinterleave(1)?, vec![rgba.clone()]);
+    assert_eq!(
+        rgba.deinterleave(3),
+        Err(Error::DimensionMismatch {
+            expected: 9,
+            actual: 8,
+            index: None
+        })
+    );
+
+    let short = Vecgrid::from_rows(vec![vec![0, 0]])?;
+    assert_eq!(
+        Vecgrid::interleave(&[channels[0].clone(), short]),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (1, 2),
+            index: Some(1)
+        })
+    );
+    let narrow = Vecgrid::from_rows(vec![vec![0], vec![0]])?;
+    assert_eq!(
+        Vecgrid::interleave(&[channels[0].clone(), narrow]),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (2, 1),
+            index: Some(1)
+        })
+    );
+    let empty = Vecgrid::<u8>::interleave(&[])?;
+    assert_eq!(empty.num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_deinterleave_zero() {
+    let vecgrid = Vecgrid::filled_with(0, 2, 2);
+    let _ = vecgrid.deinterleave(0);
+}
+
+#[test]
+fn test_row_windows() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    let windows = vecgrid.row_windows(2).collect::<Vec<_>>();
+    assert_eq!(windows.len(), 2);
+    assert_eq!(
+        windows[0].to_vecgrid().as_rows(),
+        vec![vec![1, 2], vec![3, 4]]
+    );
+    assert_eq!(
+        windows[1].to_vecgrid().as_rows(),
+        vec![vec![3, 4], vec![5, 6]]
+    );
+    assert_eq!(vecgrid.row_windows(1).count(), 3);
+    assert_eq!(vecgrid.row_windows(3).next().unwrap().to_vecgrid(), vecgrid);
+    assert_eq!(vecgrid.row_windows(4).count(), 0);
+    let last = vecgrid.row_windows(2).next_back().unwrap();
+    assert_eq!(last[(0, 0)], 3);
+
+    let no_columns = Vecgrid::from_rows(vec![Vec::<u8>::new(); 3])?;
+    assert_eq!(no_columns.row_windows(2).count(), 2);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_row_windows_zero() {
+    let vecgrid = Vecgrid::filled_with(0, 2, 2);
+    let _ = vecgrid.row_windows(0);
+}
+
+#[test]
+fn test_integral_image() -> Result<(), Error> {
+    let vecgrid = Vecgrid::<u8>::from_rows(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+    ])?;
+    let integral = vecgrid.integral_image();
+    assert_eq!(
+        integral.as_rows(),
+        vec![vec![1, 3, 6, 10], vec![6, 14, 24, 36], vec![15, 33, 54, 78]]
+    );
+    for top in 0..3 {
+        for bottom in top..3 {
+            for left in 0..4 {
+                for right in left..4 {
+                    let expected = (top..=bottom)
+                        .flat_map(|row| (left..=right).map(move |column| (row, column)))
+                        .map(|index| u64::from(vecgrid[index]))
+                        .sum::<u64>();
+                    assert_eq!(integral.region_sum((top, left), (bottom, right))?, expected);
+                }
+            }
+        }
+    }
+    assert_eq!(integral.region_sum((2, 3), (1, 1))?, 54);
+    assert_eq!(
+        integral.region_sum((0, 0), (0, 4)),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 4,
+            num_rows: 3,
+            num_columns: 4
+        })
+    );
+
+    let signed = Vecgrid::<i32>::from_rows(vec![vec![-1, 2], vec![3, -4]])?.integral_image();
+    assert_eq!(signed.region_sum((0, 1), (1, 1))?, -2);
+    let floats = Vecgrid::<f32>::filled_with(0.5, 2, 2).integral_image();
+    assert_eq!(floats.region_sum((0, 0), (1, 1))?, 2.0);
+    assert_eq!(
+        Vecgrid::<u8>::filled_with(0, 0, 0)
+            .integral_image()
+            .num_elements(),
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_integral_image_wraps() -> Result<(), Error> {
+    // The sum of the whole vecgrid overflows, but the sums of its
+    // bottom and right edges fit.
+    let vecgrid = Vecgrid::from_rows(vec![vec![u64::MAX - 1, 1], vec![1, 7]])?;
+    let integral = vecgrid.integral_image();
+    assert_eq!(integral.region_sum((1, 1), (1, 1))?, 7);
+    assert_eq!(integral.region_sum((0, 1), (1, 1))?, 8);
+    assert_eq!(integral.region_sum((1, 0), (1, 1))?, 8);
+    assert_eq!(integral.region_sum((0, 0), (0, 0))?, u64::MAX - 1);
+
+    let signed = Vecgrid::from_rows(vec![vec![i64::MAX, i64::MAX], vec![-5, -3]])?;
+    let integral = signed.integral_image();
+    assert_eq!(integral.region_sum((1, 0), (1, 1))?, -8);
+    assert_eq!(integral.region_sum((0, 0), (1, 0))?, i64::MAX - 5);
+    assert_eq!(integral.region_sum((0, 1), (1, 1))?, i64::MAX - 3);
+    assert_eq!(integral.into_inner().num_elements(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_prefix_scans() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 3, 1], vec![1, 5, 1], vec![4, 2, 1]])?;
+    assert_eq!(
+        vecgrid.row_prefix_sums().as_rows(),
+        vec![vec![1, 4, 5], vec![1, 6, 7], vec![4, 6, 7]]
+    );
+    assert_eq!(
+        vecgrid.column_prefix_sums().as_rows(),
+        vec![vec![1, 3, 1], vec![2, 8, 2], vec![6, 10, 3]]
+    );
+    let labels = vecgrid.scan_rows(String::new(), |label, element| {
+        format!("{}{}", label, element)
+    });
+    assert_eq!(labels[(2, 2)], "421");
+    let floats = Vecgrid::from_rows(vec![vec![0.5, 0.25], vec![0.25, 0.5]])?;
+    assert_eq!(
+        floats.column_prefix_sums().as_rows(),
+        vec![vec![0.5, 0.25], vec![0.75, 0.75]]
+    );
+    let empty = Vecgrid::from_rows(vec![Vec::<i32>::new(); 2])?;
+    assert_eq!(empty.row_prefix_sums().num_rows(), 2);
+    assert_eq!(empty.column_prefix_sums().num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_min_cost_path() -> Result<(), Error> {
+    let costs = Vecgrid::from_rows(vec![vec![1, 3, 1], vec![1, 5, 1], vec![4, 2, 1]])?;
+    let (total, path) = costs.min_cost_path((2, 2), |&cost| cost, Moves::RightDown)?;
+    assert_eq!(total, 7);
+    assert_eq!(path, vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+    assert_eq!(
+        costs.min_cost_path_table(|&cost| cost, Moves::Orthogonal),
+        costs.min_cost_path_table(|&cost| cost, Moves::RightDown)
+    );
+    assert_eq!(
+        costs.min_cost_path((0, 0), |&cost| cost, Moves::Orthogonal)?,
+        (1, vec![(0, 0)])
+    );
+
+    let walls = Vecgrid::from_rows(vec![vec![0, 0, 0], vec![9, 9, 0], vec![0, 0, 0]])?;
+    let table = walls.min_cost_path_table(|&cost| cost, Moves::Orthogonal);
+    assert_eq!(table[(2, 0)], 0);
+    assert_eq!(
+        walls.min_cost_path_table(|&cost| cost, Moves::RightDown)[(2, 0)],
+        9
+    );
+    let saturated = walls.min_cost_path_table(|_| u64::MAX, Moves::RightDown);
+    assert_eq!(saturated[(2, 2)], u64::MAX);
+    assert_eq!(
+        walls.min_cost_path((3, 0), |&cost| cost, Moves::RightDown),
+        Err(Error::IndicesOutOfBounds {
+            row: 3,
+            column: 0,
+            num_rows: 3,
+            num_columns: 3
+        })
+    );
+    let empty = Vecgrid::<u64>::from_rows(vec![])?;
+    assert_eq!(
+        empty
+            .min_cost_path_table(|&cost| cost, Moves::Orthogonal)
+            .num_elements(),
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_seam_carving() -> Result<(), Error> {
+    let energy = Vecgrid::from_rows(vec![
+        vec![9, 9, 0, 9, 9],
+        vec![9, 0, 9, 9, 9],
+        vec![0, 9, 9, 9, 9],
+        vec![9, 0, 9, 9, 9],
+    ])?;
+    let seam = energy.find_vertical_seam(|&energy| energy);
+    assert_eq!(seam, vec![2, 1, 0, 1]);
+
+    let mut image = Vecgrid::from_rows(vec![
+        vec![1, 2, 3, 4, 5],
+        vec![1, 2, 3, 4, 5],
+        vec![1, 2, 3, 4, 5],
+        vec![1, 2, 3, 4, 5],
+    ])?;
+    image.remove_vertical_seam(&seam)?;
+    assert_eq!(
+        image.as_rows(),
+        vec![
+            vec![1, 2, 4, 5],
+            vec![1, 3, 4, 5],
+            vec![2, 3, 4, 5],
+            vec![1, 3, 4, 5]
+        ]
+    );
+    assert_eq!(
+        image.remove_vertical_seam(&[0, 0]),
+        Err(Error::DimensionMismatch {
+            expected: 4,
+            actual: 2,
+            index: None
+        })
+    );
+    assert_eq!(
+        image.remove_vertical_seam(&[0, 1, 4, 0]),
+        Err(Error::IndicesOutOfBounds {
+            row: 2,
+            column: 4,
+            num_rows: 4,
+            num_columns: 4
+        })
+    );
+    assert_eq!(image.num_columns(), 4);
+
+    // Uniform energy keeps the seam straight.
+    let flat = Vecgrid::filled_with(1, 3, 3);
+    assert_eq!(flat.find_vertical_seam(|&energy| energy), vec![0, 0, 0]);
+    let mut column = Vecgrid::from_rows(vec![vec![7], vec![8]])?;
+    column.remove_vertical_seam(&[0, 0])?;
+    assert_eq!((column.num_rows(), column.num_columns()), (2, 0));
+    assert!(column.find_vertical_seam(|&energy| energy).is_empty());
+    assert!(column.remove_vertical_seam(&[0, 0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_remap_and_equalize() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    vecgrid.remap_values(|value| value * value);
+    assert_eq!(vecgrid.as_rows(), vec![vec![1, 4, 9], vec![16, 25, 36]]);
+
+    let mut image = Vecgrid::<u8>::from_rows(vec![vec![50, 50, 60], vec![60, 60, 70]])?;
+    image.equalize_histogram();
+    assert_eq!(image.as_rows(), vec![vec![0, 0, 191], vec![191, 191, 255]]);
+
+    let mut flat = Vecgrid::<u8>::filled_with(42, 2, 2);
+    flat.equalize_histogram();
+    assert_eq!(flat, Vecgrid::filled_with(42, 2, 2));
+    let mut empty = Vecgrid::<u8>::from_rows(vec![])?;
+    empty.equalize_histogram();
+    assert_eq!(empty.num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_threshold_and_binarize() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![0.1, 0.5], vec![0.9, f64::NAN]])?;
+    assert_eq!(
+        vecgrid.threshold(0.5).as_rows(),
+        vec![vec![false, false], vec![true, false]]
+    );
+    let mut binary = vecgrid.clone();
+    binary.binarize_in_place(0.3, 0.0, 1.0);
+    assert_eq!(binary.as_rows(), vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+
+    let mut words = Vecgrid::from_rows(vec![vec!["apple", "pear"], vec!["fig", "kiwi"]])?;
+    assert_eq!(
+        words.threshold("grape").as_rows(),
+        vec![vec![false, true], vec![false, true]]
+    );
+    words.binarize_in_place("grape", "a-g", "h-z");
+    assert_eq!(
+        words.as_rows(),
+        vec![vec!["a-g", "h-z"], vec!["a-g", "h-z"]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_roi_mut() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+    ])?;
+    let mut roi = vecgrid.roi_mut(1..3, 1..=2)?;
+    assert_eq!((roi.first_row(), roi.first_column()), (1, 1));
+    assert_eq!((roi.num_rows(), roi.num_columns()), (2, 2));
+    assert_eq!(roi.to_vecgrid().as_rows(), vec![vec![6, 7], vec![10, 11]]);
+    assert_eq!(roi[(1, 0)], 10);
+    assert_eq!(roi.get(2, 0), None);
+    assert_eq!(
+        roi.set(0, 2, 0),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 2,
+            num_rows: 2,
+            num_columns: 2
+        })
+    );
+    for element in roi.row_iter_mut(1)? {
+        *element *= 10;
+    }
+    assert_eq!(
+        roi.row_iter(1)?.copied().collect::<Vec<_>>(),
+        vec![100, 110]
+    );
+    assert!(roi.row_iter(2).is_err());
+
+    fn clear<G: GridMut<Element = i32>>(grid: &mut G) {
+        for row in 0..grid.num_rows() {
+            grid.set(row, 0, 0).unwrap();
+        }
+    }
+    clear(&mut roi);
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![vec![1, 2, 3, 4], vec![5, 0, 7, 8], vec![9, 0, 110, 12]]
+    );
+
+    vecgrid.roi_mut(.., 3..)?.fill(-1);
+    assert_eq!(vecgrid.column_to_vec(3)?, vec![-1, -1, -1]);
+    let empty: VecgridViewMut<'_, i32> = vecgrid.roi_mut(3.., ..)?;
+    assert_eq!(empty.to_vecgrid().num_elements(), 0);
+    assert_eq!(vecgrid.roi_mut(1..1, 4..)?.num_columns(), 0);
+    assert_eq!(
+        vecgrid.roi_mut(.., ..5).unwrap_err(),
+        Error::IndexOutOfBounds { index: 5, len: 5 }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_roi_mut_equality() -> Result<(), Error> {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |view: &VecgridViewMut<'_, i32>| {
+        let mut hasher = DefaultHasher::new();
+        view.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    // The same 2 by 2 block of ones, surrounded by different elements in
+    // vecgrids of different widths.
+    let mut narrow = Vecgrid::from_rows(vec![vec![1, 1, 7], vec![1, 1, 8]])?;
+    let mut wide = Vecgrid::from_rows(vec![vec![0, 1, 1, 5, 6], vec![9, 1, 1, 0, 0]])?;
+    let a = narrow.roi_mut(.., ..2)?;
+    let b = wide.roi_mut(.., 1..3)?;
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_eq!(
+        format!("{:?}", a),
+        format!("{:?}", b).replace("first_column: 1", "first_column: 0")
+    );
+    assert!(!format!("{:?}", b).contains('9'));
+
+    let row = narrow.roi_mut(..1, ..2)?;
+    let column = wide.roi_mut(.., 1..2)?;
+    assert_ne!(row, column);
+    Ok(())
+}
+
+#[test]
+fn test_roi_mut_nested() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_row_major((0..30).collect(), 5, 6)?;
+    let mut outer = vecgrid.roi_mut(1.., 2..5)?;
+    assert_eq!(outer.offset_in_parent(), (1, 2));
+    let mut inner = outer.roi_mut(1..3, 1..)?;
+    assert_eq!(inner.offset_in_parent(), (1, 1));
+    assert_eq!((inner.first_row(), inner.first_column()), (2, 3));
+    assert_eq!(
+        inner.to_vecgrid().as_rows(),
+        vec![vec![15, 16], vec![21, 22]]
+    );
+    let mut innermost = inner.roi_mut(1.., ..1)?;
+    assert_eq!(innermost.offset_in_parent(), (1, 0));
+    assert_eq!((innermost.first_row(), innermost.first_column()), (3, 3));
+    innermost[(0, 0)] = -1;
+    assert!(inner.roi_mut(.., 3..).is_err());
+    assert_eq!(inner.roi_mut(2.., 1..)?.num_rows(), 0);
+    assert_eq!(outer.roi_mut(4.., 3..)?.to_vecgrid().num_elements(), 0);
+    assert_eq!(vecgrid[(3, 3)], -1);
+    Ok(())
+}
+
+#[test]
+fn test_roi_mut_empty() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_iter_row_major(0.., 3, 3)?;
+    let mut narrow = vecgrid.roi_mut(0..3, 2..2)?;
+    narrow.fill(9);
+    assert_eq!((narrow.num_rows(), narrow.num_columns()), (3, 0));
+    assert_eq!(narrow.row_iter(2)?.count(), 0);
+    assert_eq!(narrow.row_iter_mut(2)?.count(), 0);
+    assert_eq!(narrow.to_vecgrid().num_rows(), 3);
+    assert_eq!(narrow.to_vecgrid().num_elements(), 0);
+    assert!(format!("{:?}", narrow).contains("rows: [[], [], []]"));
+    assert_eq!(narrow.roi_mut(1.., ..)?.num_rows(), 2);
+
+    let mut edge = vecgrid.roi_mut(.., 3..)?;
+    edge.fill(9);
+    assert_eq!(edge.row_iter(2)?.count(), 0);
+
+    let mut flat = vecgrid.roi_mut(1..1, ..)?;
+    flat.fill(9);
+    assert_eq!((flat.num_rows(), flat.num_columns()), (0, 3));
+    assert!(flat.row_iter(0).is_err());
+    assert_eq!(flat.to_vecgrid().num_elements(), 0);
+    assert_eq!(vecgrid, Vecgrid::from_iter_row_major(0.., 3, 3)?);
+    Ok(())
+}
+
+#[test]
+fn test_enumerate_windows() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    let windows = vecgrid
+        .enumerate_windows(2, 2)
+        .map(|(position, window)| (position, window.to_vecgrid().as_rows()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        windows,
+        vec![
+            ((0, 0), vec![vec![1, 2], vec![4, 5]]),
+            ((0, 1), vec![vec![2, 3], vec![5, 6]]),
+        ]
+    );
+    let rows = vecgrid
+        .enumerate_windows(1, 3)
+        .map(|(position, window)| (position, window.rows_iter().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            ((0, 0), vec![&[1, 2, 3][..]]),
+            ((1, 0), vec![&[4, 5, 6][..]])
+        ]
+    );
+    let (position, window): ((usize, usize), VecgridView<'_, i32>) =
+        vecgrid.enumerate_windows(1, 1).last().unwrap();
+    assert_eq!(position, (1, 2));
+    assert_eq!((window.first_row(), window.first_column()), position);
+    assert_eq!(window[(0, 0)], 6);
+    assert_eq!(window.get(0, 1), None);
+    assert!(window.row_iter(1).is_err());
+    assert_eq!(vecgrid.enumerate_windows(3, 1).count(), 0);
+    assert_eq!(vecgrid.enumerate_windows(1, 4).count(), 0);
+    assert_eq!(
+        vecgrid.roi(1.., 1..)?.to_vecgrid().as_rows(),
+        vec![vec![5, 6]]
+    );
+    assert!(vecgrid.roi(3.., ..).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_roi_nested_and_equality() -> Result<(), Error> {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |view: &VecgridView<'_, i32>| {
+        let mut hasher = DefaultHasher::new();
+        view.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let vecgrid = Vecgrid::from_row_major((0..30).collect(), 5, 6)?;
+    let outer = vecgrid.roi(1.., 2..5)?;
+    assert_eq!(outer.offset_in_parent(), (1, 2));
+    let inner = outer.roi(1..3, 1..)?;
+    assert_eq!(inner.offset_in_parent(), (1, 1));
+    assert_eq!((inner.first_row(), inner.first_column()), (2, 3));
+    assert_eq!(
+        inner.to_vecgrid().as_rows(),
+        vec![vec![15, 16], vec![21, 22]]
+    );
+    assert_eq!(inner, vecgrid.roi(2..4, 3..5)?);
+    assert!(inner.roi(.., 3..).is_err());
+    assert_eq!(outer.roi(4.., 3..)?.to_vecgrid().num_elements(), 0);
+
+    // Equal regions surrounded by different elements.
+    let ones = Vecgrid::from_rows(vec![vec![1, 1, 7], vec![1, 1, 8]])?;
+    let more_ones = Vecgrid::from_rows(vec![vec![0, 1, 1, 5, 6], vec![9, 1, 1, 0, 0]])?;
+    let (a, b) = (ones.roi(.., ..2)?, more_ones.roi(.., 1..3)?);
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert!(!format!("{:?}", b).contains('9'));
+    assert_ne!(ones.roi(..1, ..2)?, more_ones.roi(.., 1..2)?);
+    Ok(())
+}
+
+#[test]
+fn test_roi_empty() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_iter_row_major(0.., 3, 3)?;
+    let narrow = vecgrid.roi(0..3, 1..1)?;
+    assert_eq!((narrow.num_rows(), narrow.num_columns()), (3, 0));
+    assert_eq!(narrow.row_iter(2)?.count(), 0);
+    assert_eq!(narrow.rows_iter().count(), 3);
+    assert_eq!(narrow.elements_row_major_iter().count(), 0);
+    assert_eq!(narrow.to_vecgrid().num_rows(), 3);
+    assert_eq!(narrow.to_vecgrid().num_elements(), 0);
+    assert!(format!("{:?}", narrow).contains("rows: [[], [], []]"));
+    assert_eq!(narrow, vecgrid.roi(.., 3..)?);
+    assert_eq!(narrow.roi(1.., ..)?.num_rows(), 2);
+
+    let flat = vecgrid.roi(3.., ..)?;
+    assert_eq!((flat.num_rows(), flat.num_columns()), (0, 3));
+    assert_eq!(
+        flat.row_iter(0).err(),
+        Some(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 0,
+            num_rows: 0,
+            num_columns: 3
+        })
+    );
+    assert_eq!(flat.to_vecgrid().num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_enumerate_windows_zero() {
+    let vecgrid = Vecgrid::filled_with(0, 2, 2);
+    let _ = vecgrid.enumerate_windows(1, 0);
+}
+
+#[test]
+fn test_broadcast_vectors() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    vecgrid.add_row_vector(&[1, 1, 1])?;
+    vecgrid.mul_column_vector(&[1, 10])?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![2, 3, 4], vec![50, 60, 70]]);
+    vecgrid.add_column_vector(&[-2, -50])?;
+    vecgrid.mul_row_vector(&[3, 2, 1])?;
+    assert_eq!(vecgrid.as_rows(), vec![vec![0, 2, 2], vec![0, 20, 20]]);
+
+    let unchanged = vecgrid.clone();
+    assert_eq!(
+        vecgrid.add_row_vector(&[1, 2]),
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            actual: 2,
+            index: None
+        })
+    );
+    assert_eq!(
+        vecgrid.mul_column_vector(&[1, 2, 3]),
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 3,
+            index: None
+        })
+    );
+    assert_eq!(vecgrid, unchanged);
+
+    let mut labels = Vecgrid::filled_with(String::new(), 2, 2);
+    labels.broadcast_row(&["a", "b"], |label, column| label.push_str(column))?;
+    labels.broadcast_column(&[1, 2], |label, row| label.push_str(&row.to_string()))?;
+    assert_eq!(labels.as_rows(), vec![vec!["a1", "b1"], vec!["a2", "b2"]]);
+
+    let mut no_columns: Vecgrid<i32> = Vecgrid::from_rows(vec![vec![], vec![]])?;
+    no_columns.add_column_vector(&[1, 2])?;
+    no_columns.add_row_vector(&[])?;
+    Ok(())
+}
+
+#[test]
+fn test_map_lines_in_place() -> Result<(), Error> {
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1.0, 3.0], vec![2.0, 6.0], vec![5.0, 5.0]])?;
+    let mut rows = vec![];
+    vecgrid.map_rows_in_place(|row, elements| {
+        rows.push(row);
+        let total: f64 = elements.iter().sum();
+        for element in elements {
+            *element /= total;
+        }
+    });
+    assert_eq!(rows, vec![0, 1, 2]);
+    assert_eq!(
+        vecgrid.as_rows(),
+        vec![vec![0.25, 0.75], vec![0.25, 0.75], vec![0.5, 0.5]]
+    );
+
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]])?;
+    vecgrid.map_columns_in_place(|column, mut elements: ColumnMut<'_, i32>| {
+        assert_eq!(elements.len(), 3);
+        assert!(!elements.is_empty());
+        assert_eq!(elements.get(3), None);
+        if column == 0 {
+            // Shift the column down by one, wrapping around.
+            for row in (1..elements.len()).rev() {
+                elements.swap(row, row - 1);
+            }
+        } else {
+            elements[2] = elements.iter().sum();
+            *elements.get_mut(0).unwrap() = 0;
+        }
+    });
+    assert_eq!(vecgrid.as_rows(), vec![vec![5, 0], vec![1, 4], vec![3, 12]]);
+
+    let mut columns = 0;
+    let mut no_columns: Vecgrid<i32> = Vecgrid::from_rows(vec![vec![], vec![]])?;
+    no_columns.map_columns_in_place(|_, _| columns += 1);
+    assert_eq!(columns, 0);
+    let mut rows = 0;
+    no_columns.map_rows_in_place(|_, elements| {
+        assert!(elements.is_empty());
+        rows += 1;
+    });
+    assert_eq!(rows, 2);
+    let mut no_rows: Vecgrid<i32> = Vecgrid::filled_with(0, 0, 3);
+    no_rows.map_columns_in_place(|_, elements| {
+        assert!(elements.is_empty());
+        assert_eq!(elements.iter().count(), 0);
+        columns += 1;
+    });
+    assert_eq!(columns, 3);
+    Ok(())
+}
+
+#[test]
+fn test_column_mut_equality() -> Result<(), Error> {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    // Equal columns followed by different elements in the rest of the vecgrid.
+    let mut vecgrid = Vecgrid::from_rows(vec![vec![1, 1, 8], vec![2, 2, 9]])?;
+    let mut seen = Vec::new();
+    vecgrid.map_columns_in_place(|_, elements| {
+        let mut hasher = DefaultHasher::new();
+        elements.hash(&mut hasher);
+        seen.push((format!("{:?}", elements), hasher.finish()));
+    });
+    assert_eq!(seen[0], seen[1]);
+    assert_eq!(seen[0].0, "[1, 2]");
+    assert_ne!(seen[1], seen[2]);
+    Ok(())
+}
+
+#[test]
+fn test_sorted_positions_and_ranks() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![5, 2, 9], vec![2, 7, 0]])?;
+    let positions = vecgrid.sorted_positions();
+    assert_eq!(
+        positions,
+        vec![(1, 2), (0, 1), (1, 0), (0, 0), (1, 1), (0, 2)]
+    );
+    let values: Vec<_> = positions
+        .iter()
+        .map(|&position| vecgrid[position])
+        .collect();
+    assert_eq!(values, vec![0, 2, 2, 5, 7, 9]);
+    assert_eq!(
+        vecgrid.rank_grid().as_rows(),
+        vec![vec![3, 1, 5], vec![2, 4, 0]]
+    );
+
+    let descending = vecgrid.sorted_positions_by(|a, b| b.cmp(a));
+    assert_eq!(descending[0], (0, 2));
+    assert_eq!(&descending[3..5], &[(0, 1), (1, 0)]);
+    assert_eq!(
+        vecgrid.rank_grid_by(|a, b| b.cmp(a)).as_rows(),
+        vec![vec![2, 3, 0], vec![4, 1, 5]]
+    );
+
+    let empty: Vecgrid<i32> = Vecgrid::from_rows(vec![])?;
+    assert!(empty.sorted_positions().is_empty());
+    assert_eq!(empty.rank_grid().num_elements(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_top_k() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![vec![5, 2, 9], vec![2, 7, 9], vec![0, 5, 1]])?;
+    assert_eq!(vecgrid.top_k(0), vec![]);
+    assert_eq!(vecgrid.top_k(1), vec![((0, 2), &9)]);
+    assert_eq!(
+        vecgrid.top_k(4),
+        vec![((0, 2), &9), ((1, 2), &9), ((1, 1), &7), ((0, 0), &5)]
+    );
+    let all = vecgrid.top_k(20);
+    assert_eq!(all.len(), 9);
+    let descending = vecgrid.sorted_positions_by(|a, b| b.cmp(a));
+    assert_eq!(
+        all.iter()
+            .map(|&(position, _)| position)
+            .collect::<Vec<_>>(),
+        descending
+    );
+
+    let empty: Vecgrid<i32> = Vecgrid::from_rows(vec![])?;
+    assert!(empty.top_k(3).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_sorted_searches() -> Result<(), Error> {
+    let vecgrid = Vecgrid::from_rows(vec![
+        vec![1, 4, 7, 11],
+        vec![2, 5, 8, 12],
+        vec![3, 6, 9, 16],
+        vec![10, 13, 14, 17],
+    ])?;
+    assert_eq!(vecgrid.binary_search_row(1, &12)?, Ok(3));
+    assert_eq!(vecgrid.binary_search_row(3, &0)?, Err(0));
+    assert_eq!(vecgrid.binary_search_row(3, &20)?, Err(4));
+    assert_eq!(
+        vecgrid.binary_search_row(4, &1),
+        Err(Error::IndicesOutOfBounds {
+            row: 4,
+            column: 0,
+            num_rows: 4,
+            num_columns: 4
+        })
+    );
+    assert_eq!(vecgrid.binary_search_column(2, &9)?, Ok(2));
+    assert_eq!(vecgrid.binary_search_column(0, &4)?, Err(3));
+    assert_eq!(vecgrid.binary_search_column(3, &1)?, Err(0));
+    assert_eq!(vecgrid.binary_search_column(3, &18)?, Err(4));
+    assert_eq!(
+        vecgrid.binary_search_column(4, &1),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 4,
+            num_rows: 4,
+            num_columns: 4
+        })
+    );
+
+    for (index, &element) in vecgrid.elements_row_major_iter().enumerate() {
+        assert_eq!(
+            vecgrid.saddleback_search(&element),
+            Some((index / 4, index % 4))
+        );
+    }
+    for missing in [0, 15, 18] {
+        assert_eq!(vecgrid.saddleback_search(&missing), None);
+    }
+    let no_columns: Vecgrid<i32> = Vecgrid::from_rows(vec![vec![], vec![]])?;
+    assert_eq!(no_columns.saddleback_search(&1), None);
+    assert_eq!(no_columns.binary_search_row(1, &1)?, Err(0));
+    Ok(())
+}
+
 fn main() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let vecgrid = Vecgrid::from_rows(rows.clone())?;